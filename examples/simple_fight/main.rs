@@ -1,25 +1,28 @@
 use srd::character::{class::FIGHTER, race::HILL_DWARF, Character};
 use srd::rules::narrator::DebugNarrator;
 use srd::util::seed_battle_prng;
-use srd::{ability::*, init_srd_compendium, CreatureHandle, SRDRules};
-use weasel::{Battle, BattleController, Server};
+use srd::{ability::*, init_srd_compendium, CreatureHandle, CreatureHandleMut, SRDRules};
+use weasel::round::TurnState;
+use weasel::{Battle, BattleController, EndTurn, EntityId, EventTrigger, Id, Server, StartTurn};
 
 fn main() {
     // Initialize a compendium with the SRD rules.
     init_srd_compendium().unwrap();
 
     println!("Creating the characters...");
-    // Create the first character.
+    // Create the first character. Characters of different teams are enemies.
     let mut guy = Character::new("guy", HILL_DWARF, FIGHTER).unwrap();
     guy.add_ability(STRENGTH, AbilityScore::new(18).unwrap())
         .add_ability(DEXTERITY, AbilityScore::new(10).unwrap())
-        .add_ability(CONSTITUTION, AbilityScore::new(10).unwrap());
+        .add_ability(CONSTITUTION, AbilityScore::new(10).unwrap())
+        .set_team(1);
 
-    // Create the second character.
+    // Create the second character, fighting for the other team.
     let mut dude = Character::new("dude", HILL_DWARF, FIGHTER).unwrap();
     dude.add_ability(STRENGTH, AbilityScore::new(10).unwrap())
         .add_ability(DEXTERITY, AbilityScore::new(14).unwrap())
-        .add_ability(CONSTITUTION, AbilityScore::new(14).unwrap());
+        .add_ability(CONSTITUTION, AbilityScore::new(14).unwrap())
+        .set_team(2);
 
     // Create a battle.
     println!("Creating the battle...");
@@ -30,7 +33,7 @@ fn main() {
     // Seed the pseudo random number generator.
     seed_battle_prng(&mut server).unwrap();
 
-    // Spawn both characters.
+    // Spawn both characters. Their teams are created as they join the battle.
     println!("Spawning the characters...");
     guy.spawn(&mut server).unwrap();
     dude.spawn(&mut server).unwrap();
@@ -39,20 +42,42 @@ fn main() {
     print_character_stats(CreatureHandle::new(guy.id(), &server));
     print_character_stats(CreatureHandle::new(dude.id(), &server));
 
+    // Print the initiative order.
+    for initiative in server.battle().rounds().model().order() {
+        println!(
+            "{:?} rolled {} initiative",
+            initiative.creature,
+            initiative.total()
+        );
+    }
+
     // Fight until only one remains!
+    let mut turn = 0;
     while server.battle().entities().creatures().count() > 1 {
-        // Get who acts now.
-        // let id = xxx.next_actor();
-
-        // get all possible actions and print..
-        // let handle = CharacterHandle::new(id, &mut server);
-        // TODO
-
-        // maybe move? or just spawn the two close to each other..
-
-        // attack..
-        // handle.attack(...);
+        // The two characters take turns attacking each other, in initiative order.
+        let attacker = server.battle().rounds().model().current().unwrap().clone();
+        let target = if attacker == *guy.id() {
+            dude.id()
+        } else {
+            guy.id()
+        };
+        StartTurn::trigger(&mut server, EntityId::Creature(attacker.clone()))
+            .fire()
+            .unwrap();
+        // Unconscious characters can only hope for a good death saving throw.
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        if handle.life_state().is_ok_and(|state| state.is_conscious()) {
+            handle.attack(target).unwrap();
+        }
+        // The turn is already over if the attacker died during it.
+        if let TurnState::Started(_) = server.battle().rounds().state() {
+            EndTurn::trigger(&mut server).fire().unwrap();
+        }
+        turn += 1;
     }
+
+    let winner = server.battle().entities().creatures().next().unwrap();
+    println!("{:?} wins after {} turns!", winner.id(), turn);
 }
 
 fn print_character_stats(handle: CreatureHandle<Server<SRDRules>>) {
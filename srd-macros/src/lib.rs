@@ -61,6 +61,7 @@ fn impl_display_for_variant(name: &syn::Ident, variant: &syn::Variant) -> impl q
     if let syn::Fields::Unit = fields {
     } else {
         fields.iter_mut().for_each(|field| {
+            field.attrs.clear();
             field.ty = syn::Type::Infer(syn::TypeInfer {
                 underscore_token: syn::token::Underscore::default(),
             })
@@ -4,6 +4,8 @@
 use std::env;
 
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(atomic_cas)");
+
     let target = match rustc_target() {
         Some(target) => target,
         None => return,
@@ -123,7 +123,7 @@ impl AbilityScore {
     /// Constructs a new `AbilityScore` capped between the min and max value.
     pub fn capped(value: u8) -> Self {
         Self {
-            value: std::cmp::max(std::cmp::min(ABILITY_SCORE_MAX, value), ABILITY_SCORE_MIN),
+            value: value.clamp(ABILITY_SCORE_MIN, ABILITY_SCORE_MAX),
         }
     }
 
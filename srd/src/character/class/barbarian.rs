@@ -0,0 +1,83 @@
+//! Barbarian class.
+
+use crate::ability::{AbilityId, CONSTITUTION, STRENGTH};
+use crate::character::class::{ClassModel, SkillChoice};
+use crate::dice::Die;
+use crate::equipment::{ArmorCategory, UnarmoredDefense, WeaponCategory, WeaponProficiency};
+use crate::hit_points::HitDice;
+use crate::skill::*;
+
+/// Id of the Barbarian class.
+///
+/// # Reference
+///
+/// **Hit Dice:** 1d12 per barbarian level\
+/// **Hit Points at 1st Level:** 12 + your Constitution modifier\
+/// **Hit Points at Higher Levels:** 1d12 (or 7) + your Constitution modifier per barbarian level
+/// after 1st
+///
+/// **Armor:** Light armor, medium armor, shields\
+/// **Weapons:** Simple weapons, martial weapons\
+/// **Tools:** None\
+/// **Saving Throws:** Strength, Constitution\
+/// **Skills:** Choose two from Animal Handling, Athletics, Intimidation, Nature, Perception,
+/// and Survival
+///
+/// |Level|Proficiency Bonus|Features|
+/// |:---:|:---------------:|--------|
+/// |1st|+2|Rage, Unarmored Defense
+pub const BARBARIAN: &str = "_barbarian";
+
+#[derive(Default)]
+/// Models the Barbarian class.
+pub(crate) struct BarbarianModel {}
+
+impl ClassModel for BarbarianModel {
+    fn hit_dice(&self) -> HitDice {
+        HitDice::new(1, Die::D12)
+    }
+
+    fn saving_throws(&self) -> &[AbilityId] {
+        &[STRENGTH, CONSTITUTION]
+    }
+
+    fn armor_proficiencies(&self) -> &[ArmorCategory] {
+        &[
+            ArmorCategory::Light,
+            ArmorCategory::Medium,
+            ArmorCategory::Shield,
+        ]
+    }
+
+    fn weapon_proficiencies(&self) -> &[WeaponProficiency] {
+        &[
+            WeaponProficiency::Category(WeaponCategory::Simple),
+            WeaponProficiency::Category(WeaponCategory::Martial),
+        ]
+    }
+
+    /// # Reference
+    ///
+    /// While you are not wearing any armor, your Armor Class equals 10 + your Dexterity
+    /// modifier + your Constitution modifier. You can use a shield and still gain this benefit.
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        Some(UnarmoredDefense {
+            ability: CONSTITUTION,
+            allows_shield: true,
+        })
+    }
+
+    fn skill_choice(&self) -> SkillChoice<'_> {
+        SkillChoice {
+            count: 2,
+            among: &[
+                ANIMAL_HANDLING,
+                ATHLETICS,
+                INTIMIDATION,
+                NATURE,
+                PERCEPTION,
+                SURVIVAL,
+            ],
+        }
+    }
+}
@@ -1,8 +1,12 @@
 //! Fighter class.
 
-use crate::character::class::ClassModel;
+use crate::ability::{AbilityId, CONSTITUTION, STRENGTH};
+use crate::character::class::{ClassModel, SkillChoice};
+use crate::character::level::Level;
 use crate::dice::Die;
+use crate::equipment::{ArmorCategory, WeaponCategory, WeaponProficiency};
 use crate::hit_points::HitDice;
+use crate::skill::*;
 
 /// Id of the Fighter class.
 ///
@@ -12,6 +16,13 @@ use crate::hit_points::HitDice;
 /// **Hit Points at 1st Level: 10 + your Constitution modifier
 /// **Hit Points at Higher Levels: 1d10 (or 6) + your Constitution modifier per fighter level after 1s
 ///
+/// **Armor:** All armor, shields\
+/// **Weapons:** Simple weapons, martial weapons\
+/// **Tools:** None\
+/// **Saving Throws:** Strength, Constitution\
+/// **Skills:** Choose two skills from Acrobatics, Animal Handling, Athletics, History, Insight,
+/// Intimidation, Perception, and Survival
+///
 /// |Level|Proficiency Bonus|Features|
 /// |:---:|:---------------:|--------|
 /// |1st|+2|Fighting Style, Second Wind
@@ -25,4 +36,70 @@ impl ClassModel for FighterModel {
     fn hit_dice(&self) -> HitDice {
         HitDice::new(1, Die::D10)
     }
+
+    fn saving_throws(&self) -> &[AbilityId] {
+        &[STRENGTH, CONSTITUTION]
+    }
+
+    fn armor_proficiencies(&self) -> &[ArmorCategory] {
+        &[
+            ArmorCategory::Light,
+            ArmorCategory::Medium,
+            ArmorCategory::Heavy,
+            ArmorCategory::Shield,
+        ]
+    }
+
+    fn weapon_proficiencies(&self) -> &[WeaponProficiency] {
+        &[
+            WeaponProficiency::Category(WeaponCategory::Simple),
+            WeaponProficiency::Category(WeaponCategory::Martial),
+        ]
+    }
+
+    /// # Reference
+    ///
+    /// Beginning at 5th level, you can attack twice, instead of once, whenever you take the Attack
+    /// action on your turn.\
+    /// The number of attacks increases to three when you reach 11th level in this class and to
+    /// four when you reach 20th level in this class.
+    fn attacks_per_action(&self, level: &Level) -> u8 {
+        match level.value() {
+            20 => 4,
+            11..=19 => 3,
+            5..=10 => 2,
+            _ => 1,
+        }
+    }
+
+    fn skill_choice(&self) -> SkillChoice<'_> {
+        SkillChoice {
+            count: 2,
+            among: &[
+                ACROBATICS,
+                ANIMAL_HANDLING,
+                ATHLETICS,
+                HISTORY,
+                INSIGHT,
+                INTIMIDATION,
+                PERCEPTION,
+                SURVIVAL,
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra_attack() {
+        let model = FighterModel::default();
+        let attacks = |level| model.attacks_per_action(&Level::new(level).unwrap());
+        assert_eq!(attacks(1), 1);
+        assert_eq!(attacks(5), 2);
+        assert_eq!(attacks(11), 3);
+        assert_eq!(attacks(20), 4);
+    }
 }
@@ -1,11 +1,22 @@
 //! Character classes.
 
+pub mod barbarian;
+pub use self::barbarian::BARBARIAN;
+
 pub mod fighter;
 pub use self::fighter::FIGHTER;
 
+pub mod monk;
+pub use self::monk::MONK;
+
+use crate::ability::AbilityId;
 use crate::character::level::Level;
+use crate::damage::{DamageDefense, DamageType};
+use crate::equipment::{ArmorCategory, ToolId, UnarmoredDefense, WeaponProficiency};
 use crate::hit_points::HitDice;
 use crate::proficiency::ProficiencyBonus;
+use crate::rules::core::attack::CriticalHit;
+use crate::skill::SkillId;
 use serde::{Deserialize, Serialize};
 
 /// Number of core classes.
@@ -23,6 +34,56 @@ impl From<&str> for ClassId {
     }
 }
 
+/// A fighting style, the specialty in combat adopted by fighters, paladins and rangers.
+///
+/// # Reference
+///
+/// You adopt a particular style of fighting as your specialty. You can't take a Fighting Style
+/// option more than once, even if you later get to choose again.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub enum FightingStyle {
+    /// You gain a +2 bonus to attack rolls you make with ranged weapons.
+    Archery,
+    /// While you are wearing armor, you gain a +1 bonus to AC.
+    Defense,
+    /// When you are wielding a melee weapon in one hand and no other weapons, you gain a +2 bonus
+    /// to damage rolls with that weapon.
+    Dueling,
+    /// When you roll a 1 or 2 on a damage die for an attack you make with a melee weapon that you
+    /// are wielding with two hands, you can reroll the die and must use the new roll.
+    GreatWeaponFighting,
+    /// When a creature you can see attacks a target other than you that is within 5 feet of you,
+    /// you can use your reaction to impose disadvantage on the attack roll.
+    Protection,
+    /// When you engage in two-weapon fighting, you can add your ability modifier to the damage of
+    /// the second attack.
+    TwoWeaponFighting,
+}
+
+/// Armor class bonus granted by the Defense fighting style.
+pub const DEFENSE_STYLE_BONUS: u8 = 1;
+
+/// A choice of skill proficiencies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkillChoice<'a> {
+    /// How many skills can be chosen.
+    pub count: u8,
+    /// The skills to choose from.
+    pub among: &'a [SkillId],
+}
+
+impl SkillChoice<'_> {
+    /// Returns true if `skills` is a valid choice.
+    pub fn is_valid(&self, skills: &[SkillId]) -> bool {
+        skills.len() <= usize::from(self.count)
+            && skills.iter().all(|skill| self.among.contains(skill))
+            && skills
+                .iter()
+                .enumerate()
+                .all(|(i, skill)| !skills[..i].contains(skill))
+    }
+}
+
 /// Describes all bonuses and maluses of a class.
 pub trait ClassModel {
     /// Returns the hit dice per level.
@@ -37,6 +98,60 @@ pub trait ClassModel {
     fn proficiency_bonus(&self, level: &Level) -> ProficiencyBonus {
         proficiency_bonus_at_level(level)
     }
+
+    /// Returns the abilities whose saving throws the class is proficient in.
+    ///
+    /// # Reference
+    ///
+    /// Each class gives proficiency in at least two saving throws.\
+    /// When multiclassing, you gain your first class's saving throw proficiencies only.
+    fn saving_throws(&self) -> &[AbilityId] {
+        &[]
+    }
+
+    /// Returns the armor categories the class is proficient with.
+    fn armor_proficiencies(&self) -> &[ArmorCategory] {
+        &[]
+    }
+
+    /// Returns the weapons the class is proficient with.
+    fn weapon_proficiencies(&self) -> &[WeaponProficiency] {
+        &[]
+    }
+
+    /// Returns the tools the class is proficient with.
+    fn tool_proficiencies(&self) -> &[ToolId] {
+        &[]
+    }
+
+    /// Returns how the class scores critical hits at the given level.
+    fn critical_hit(&self, _level: &Level) -> CriticalHit {
+        CriticalHit::default()
+    }
+
+    /// Returns how many attacks a character of the class makes when taking the Attack action at
+    /// the given level.
+    fn attacks_per_action(&self, _level: &Level) -> u8 {
+        1
+    }
+
+    /// Returns the class's Unarmored Defense feature, if it has one.
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        None
+    }
+
+    /// Returns the damage types the class has a defense against.
+    fn damage_defenses(&self) -> &[(DamageType, DamageDefense)] {
+        &[]
+    }
+
+    /// Returns the skill proficiencies a character of this class can choose.
+    fn skill_choice(&self) -> SkillChoice<'_> {
+        SkillChoice {
+            count: 0,
+            among: &[],
+        }
+    }
 }
 
 /// Returns the standard proficiency bonus at the given level.
@@ -47,6 +162,19 @@ const fn proficiency_bonus_at_level(level: &Level) -> ProficiencyBonus {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::skill::{ACROBATICS, ATHLETICS, STEALTH};
+
+    #[test]
+    fn skill_choice_validation() {
+        let choice = SkillChoice {
+            count: 2,
+            among: &[ACROBATICS, ATHLETICS],
+        };
+        assert!(choice.is_valid(&[]));
+        assert!(choice.is_valid(&[ATHLETICS, ACROBATICS]));
+        assert!(!choice.is_valid(&[STEALTH]));
+        assert!(!choice.is_valid(&[ATHLETICS, ATHLETICS]));
+    }
 
     #[test]
     fn standard_proficiency_bonus() {
@@ -0,0 +1,68 @@
+//! Monk class.
+
+use crate::ability::{AbilityId, DEXTERITY, STRENGTH, WISDOM};
+use crate::character::class::{ClassModel, SkillChoice};
+use crate::dice::Die;
+use crate::equipment::weapon::SHORTSWORD;
+use crate::equipment::{UnarmoredDefense, WeaponCategory, WeaponProficiency};
+use crate::hit_points::HitDice;
+use crate::skill::*;
+
+/// Id of the Monk class.
+///
+/// # Reference
+///
+/// **Hit Dice:** 1d8 per monk level\
+/// **Hit Points at 1st Level:** 8 + your Constitution modifier\
+/// **Hit Points at Higher Levels:** 1d8 (or 5) + your Constitution modifier per monk level
+/// after 1st
+///
+/// **Armor:** None\
+/// **Weapons:** Simple weapons, shortswords\
+/// **Tools:** Choose one type of artisan's tools or one musical instrument\
+/// **Saving Throws:** Strength, Dexterity\
+/// **Skills:** Choose two from Acrobatics, Athletics, History, Insight, Religion, and Stealth
+///
+/// |Level|Proficiency Bonus|Martial Arts|Ki Points|Unarmored Movement|Features|
+/// |:---:|:---------------:|:----------:|:-------:|:----------------:|--------|
+/// |1st|+2|1d4|—|—|Unarmored Defense, Martial Arts
+pub const MONK: &str = "_monk";
+
+#[derive(Default)]
+/// Models the Monk class.
+pub(crate) struct MonkModel {}
+
+impl ClassModel for MonkModel {
+    fn hit_dice(&self) -> HitDice {
+        HitDice::new(1, Die::D8)
+    }
+
+    fn saving_throws(&self) -> &[AbilityId] {
+        &[STRENGTH, DEXTERITY]
+    }
+
+    fn weapon_proficiencies(&self) -> &[WeaponProficiency] {
+        &[
+            WeaponProficiency::Category(WeaponCategory::Simple),
+            WeaponProficiency::Weapon(SHORTSWORD),
+        ]
+    }
+
+    /// # Reference
+    ///
+    /// Beginning at 1st level, while you are wearing no armor and not wielding a shield, your AC
+    /// equals 10 + your Dexterity modifier + your Wisdom modifier.
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        Some(UnarmoredDefense {
+            ability: WISDOM,
+            allows_shield: false,
+        })
+    }
+
+    fn skill_choice(&self) -> SkillChoice<'_> {
+        SkillChoice {
+            count: 2,
+            among: &[ACROBATICS, ATHLETICS, HISTORY, INSIGHT, RELIGION, STEALTH],
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Feats.
+
+use crate::ability::{AbilityId, STRENGTH};
+use serde::{Deserialize, Serialize};
+
+/// A feat represents a talent or an area of expertise that gives a character special
+/// capabilities.
+///
+/// # Reference
+///
+/// At certain levels, your class gives you the Ability Score Improvement feature. Using the
+/// optional feats rule, you can forgo taking that feature to take a feat of your choice instead.
+/// You must meet any prerequisite specified in a feat to take that feat.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub enum Feat {
+    /// You've developed the skills necessary to hold your own in close-quarters grappling.
+    ///
+    /// # Reference
+    ///
+    /// Prerequisite: Strength 13 or higher.\
+    /// You have advantage on attack rolls against a creature you are grappling.
+    Grappler,
+}
+
+impl Feat {
+    /// Returns the ability and the minimum score required to take this feat, if any.
+    pub const fn prerequisite(&self) -> Option<(AbilityId, u8)> {
+        match self {
+            Self::Grappler => Some((STRENGTH, 13)),
+        }
+    }
+}
@@ -1,7 +1,10 @@
 //! Playable and non-playable characters.
 
 pub mod class;
-pub use self::class::{ClassId, ClassModel};
+pub use self::class::{ClassId, ClassModel, FightingStyle, SkillChoice};
+
+pub mod feat;
+pub use self::feat::Feat;
 
 pub mod level;
 pub use self::level::Level;
@@ -11,13 +14,25 @@ pub use self::race::{RaceId, RaceModel};
 
 mod spawn;
 
+use self::class::DEFENSE_STYLE_BONUS;
 use self::spawn::CharacterSpawner;
-use crate::ability::{AbilityId, AbilityScore, CONSTITUTION, DEFAULT_ABILITY_SCORE};
+use crate::ability::{
+    AbilityId, AbilityScore, CONSTITUTION, DEFAULT_ABILITY_SCORE, DEXTERITY, STRENGTH,
+};
 use crate::compendium::compendium;
+use crate::damage::{DamageDefense, DamageType};
+use crate::equipment::armor::{HEAVY_ARMOR_SPEED_PENALTY_FT, SHIELD_BONUS};
+use crate::equipment::{
+    Armor, ArmorCategory, ArmorClass, Weapon, WeaponId, WeaponProficiency, WeaponSlots,
+};
 use crate::error::{SRDError, SRDResult};
-use crate::handle::creature_handle::CreatureHandleMut;
+use crate::handle::creature_handle::{CreatureHandle, CreatureHandleMut};
 use crate::hit_points::{HitPoints, HitPointsHistory};
-use crate::proficiency::{Proficiency, DEFAULT_PROFICIENCY};
+use crate::proficiency::{Proficiency, ProficiencyBonus, DEFAULT_PROFICIENCY};
+use crate::rules::core::attack::{AttackProfile, CriticalHit};
+use crate::rules::core::battlegrid::Square;
+use crate::rules::core::movement::Speeds;
+use crate::rules::team_rules::GLOBAL_TEAM_ID;
 use crate::rules::SRDRules;
 use crate::skill::SkillId;
 use serde::{Deserialize, Serialize};
@@ -56,6 +71,30 @@ pub struct Character {
     abilities: HashMap<AbilityId, AbilityScore>,
     /// The character's proficiency in skills.
     skills: HashMap<SkillId, Proficiency>,
+    /// The skills chosen among those offered by the class.
+    class_skills: Vec<SkillId>,
+    /// The character's proficiency in ability checks that don't use a skill.
+    ability_checks: HashMap<AbilityId, Proficiency>,
+    /// The character's own defenses against damage, on top of racial and class ones.
+    damage_defenses: HashMap<DamageType, DamageDefense>,
+    /// Overrides the critical hit rules given by race and class.
+    critical_hit: Option<CriticalHit>,
+    /// The armor worn by the character.
+    armor: Option<Armor>,
+    /// Whether or not the character wields a shield.
+    shield: bool,
+    /// The weapon held in the character's main hand.
+    main_hand: Option<WeaponId>,
+    /// The weapon held in the character's off hand.
+    off_hand: Option<WeaponId>,
+    /// The pieces of ammunition carried by the character.
+    ammunition: u16,
+    /// The fighting styles adopted by the character.
+    fighting_styles: Vec<FightingStyle>,
+    /// The feats taken by the character.
+    feats: Vec<Feat>,
+    /// The team the character fights for.
+    team: u8,
 }
 
 impl Character {
@@ -87,6 +126,18 @@ impl Character {
             hit_points_history,
             abilities: HashMap::new(),
             skills: HashMap::new(),
+            class_skills: Vec::new(),
+            ability_checks: HashMap::new(),
+            damage_defenses: HashMap::new(),
+            critical_hit: None,
+            armor: None,
+            shield: false,
+            main_hand: None,
+            off_hand: None,
+            ammunition: 0,
+            fighting_styles: Vec::new(),
+            feats: Vec::new(),
+            team: GLOBAL_TEAM_ID,
         };
         // Add default abilities and skills.
         Self::add_default_abilities(&mut instance);
@@ -135,6 +186,226 @@ impl Character {
         &self.hit_points
     }
 
+    /// Returns the armor worn by the character, if any.
+    pub fn armor(&self) -> Option<Armor> {
+        self.armor
+    }
+
+    /// Returns true if the character wields a shield.
+    pub fn shield(&self) -> bool {
+        self.shield
+    }
+
+    /// Returns the weapon held in the character's main hand, if any.
+    pub fn main_hand(&self) -> Option<WeaponId> {
+        self.main_hand
+    }
+
+    /// Returns the weapon held in the character's off hand, if any.
+    pub fn off_hand(&self) -> Option<WeaponId> {
+        self.off_hand
+    }
+
+    /// Returns the weapons held in the character's hands.
+    pub fn weapon_slots(&self) -> WeaponSlots {
+        WeaponSlots {
+            main_hand: self.main_hand,
+            off_hand: self.off_hand,
+            shield: self.shield,
+        }
+    }
+
+    /// Returns the pieces of ammunition carried by the character.
+    pub fn ammunition(&self) -> u16 {
+        self.ammunition
+    }
+
+    /// Returns the weapon proficiencies granted by the character's race and class.
+    pub fn weapon_proficiencies(&self) -> Vec<WeaponProficiency> {
+        let class_model = compendium()
+            .class_model(&self.class)
+            .expect("class model not found");
+        let race_model = compendium()
+            .race_model(&self.race)
+            .expect("race model not found");
+        let mut proficiencies: Vec<WeaponProficiency> = Vec::new();
+        for proficiency in class_model
+            .weapon_proficiencies()
+            .iter()
+            .chain(race_model.weapon_proficiencies())
+        {
+            if !proficiencies.contains(proficiency) {
+                proficiencies.push(*proficiency);
+            }
+        }
+        proficiencies
+    }
+
+    /// Returns how the character attacks: with the weapon in its main hand, or with an
+    /// unarmed strike.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the weapon doesn't exist or if it requires two hands while the
+    /// character wields a shield or an off-hand weapon.
+    pub fn attack_profile(&self) -> SRDResult<AttackProfile> {
+        let attacks = compendium()
+            .class_model(&self.class)
+            .expect("class model not found")
+            .attacks_per_action(&self.level);
+        AttackProfile::main_hand(
+            &self.weapon_slots(),
+            |id, weapon| self.is_proficient_with(id, weapon),
+            attacks,
+        )
+    }
+
+    /// Returns how the character attacks with the weapon in its off hand, if it holds one.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the weapon doesn't exist or if the character also wields a
+    /// shield.
+    pub fn off_hand_attack_profile(&self) -> SRDResult<Option<AttackProfile>> {
+        AttackProfile::off_hand_weapon(
+            &self.weapon_slots(),
+            |id, weapon| self.is_proficient_with(id, weapon),
+            self.fighting_styles
+                .contains(&FightingStyle::TwoWeaponFighting),
+        )
+    }
+
+    /// Returns true if the character is proficient with `weapon`.
+    fn is_proficient_with(&self, id: WeaponId, weapon: &Weapon) -> bool {
+        self.weapon_proficiencies().iter().any(|proficiency| {
+            *proficiency == WeaponProficiency::Weapon(id)
+                || *proficiency == WeaponProficiency::Category(weapon.category)
+        })
+    }
+
+    /// Returns an iterator over the fighting styles adopted by the character.
+    pub fn fighting_styles(&self) -> impl Iterator<Item = &FightingStyle> {
+        self.fighting_styles.iter()
+    }
+
+    /// Returns an iterator over the feats taken by the character.
+    pub fn feats(&self) -> impl Iterator<Item = &Feat> {
+        self.feats.iter()
+    }
+
+    /// Returns the character's armor class, computed from its armor, shield, Dexterity and
+    /// class features.
+    pub fn armor_class(&self) -> ArmorClass {
+        let dexterity = self.ability(DEXTERITY).unwrap_or(DEFAULT_ABILITY_SCORE);
+        let unarmored_defense = compendium()
+            .class_model(&self.class)
+            .expect("class model not found")
+            .unarmored_defense();
+        let ArmorClass(mut armor_class) = match (self.armor, unarmored_defense) {
+            (Some(armor), _) => armor.armor_class(dexterity),
+            (None, Some(defense)) if defense.allows_shield || !self.shield => {
+                ArmorClass::unarmored_defense(
+                    dexterity,
+                    self.ability(defense.ability)
+                        .unwrap_or(DEFAULT_ABILITY_SCORE),
+                )
+            }
+            (None, _) => ArmorClass::unarmored(dexterity),
+        };
+        if self.shield {
+            armor_class += SHIELD_BONUS;
+        }
+        if self.armor.is_some() && self.fighting_styles.contains(&FightingStyle::Defense) {
+            armor_class += DEFENSE_STYLE_BONUS;
+        }
+        ArmorClass(armor_class)
+    }
+
+    /// Returns the character's speeds. Heavy armor slows down characters lacking the
+    /// Strength to wear it.
+    pub fn speeds(&self) -> Speeds {
+        let race_model = compendium()
+            .race_model(&self.race)
+            .expect("race model not found");
+        let mut speeds = race_model.speeds();
+        let strength = self.ability(STRENGTH).unwrap_or(DEFAULT_ABILITY_SCORE);
+        if self.armor.is_some_and(|armor| armor.slows(strength))
+            && !race_model.ignores_heavy_armor_speed_penalty()
+        {
+            speeds.walk = speeds.walk.saturating_sub(HEAVY_ARMOR_SPEED_PENALTY_FT);
+        }
+        speeds
+    }
+
+    /// Returns the team the character fights for.
+    pub fn team(&self) -> u8 {
+        self.team
+    }
+
+    /// Returns how the character scores critical hits.
+    pub fn critical_hit(&self) -> CriticalHit {
+        if let Some(critical_hit) = self.critical_hit {
+            return critical_hit;
+        }
+        let mut critical_hit = compendium()
+            .class_model(&self.class)
+            .expect("class model not found")
+            .critical_hit(&self.level);
+        critical_hit.extra_dice += compendium()
+            .race_model(&self.race)
+            .expect("race model not found")
+            .extra_critical_dice();
+        critical_hit
+    }
+
+    /// Returns the character's proficiency bonus.
+    pub fn proficiency_bonus(&self) -> ProficiencyBonus {
+        compendium()
+            .class_model(&self.class)
+            .expect("class model not found")
+            .proficiency_bonus(&self.level)
+    }
+
+    /// Returns the character's score (with bonuses) in the given ability, or `None` if
+    /// the character doesn't have such ability.
+    pub fn ability(&self, id: AbilityId) -> Option<AbilityScore> {
+        self.abilities()
+            .find(|(ability_id, _)| *ability_id == id)
+            .map(|(_, score)| score)
+    }
+
+    /// Returns the character's proficiency in the given skill, or `None` if
+    /// the character doesn't have such skill.
+    pub fn skill(&self, id: SkillId) -> Option<Proficiency> {
+        self.skills()
+            .find(|(skill_id, _)| *skill_id == id)
+            .map(|(_, p)| p)
+    }
+
+    /// Returns the skills chosen among those offered by the character's class.
+    pub fn class_skills(&self) -> &[SkillId] {
+        &self.class_skills
+    }
+
+    /// Returns the character's proficiency in checks of the given ability that don't use a
+    /// skill.
+    pub fn ability_check(&self, id: AbilityId) -> Proficiency {
+        self.ability_checks
+            .get(&id)
+            .copied()
+            .unwrap_or(DEFAULT_PROFICIENCY)
+    }
+
+    /// Returns the character's proficiency in saving throws of the given ability.
+    pub fn saving_throw(&self, id: AbilityId) -> Proficiency {
+        compendium()
+            .class_model(&self.class)
+            .expect("class model not found")
+            .saving_throws()
+            .contains(&id)
+            .into()
+    }
+
     /// Returns an iterator over the character's base ability scores.
     pub fn raw_abilities(&self) -> impl Iterator<Item = (AbilityId, AbilityScore)> + '_ {
         self.abilities.iter().map(|(k, v)| (*k, *v))
@@ -157,9 +428,39 @@ impl Character {
         })
     }
 
-    /// Returns an iterator over the character's skill proficiencies.
+    /// Returns an iterator over the character's skill proficiencies.\
+    /// Skills chosen from the class grant at least proficiency.
     pub fn skills(&self) -> impl Iterator<Item = (SkillId, Proficiency)> + '_ {
-        self.skills.iter().map(|(k, v)| (*k, *v))
+        self.skills.iter().map(move |(k, v)| {
+            if self.class_skills.contains(k) {
+                (*k, (*v).max(Proficiency::Proficient))
+            } else {
+                (*k, *v)
+            }
+        })
+    }
+
+    /// Returns the character's defense against the given damage type, if any.
+    pub fn damage_defense(&self, damage_type: DamageType) -> Option<DamageDefense> {
+        self.damage_defenses()
+            .find(|(id, _)| *id == damage_type)
+            .map(|(_, defense)| defense)
+    }
+
+    /// Returns an iterator over the character's defenses against damage types.\
+    /// Defenses added to the character take precedence over racial and class ones.
+    pub fn damage_defenses(&self) -> impl Iterator<Item = (DamageType, DamageDefense)> + '_ {
+        let mut defenses: HashMap<DamageType, DamageDefense> = HashMap::new();
+        let race_model = compendium()
+            .race_model(&self.race)
+            .expect("race model not found");
+        let class_model = compendium()
+            .class_model(&self.class)
+            .expect("class model not found");
+        defenses.extend(race_model.damage_defenses().iter().copied());
+        defenses.extend(class_model.damage_defenses().iter().copied());
+        defenses.extend(self.damage_defenses.iter().map(|(k, v)| (*k, *v)));
+        defenses.into_iter()
     }
 
     /// Adds or replaces one ability.
@@ -183,6 +484,172 @@ impl Character {
         self
     }
 
+    /// Chooses the skill proficiencies granted by the character's class, replacing any previous
+    /// choice.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the class doesn't offer the skills, or if too many are chosen.
+    ///
+    /// # Reference
+    ///
+    /// Skills: Choose two from Acrobatics, Animal Handling, Athletics, History, Insight,
+    /// Intimidation, Perception, and Survival.
+    pub fn choose_class_skills(&mut self, skills: &[SkillId]) -> SRDResult<&mut Self> {
+        let class_model = compendium()
+            .class_model(&self.class)
+            .ok_or_else(|| SRDError::ClassNotFound(self.class.clone()))?;
+        if !class_model.skill_choice().is_valid(skills) {
+            return Err(SRDError::InvalidSkillChoice(skills.to_vec()));
+        }
+        self.class_skills = skills.to_vec();
+        Ok(self)
+    }
+
+    /// Adds or replaces the proficiency in checks of one ability that don't use a skill, such as
+    /// the half proficiency bonus granted by Jack of All Trades or Remarkable Athlete.
+    pub fn add_ability_check<A, P>(&mut self, ability: A, proficiency: P) -> &mut Self
+    where
+        A: Into<AbilityId>,
+        P: Into<Proficiency>,
+    {
+        self.ability_checks
+            .insert(ability.into(), proficiency.into());
+        self
+    }
+
+    /// Sets how the character scores critical hits, replacing the rules given by
+    /// race and class.
+    pub fn set_critical_hit(&mut self, critical_hit: CriticalHit) -> &mut Self {
+        self.critical_hit = Some(critical_hit);
+        self
+    }
+
+    /// Sets the team the character fights for. Characters of different teams are enemies.
+    pub fn set_team(&mut self, team: u8) -> &mut Self {
+        self.team = team;
+        self
+    }
+
+    /// Puts on `armor`, replacing the current one, or takes off the armor if `None`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `armor` is a shield, which is carried with `equip_shield` instead.
+    pub fn wear_armor(&mut self, armor: Option<Armor>) -> SRDResult<&mut Self> {
+        if armor.is_some_and(|armor| armor.category == ArmorCategory::Shield) {
+            return Err(SRDError::InvalidArgument(
+                "shields are not worn as armor".to_string(),
+            ));
+        }
+        self.armor = armor;
+        Ok(self)
+    }
+
+    /// Equips or unequips a shield.
+    pub fn equip_shield(&mut self, shield: bool) -> &mut Self {
+        self.shield = shield;
+        self
+    }
+
+    /// Takes `weapon` in the main hand, or leaves it empty if `None`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the weapon doesn't exist in the compendium.
+    pub fn wield(&mut self, weapon: Option<WeaponId>) -> SRDResult<&mut Self> {
+        if let Some(id) = weapon {
+            compendium()
+                .weapon(&id)
+                .ok_or(SRDError::WeaponNotFound(id))?;
+        }
+        self.main_hand = weapon;
+        Ok(self)
+    }
+
+    /// Takes `weapon` in the off hand, or leaves it empty if `None`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the weapon doesn't exist in the compendium.
+    pub fn wield_off_hand(&mut self, weapon: Option<WeaponId>) -> SRDResult<&mut Self> {
+        if let Some(id) = weapon {
+            compendium()
+                .weapon(&id)
+                .ok_or(SRDError::WeaponNotFound(id))?;
+        }
+        self.off_hand = weapon;
+        Ok(self)
+    }
+
+    /// Sets the pieces of ammunition carried by the character.
+    pub fn set_ammunition(&mut self, ammunition: u16) -> &mut Self {
+        self.ammunition = ammunition;
+        self
+    }
+
+    /// Searches the battlefield at the end of a battle, keeping the ammunition the character's
+    /// creature has left plus half of the ammunition it expended.
+    ///
+    /// # Reference
+    ///
+    /// At the end of the battle, you can recover half your expended ammunition by taking a minute
+    /// to search the battlefield.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the character's creature is not in the battle.
+    pub fn recover_ammunition(&mut self, server: &Server<SRDRules>) -> SRDResult<&mut Self> {
+        let handle = CreatureHandle::new(&self.id, server);
+        match handle.ammunition() {
+            Ok(ammunition) => self.ammunition = ammunition.recovered(),
+            Err(SRDError::StatisticNotFound(_)) => {
+                // The creature had no ammunition, but it must still exist.
+                handle.position()?;
+            }
+            Err(err) => return Err(err),
+        }
+        Ok(self)
+    }
+
+    /// Adopts a fighting style.
+    pub fn add_fighting_style(&mut self, style: FightingStyle) -> &mut Self {
+        if !self.fighting_styles.contains(&style) {
+            self.fighting_styles.push(style);
+        }
+        self
+    }
+
+    /// Takes a feat.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the character doesn't meet the feat's prerequisite.
+    pub fn add_feat(&mut self, feat: Feat) -> SRDResult<&mut Self> {
+        if let Some((ability, minimum)) = feat.prerequisite() {
+            if self.ability(ability).map_or(0, |score| score.value()) < minimum {
+                return Err(SRDError::InvalidArgument(format!(
+                    "{:?} requires a score of {} in {:?}",
+                    feat, minimum, ability
+                )));
+            }
+        }
+        if !self.feats.contains(&feat) {
+            self.feats.push(feat);
+        }
+        Ok(self)
+    }
+
+    /// Adds or replaces the defense against one damage type.
+    pub fn add_damage_defense(
+        &mut self,
+        damage_type: DamageType,
+        defense: DamageDefense,
+    ) -> &mut Self {
+        self.damage_defenses.insert(damage_type, defense);
+        self
+    }
+
     /// Applies ability bonuses to the character's statistics.
     fn apply_ability_bonuses(&mut self) {
         self.apply_constitution_bonus();
@@ -215,16 +682,32 @@ impl Character {
     }
 
     /// Spawns a character in the given battle and returns an handler to it.
-    /// The character is guaranteed to be compliant with the rules contained in the current Compendium.
+    /// The character is guaranteed to be compliant with the rules contained in the current Compendium.\
+    /// The character is placed in the first free space of the battle grid.
     ///
     /// # Errors
     ///
-    /// An error is returned if the character is invalid.
+    /// An error is returned if the character is invalid or if there's no space left.
     pub fn spawn<'a>(
         &'a self,
         server: &'a mut Server<SRDRules>,
     ) -> SRDResult<CreatureHandleMut<'a, Server<SRDRules>>> {
-        CharacterSpawner::new(&self).spawn(server)?;
+        CharacterSpawner::new(self).spawn(server, None)?;
+        Ok(CreatureHandleMut::new(&self.id, server))
+    }
+
+    /// Spawns a character in the given battle, with the corner of its space at `origin`,
+    /// and returns an handler to it.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the character is invalid or if its space can't be occupied.
+    pub fn spawn_at<'a>(
+        &'a self,
+        server: &'a mut Server<SRDRules>,
+        origin: Square,
+    ) -> SRDResult<CreatureHandleMut<'a, Server<SRDRules>>> {
+        CharacterSpawner::new(self).spawn(server, Some(origin))?;
         Ok(CreatureHandleMut::new(&self.id, server))
     }
 }
@@ -245,12 +728,19 @@ impl Hash for Character {
 
 #[cfg(test)]
 mod tests {
+    use self::class::barbarian::BARBARIAN;
     use self::class::fighter::FIGHTER;
+    use self::class::monk::MONK;
     use self::race::hill_dwarf::HILL_DWARF;
     use super::*;
     use crate::ability::RESERVED_ABILITIES;
+    use crate::ability::WISDOM;
     use crate::compendium::init_srd_compendium;
-    use crate::skill::RESERVED_SKILLS;
+    use crate::dice::Die;
+    use crate::equipment::armor::{HALF_PLATE, HIDE, LEATHER, PLATE};
+    use crate::equipment::weapon::{DAGGER, GREATSWORD, LONGSWORD, RAPIER, SHORTSWORD, WARHAMMER};
+    use crate::equipment::WeaponCategory;
+    use crate::skill::{ATHLETICS, HISTORY, PERCEPTION, RESERVED_SKILLS, STEALTH};
 
     #[test]
     fn character_equality() {
@@ -263,6 +753,30 @@ mod tests {
         assert_eq!(c1, c3);
     }
 
+    #[test]
+    fn class_skill_choice() {
+        let _ = init_srd_compendium();
+        let mut c = Character::new("one", HILL_DWARF, FIGHTER).unwrap();
+        assert!(c.choose_class_skills(&[ATHLETICS, PERCEPTION]).is_ok());
+        assert_eq!(c.class_skills(), &[ATHLETICS, PERCEPTION]);
+        assert_eq!(c.skill(ATHLETICS), Some(Proficiency::Proficient));
+        assert_eq!(c.skill(STEALTH), Some(DEFAULT_PROFICIENCY));
+        // Too many skills.
+        assert_eq!(
+            c.choose_class_skills(&[ATHLETICS, PERCEPTION, HISTORY])
+                .err(),
+            Some(SRDError::InvalidSkillChoice(vec![
+                ATHLETICS, PERCEPTION, HISTORY
+            ]))
+        );
+        // Fighters can't choose Stealth.
+        assert!(c.choose_class_skills(&[STEALTH]).is_err());
+        assert_eq!(c.class_skills(), &[ATHLETICS, PERCEPTION]);
+        // A new choice replaces the previous one.
+        c.choose_class_skills(&[HISTORY]).unwrap();
+        assert_eq!(c.skill(ATHLETICS), Some(DEFAULT_PROFICIENCY));
+    }
+
     #[test]
     fn character_has_default_abilities_skills() {
         let _ = init_srd_compendium();
@@ -282,4 +796,153 @@ mod tests {
         c.add_ability(CONSTITUTION, AbilityScore::capped(20));
         assert_eq!(c.hit_points().value(), 16);
     }
+
+    #[test]
+    fn damage_defenses() {
+        let _ = init_srd_compendium();
+        let mut c = Character::new("one", HILL_DWARF, FIGHTER).unwrap();
+        // Dwarven Resilience.
+        assert_eq!(
+            c.damage_defense(DamageType::Poison),
+            Some(DamageDefense::Resistance)
+        );
+        assert_eq!(c.damage_defense(DamageType::Fire), None);
+        c.add_damage_defense(DamageType::Fire, DamageDefense::Vulnerability)
+            .add_damage_defense(DamageType::Poison, DamageDefense::Immunity);
+        assert_eq!(
+            c.damage_defense(DamageType::Fire),
+            Some(DamageDefense::Vulnerability)
+        );
+        assert_eq!(
+            c.damage_defense(DamageType::Poison),
+            Some(DamageDefense::Immunity)
+        );
+        assert_eq!(c.damage_defenses().count(), 2);
+    }
+
+    #[test]
+    fn armor_class_calculation() {
+        let _ = init_srd_compendium();
+        let mut c = Character::new("one", HILL_DWARF, FIGHTER).unwrap();
+        c.add_ability(DEXTERITY, AbilityScore::capped(16));
+        assert_eq!(c.armor_class(), ArmorClass(13));
+        c.wear_armor(Some(LEATHER)).unwrap().equip_shield(true);
+        assert_eq!(c.armor_class(), ArmorClass(16));
+        c.wear_armor(Some(HALF_PLATE)).unwrap();
+        assert_eq!(c.armor_class(), ArmorClass(19));
+        c.add_fighting_style(FightingStyle::Defense);
+        assert_eq!(c.armor_class(), ArmorClass(20));
+        assert!(c
+            .wear_armor(Some(Armor {
+                category: ArmorCategory::Shield,
+                ..LEATHER
+            }))
+            .is_err());
+        // Defense only applies while wearing armor.
+        c.wear_armor(None).unwrap().equip_shield(false);
+        assert_eq!(c.armor_class(), ArmorClass(13));
+    }
+
+    #[test]
+    fn unarmored_defense() {
+        let _ = init_srd_compendium();
+        let mut barbarian = Character::new("one", HILL_DWARF, BARBARIAN).unwrap();
+        barbarian
+            .add_ability(DEXTERITY, AbilityScore::capped(14))
+            .add_ability(CONSTITUTION, AbilityScore::capped(14));
+        // Hill dwarves get +2 Constitution.
+        assert_eq!(barbarian.armor_class(), ArmorClass(15));
+        barbarian.equip_shield(true);
+        assert_eq!(barbarian.armor_class(), ArmorClass(17));
+        barbarian.wear_armor(Some(HIDE)).unwrap();
+        assert_eq!(barbarian.armor_class(), ArmorClass(16));
+        let mut monk = Character::new("two", HILL_DWARF, MONK).unwrap();
+        monk.add_ability(DEXTERITY, AbilityScore::capped(16))
+            .add_ability(WISDOM, AbilityScore::capped(14));
+        assert_eq!(monk.armor_class(), ArmorClass(15));
+        // Monks lose Unarmored Defense with a shield.
+        monk.equip_shield(true);
+        assert_eq!(monk.armor_class(), ArmorClass(15));
+    }
+
+    #[test]
+    fn heavy_armor_strength() {
+        let _ = init_srd_compendium();
+        let mut c = Character::new("one", HILL_DWARF, FIGHTER).unwrap();
+        c.add_ability(STRENGTH, AbilityScore::capped(13));
+        c.wear_armor(Some(PLATE)).unwrap();
+        // Dwarves aren't slowed by heavy armor.
+        assert_eq!(c.speeds().walk, 25);
+    }
+
+    #[test]
+    fn feat_prerequisites() {
+        let _ = init_srd_compendium();
+        let mut c = Character::new("one", HILL_DWARF, FIGHTER).unwrap();
+        assert!(c.add_feat(Feat::Grappler).is_err());
+        c.add_ability(STRENGTH, AbilityScore::capped(13));
+        c.add_feat(Feat::Grappler).unwrap();
+        c.add_feat(Feat::Grappler).unwrap();
+        assert_eq!(c.feats().collect::<Vec<_>>(), vec![&Feat::Grappler]);
+    }
+
+    #[test]
+    fn weapon_attack_profile() {
+        let _ = init_srd_compendium();
+        let mut c = Character::new("one", HILL_DWARF, MONK).unwrap();
+        assert_eq!(c.attack_profile(), Ok(AttackProfile::unarmed_strike()));
+        assert!(c.wield(Some(WeaponId(u8::MAX))).is_err());
+        // Dwarven Combat Training.
+        c.wield(Some(WARHAMMER)).unwrap();
+        let profile = c.attack_profile().unwrap();
+        assert!(profile.proficient);
+        assert_eq!(profile.weapon, Some(WARHAMMER));
+        assert_eq!(profile.damage[0].roll.dice_pool.count(Die::D10), 1);
+        c.wield(Some(RAPIER)).unwrap();
+        let profile = c.attack_profile().unwrap();
+        assert!(!profile.proficient);
+        assert!(profile.finesse);
+        // Versatile weapons are wielded with one hand when carrying a shield.
+        let mut fighter = Character::new("two", HILL_DWARF, FIGHTER).unwrap();
+        fighter.wield(Some(LONGSWORD)).unwrap().equip_shield(true);
+        let profile = fighter.attack_profile().unwrap();
+        assert_eq!(profile.damage[0].roll.dice_pool.count(Die::D8), 1);
+        fighter.wield(Some(GREATSWORD)).unwrap();
+        assert!(fighter.attack_profile().is_err());
+        fighter.equip_shield(false);
+        assert!(fighter.attack_profile().is_ok());
+        assert!(fighter
+            .weapon_proficiencies()
+            .contains(&WeaponProficiency::Category(WeaponCategory::Martial)));
+    }
+
+    #[test]
+    fn off_hand_attack_profile() {
+        let _ = init_srd_compendium();
+        let mut c = Character::new("one", HILL_DWARF, FIGHTER).unwrap();
+        assert_eq!(c.off_hand_attack_profile(), Ok(None));
+        assert!(c.wield_off_hand(Some(WeaponId(u8::MAX))).is_err());
+        c.wield(Some(SHORTSWORD))
+            .unwrap()
+            .wield_off_hand(Some(DAGGER))
+            .unwrap();
+        assert_eq!(
+            c.weapon_slots(),
+            WeaponSlots {
+                main_hand: Some(SHORTSWORD),
+                off_hand: Some(DAGGER),
+                shield: false,
+            }
+        );
+        let profile = c.off_hand_attack_profile().unwrap().unwrap();
+        assert_eq!(profile.weapon, Some(DAGGER));
+        assert!(!profile.ability_damage);
+        c.add_fighting_style(FightingStyle::TwoWeaponFighting);
+        assert!(c.off_hand_attack_profile().unwrap().unwrap().ability_damage);
+        // Two-handed weapons need a free off hand, and shields take it.
+        c.wield(Some(GREATSWORD)).unwrap();
+        assert!(c.attack_profile().is_err());
+        c.equip_shield(true);
+        assert!(c.off_hand_attack_profile().is_err());
+    }
 }
@@ -2,11 +2,21 @@
 
 use crate::ability::{AbilityScore, CONSTITUTION};
 use crate::character::race::generic_model::GenericRaceModel;
+use crate::damage::{DamageDefense, DamageType};
+use crate::equipment::weapon::{BATTLEAXE, HANDAXE, LIGHT_HAMMER, WARHAMMER};
+use crate::equipment::WeaponProficiency;
+use crate::rules::core::movement::MovementMode;
 use crate::rules::core::size::CreatureSize;
 
 /// Returns the race model for Dwarf.
 pub fn dwarf_model() -> GenericRaceModel {
     let mut model = GenericRaceModel::new(CreatureSize::Medium);
     model.add_ability_score_increase(CONSTITUTION, AbilityScore::capped(2));
+    model.set_speed(MovementMode::Walk, 25);
+    model.set_ignores_heavy_armor_speed_penalty(true);
+    model.add_damage_defense(DamageType::Poison, DamageDefense::Resistance);
+    for weapon in [BATTLEAXE, HANDAXE, LIGHT_HAMMER, WARHAMMER] {
+        model.add_weapon_proficiency(WeaponProficiency::Weapon(weapon));
+    }
     model
 }
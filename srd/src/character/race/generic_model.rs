@@ -2,6 +2,9 @@
 
 use crate::ability::{AbilityId, AbilityScore};
 use crate::character::race::RaceModel;
+use crate::damage::{DamageDefense, DamageType};
+use crate::equipment::WeaponProficiency;
+use crate::rules::core::movement::{MovementMode, Speeds};
 use crate::rules::core::size::CreatureSize;
 
 /// A generic race model implementation.
@@ -10,6 +13,16 @@ pub struct GenericRaceModel {
     #[new(default)]
     ability_score_increases: Vec<(AbilityId, AbilityScore)>,
     size: CreatureSize,
+    #[new(default)]
+    speeds: Speeds,
+    #[new(default)]
+    damage_defenses: Vec<(DamageType, DamageDefense)>,
+    #[new(default)]
+    extra_critical_dice: u8,
+    #[new(default)]
+    ignores_heavy_armor_speed_penalty: bool,
+    #[new(default)]
+    weapon_proficiencies: Vec<WeaponProficiency>,
 }
 
 impl GenericRaceModel {
@@ -18,6 +31,42 @@ impl GenericRaceModel {
         self.ability_score_increases.push((id, score));
         self
     }
+
+    /// Sets the speed, in feet, of a movement mode.
+    pub fn set_speed(&mut self, mode: MovementMode, speed: u16) -> &mut Self {
+        self.speeds.set(mode, speed);
+        self
+    }
+
+    /// Sets whether or not heavy armor reduces the speed of creatures without the required
+    /// Strength.
+    pub fn set_ignores_heavy_armor_speed_penalty(&mut self, ignores: bool) -> &mut Self {
+        self.ignores_heavy_armor_speed_penalty = ignores;
+        self
+    }
+
+    /// Sets the number of additional weapon damage dice rolled on a critical hit with a melee
+    /// attack.
+    pub fn set_extra_critical_dice(&mut self, extra_dice: u8) -> &mut Self {
+        self.extra_critical_dice = extra_dice;
+        self
+    }
+
+    /// Adds a new defense against a damage type.
+    pub fn add_damage_defense(
+        &mut self,
+        damage_type: DamageType,
+        defense: DamageDefense,
+    ) -> &mut Self {
+        self.damage_defenses.push((damage_type, defense));
+        self
+    }
+
+    /// Adds a new weapon proficiency.
+    pub fn add_weapon_proficiency(&mut self, proficiency: WeaponProficiency) -> &mut Self {
+        self.weapon_proficiencies.push(proficiency);
+        self
+    }
 }
 
 impl RaceModel for GenericRaceModel {
@@ -28,6 +77,26 @@ impl RaceModel for GenericRaceModel {
     fn size(&self) -> CreatureSize {
         self.size
     }
+
+    fn speeds(&self) -> Speeds {
+        self.speeds
+    }
+
+    fn damage_defenses(&self) -> &[(DamageType, DamageDefense)] {
+        &self.damage_defenses
+    }
+
+    fn ignores_heavy_armor_speed_penalty(&self) -> bool {
+        self.ignores_heavy_armor_speed_penalty
+    }
+
+    fn extra_critical_dice(&self) -> u8 {
+        self.extra_critical_dice
+    }
+
+    fn weapon_proficiencies(&self) -> &[WeaponProficiency] {
+        &self.weapon_proficiencies
+    }
 }
 
 #[cfg(test)]
@@ -41,5 +110,14 @@ mod tests {
         model.add_ability_score_increase(AbilityId(2), AbilityScore::capped(2));
         assert_eq!(model.ability_score_increases().len(), 2);
         assert_eq!(model.size(), CreatureSize::Medium);
+        assert_eq!(model.speeds(), Speeds::default());
+        model.set_speed(MovementMode::Walk, 25);
+        assert_eq!(model.speeds().walk, 25);
+        assert!(model.damage_defenses().is_empty());
+        model.add_damage_defense(DamageType::Poison, DamageDefense::Resistance);
+        assert_eq!(
+            model.damage_defenses(),
+            &[(DamageType::Poison, DamageDefense::Resistance)]
+        );
     }
 }
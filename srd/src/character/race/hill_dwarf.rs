@@ -10,7 +10,12 @@ use crate::character::race::generic_model::GenericRaceModel;
 ///
 /// ## Dwarf
 /// **Ability Score Increase.** Your Constitution score increases by 2.\
-/// **Size.** Your size is medium.
+/// **Size.** Your size is medium.\
+/// **Speed.** Your base walking speed is 25 feet. Your speed is not reduced by wearing heavy
+/// armor.\
+/// **Dwarven Resilience.** You have resistance against poison damage.\
+/// **Dwarven Combat Training.** You have proficiency with the battleaxe, handaxe, light hammer,
+/// and warhammer.
 ///
 /// ## Hill Dwarf
 /// **Ability Score Increase.** Your Wisdom score increases by 1.
@@ -9,6 +9,9 @@ pub mod hill_dwarf;
 pub use self::hill_dwarf::HILL_DWARF;
 
 use crate::ability::{AbilityId, AbilityScore};
+use crate::damage::{DamageDefense, DamageType};
+use crate::equipment::WeaponProficiency;
+use crate::rules::core::movement::Speeds;
 use crate::rules::core::size::CreatureSize;
 use serde::{Deserialize, Serialize};
 
@@ -36,4 +39,31 @@ pub trait RaceModel {
 
     /// Returns the size of creatures of this race.
     fn size(&self) -> CreatureSize;
+
+    /// Returns the speeds of creatures of this race.
+    fn speeds(&self) -> Speeds {
+        Speeds::default()
+    }
+
+    /// Returns true if heavy armor doesn't reduce the speed of creatures of this race, even
+    /// without the required Strength.
+    fn ignores_heavy_armor_speed_penalty(&self) -> bool {
+        false
+    }
+
+    /// Returns the number of additional weapon damage dice rolled on a critical hit with a melee
+    /// attack.
+    fn extra_critical_dice(&self) -> u8 {
+        0
+    }
+
+    /// Returns the damage types creatures of this race have a defense against.
+    fn damage_defenses(&self) -> &[(DamageType, DamageDefense)] {
+        &[]
+    }
+
+    /// Returns the weapon proficiencies granted by this race.
+    fn weapon_proficiencies(&self) -> &[WeaponProficiency] {
+        &[]
+    }
 }
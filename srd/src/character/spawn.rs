@@ -3,13 +3,15 @@
 use crate::character::Character;
 use crate::compendium::compendium;
 use crate::error::{SRDError, SRDResult};
+use crate::proficiency::Proficiency;
 use crate::rules::core::action::ActionInitializer;
 use crate::rules::core::action::ActionsSeed;
+use crate::rules::core::battlegrid::{Footprint, Square};
 use crate::rules::core::statistic::StatisticInitializer;
 use crate::rules::core::statistic::StatisticsSeed;
-use crate::rules::team_rules::{create_global_team, GLOBAL_TEAM_ID};
+use crate::rules::team_rules::create_team;
 use crate::rules::SRDRules;
-use weasel::{CreateCreature, EventTrigger, Server};
+use weasel::{BattleController, CreateCreature, EventTrigger, Server};
 
 /// Helper to spawn a creature for the given character.
 #[derive(new)]
@@ -18,27 +20,64 @@ pub(crate) struct CharacterSpawner<'a> {
 }
 
 impl CharacterSpawner<'_> {
-    pub(crate) fn spawn(&self, server: &mut Server<SRDRules>) -> SRDResult<()> {
+    pub(crate) fn spawn(
+        &self,
+        server: &mut Server<SRDRules>,
+        origin: Option<Square>,
+    ) -> SRDResult<()> {
         log::debug!("spawning character {:?}", self.character.id());
         // Get the creature's statistics and actions.
         let statistics = self.build_statistics()?;
-        let actions = self.build_actions();
+        let actions = self.build_actions()?;
+        let position = self.find_position(server, origin)?;
         // We need a team in order to spawn a creature.
-        create_global_team(server)?;
+        create_team(server, self.character.team())?;
         // Now we can finally spawn the character's creature.
-        CreateCreature::trigger(server, self.character.id().clone(), GLOBAL_TEAM_ID, ())
-            .statistics_seed(statistics)
-            .abilities_seed(actions)
-            .fire()?;
+        CreateCreature::trigger(
+            server,
+            self.character.id().clone(),
+            self.character.team(),
+            position,
+        )
+        .statistics_seed(statistics)
+        .abilities_seed(actions)
+        .fire()?;
         log::info!("spawned character {:?}", self.character.id());
         Ok(())
     }
 
+    /// Returns the space occupied by the creature, starting at `origin` or in the first
+    /// free area of the grid.
+    fn find_position(
+        &self,
+        server: &Server<SRDRules>,
+        origin: Option<Square>,
+    ) -> SRDResult<Footprint> {
+        let race = self.character.race();
+        let size = compendium()
+            .race_model(race)
+            .ok_or_else(|| SRDError::RaceNotFound(race.clone()))?
+            .size();
+        match origin {
+            Some(origin) => Ok(Footprint::new(origin, size)),
+            None => server
+                .battle()
+                .space()
+                .model()
+                .free_footprint(size)
+                .ok_or_else(|| SRDError::InvalidPosition("no free space on the grid".to_string())),
+        }
+    }
+
     fn build_statistics(&self) -> SRDResult<StatisticsSeed> {
         let mut seed = StatisticsSeed::default();
         self.add_base_statistics(&mut seed)?;
         self.add_abilities(&mut seed);
         self.add_skills(&mut seed);
+        self.add_proficiencies(&mut seed)?;
+        self.add_damage_defenses(&mut seed);
+        self.add_feats(&mut seed);
+        self.add_fighting_styles(&mut seed);
         Ok(seed)
     }
 
@@ -61,6 +100,32 @@ impl CharacterSpawner<'_> {
         ));
         seed.statistics
             .push(HitPoints(*self.character.hit_points()));
+        seed.statistics.push(TemporaryHitPoints(
+            crate::hit_points::TemporaryHitPoints::default(),
+        ));
+        seed.statistics
+            .push(LifeState(crate::rules::core::LifeState::default()));
+        seed.statistics
+            .push(ArmorClass(self.character.armor_class()));
+        if let Some(armor) = self.character.armor() {
+            seed.statistics.push(Armor(armor));
+        }
+        if self.character.ammunition() > 0 || self.character.attack_profile()?.ammunition {
+            seed.statistics
+                .push(Ammunition(crate::equipment::Ammunition::new(
+                    self.character.ammunition(),
+                )));
+        }
+        seed.statistics
+            .push(WeaponSlots(self.character.weapon_slots()));
+        seed.statistics
+            .push(CriticalHit(self.character.critical_hit()));
+        seed.statistics
+            .push(TurnBudget(crate::rules::core::TurnBudget::new(
+                self.character.speeds(),
+            )));
+        seed.statistics
+            .push(Tactics(crate::rules::core::Tactics::default()));
         Ok(())
     }
 
@@ -78,16 +143,78 @@ impl CharacterSpawner<'_> {
         }
     }
 
-    fn build_actions(&self) -> ActionsSeed {
+    fn add_proficiencies(&self, seed: &mut StatisticsSeed) -> SRDResult<()> {
+        use StatisticInitializer::*;
+        let class = self.character.class();
+        let class_model = compendium()
+            .class_model(class)
+            .ok_or_else(|| SRDError::ClassNotFound(class.clone()))?;
+        for (id, _) in self.character.abilities() {
+            seed.statistics
+                .push(AbilityCheck(id, self.character.ability_check(id)));
+            seed.statistics
+                .push(SavingThrow(id, self.character.saving_throw(id)));
+        }
+        for category in class_model.armor_proficiencies() {
+            seed.statistics.push(ArmorProficiency(*category));
+        }
+        for weapon in self.character.weapon_proficiencies() {
+            seed.statistics.push(WeaponProficiency(weapon));
+        }
+        for tool in class_model.tool_proficiencies() {
+            seed.statistics
+                .push(ToolProficiency(*tool, Proficiency::Proficient));
+        }
+        Ok(())
+    }
+
+    fn add_damage_defenses(&self, seed: &mut StatisticsSeed) {
+        for (damage_type, defense) in self.character.damage_defenses() {
+            seed.statistics
+                .push(StatisticInitializer::DamageDefense(damage_type, defense));
+        }
+    }
+
+    fn add_feats(&self, seed: &mut StatisticsSeed) {
+        for feat in self.character.feats() {
+            seed.statistics.push(StatisticInitializer::Feat(*feat));
+        }
+    }
+
+    fn add_fighting_styles(&self, seed: &mut StatisticsSeed) {
+        for style in self.character.fighting_styles() {
+            seed.statistics
+                .push(StatisticInitializer::FightingStyle(*style));
+        }
+    }
+
+    fn build_actions(&self) -> SRDResult<ActionsSeed> {
         let mut seed = ActionsSeed::default();
-        self.add_base_actions(&mut seed);
-        seed
+        self.add_base_actions(&mut seed)?;
+        Ok(seed)
     }
 
-    fn add_base_actions(&self, seed: &mut ActionsSeed) {
+    fn add_base_actions(&self, seed: &mut ActionsSeed) -> SRDResult<()> {
         use ActionInitializer::*;
         seed.actions.push(Movement);
-        seed.actions.push(Attack);
+        seed.actions.push(Attack(self.character.attack_profile()?));
+        seed.actions.push(Stabilize);
+        seed.actions.push(Dash);
+        seed.actions.push(Disengage);
+        seed.actions.push(StandUp);
+        seed.actions.push(Grapple);
+        seed.actions.push(Shove);
+        seed.actions.push(Escape);
+        seed.actions.push(Dodge);
+        seed.actions.push(Help);
+        seed.actions.push(Hide);
+        seed.actions.push(Ready);
+        seed.actions.push(Search);
+        seed.actions.push(UseObject);
+        if let Some(profile) = self.character.off_hand_attack_profile()? {
+            seed.actions.push(OffHandAttack(profile));
+        }
+        Ok(())
     }
 }
 
@@ -98,18 +225,25 @@ mod tests {
     use crate::character::class::fighter::FIGHTER;
     use crate::character::race::hill_dwarf::HILL_DWARF;
     use crate::character::CharacterId;
+    use crate::equipment::{WeaponCategory, WeaponProficiency};
     use crate::handle::creature_handle::CreatureHandle;
     use crate::proficiency::{Proficiency, DEFAULT_PROFICIENCY};
     use crate::rules::core::action::ActionId;
     use crate::rules::core::statistic::StatisticId;
     use crate::rules::core::CreatureSize;
-    use crate::skill::{ACROBATICS, RESERVED_SKILLS, STEALTH};
+    use crate::skill::{ACROBATICS, ATHLETICS, RESERVED_SKILLS, STEALTH};
     use crate::util::simple_server;
     use weasel::{Actor, BattleController, Character};
 
     #[test]
     fn character_has_all_statistics() {
-        const ESSENTIAL_STATISTICS_COUNT: u8 = 6;
+        const ESSENTIAL_STATISTICS_COUNT: u8 = 13;
+        // Fighters are proficient with all armor, shields, simple and martial weapons.
+        const FIGHTER_PROFICIENCIES_COUNT: u8 = 6;
+        // Dwarves are proficient with battleaxes, handaxes, light hammers and warhammers.
+        const HILL_DWARF_PROFICIENCIES_COUNT: u8 = 4;
+        // Dwarves are resistant to poison.
+        const HILL_DWARF_DAMAGE_DEFENSES_COUNT: u8 = 1;
         let id: CharacterId = "one".into();
         let mut server = simple_server();
         crate::Character::new(id.clone(), HILL_DWARF, FIGHTER)
@@ -117,8 +251,13 @@ mod tests {
             .spawn(&mut server)
             .unwrap();
         let creature = server.battle().entities().creature(&id).unwrap();
-        let expected_statistics: usize =
-            (RESERVED_ABILITIES + RESERVED_SKILLS + ESSENTIAL_STATISTICS_COUNT).into();
+        let expected_statistics: usize = (RESERVED_ABILITIES * 3
+            + RESERVED_SKILLS
+            + ESSENTIAL_STATISTICS_COUNT
+            + FIGHTER_PROFICIENCIES_COUNT
+            + HILL_DWARF_PROFICIENCIES_COUNT
+            + HILL_DWARF_DAMAGE_DEFENSES_COUNT)
+            .into();
         assert_eq!(creature.statistics().count(), expected_statistics);
     }
 
@@ -166,10 +305,12 @@ mod tests {
     fn character_has_correct_skills() {
         let mut server = simple_server();
         let id: CharacterId = "one".into();
-        // Spawn a character with one non default skill.
+        // Spawn a character with one non default skill and two class skills.
         crate::Character::new(id.clone(), HILL_DWARF, FIGHTER)
             .unwrap()
-            .add_skill(ACROBATICS, Proficiency(true))
+            .add_skill(ACROBATICS, Proficiency::Expertise)
+            .choose_class_skills(&[ACROBATICS, ATHLETICS])
+            .unwrap()
             .spawn(&mut server)
             .unwrap();
         // The creature should have the skill we set and everything else defaulted.
@@ -180,7 +321,15 @@ mod tests {
                 .unwrap()
                 .skill()
                 .unwrap(),
-            Proficiency(true)
+            Proficiency::Expertise
+        );
+        assert_eq!(
+            *creature
+                .statistic(&StatisticId::Skill(ATHLETICS))
+                .unwrap()
+                .skill()
+                .unwrap(),
+            Proficiency::Proficient
         );
         assert_eq!(
             *creature
@@ -192,6 +341,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn character_has_class_proficiencies() {
+        let mut server = simple_server();
+        let id: CharacterId = "one".into();
+        crate::Character::new(id.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn(&mut server)
+            .unwrap();
+        let handle = CreatureHandle::new(&id, &server);
+        assert_eq!(handle.saving_throw(STRENGTH), Ok(&Proficiency::Proficient));
+        assert_eq!(handle.saving_throw(DEXTERITY), Ok(&Proficiency::None));
+        assert_eq!(handle.armor_proficiencies().unwrap().count(), 4);
+        assert!(handle
+            .weapon_proficiencies()
+            .unwrap()
+            .any(|w| *w == WeaponProficiency::Category(WeaponCategory::Martial)));
+        assert_eq!(handle.tool_proficiencies().unwrap().count(), 0);
+    }
+
     #[test]
     fn character_has_default_actions() {
         let id: CharacterId = "one".into();
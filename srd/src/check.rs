@@ -0,0 +1,706 @@
+//! Ability checks, skill checks and saving throws.
+
+use crate::ability::{AbilityId, AbilityScore};
+use crate::character::{Character, CharacterId};
+use crate::compendium::compendium;
+use crate::dice::{D20Test, D20TestResult, RollMode, Roller};
+use crate::equipment::Armor;
+use crate::error::{SRDError, SRDResult};
+use crate::handle::creature_handle::{CreatureHandle, CreatureHandleMut};
+use crate::proficiency::{Proficiency, ProficiencyBonus};
+use crate::rules::core::{Conditions, Cover, StatisticId};
+use crate::rules::SRDRules;
+use crate::skill::{SkillId, STEALTH};
+use serde::{Deserialize, Serialize};
+use weasel::{BattleController, Creature, EventProcessor};
+
+/// The number a check must equal or exceed in order to succeed.
+///
+/// # Reference
+///
+/// For every ability check, the GM decides which of the six abilities is relevant to the task at
+/// hand and the difficulty of the task, represented by a Difficulty Class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct DifficultyClass(pub u8);
+
+impl From<u8> for DifficultyClass {
+    fn from(item: u8) -> Self {
+        Self(item)
+    }
+}
+
+/// Difficulty class of a very easy task.
+pub const VERY_EASY: DifficultyClass = DifficultyClass(5);
+
+/// Difficulty class of an easy task.
+pub const EASY: DifficultyClass = DifficultyClass(10);
+
+/// Difficulty class of a task of medium difficulty.
+pub const MEDIUM: DifficultyClass = DifficultyClass(15);
+
+/// Difficulty class of a hard task.
+pub const HARD: DifficultyClass = DifficultyClass(20);
+
+/// Difficulty class of a very hard task.
+pub const VERY_HARD: DifficultyClass = DifficultyClass(25);
+
+/// Difficulty class of a nearly impossible task.
+pub const NEARLY_IMPOSSIBLE: DifficultyClass = DifficultyClass(30);
+
+/// The kind of d20 roll used to resolve a check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CheckKind {
+    /// A check using only an ability.
+    ///
+    /// # Reference
+    ///
+    /// An ability check tests a character's or monster's innate talent and training in an effort
+    /// to overcome a challenge.
+    Ability(AbilityId),
+    /// An ability check using a skill.
+    ///
+    /// # Reference
+    ///
+    /// If you are proficient in a skill, you add your proficiency bonus to the ability check that
+    /// uses that skill.
+    Skill(SkillId),
+    /// A saving throw.
+    ///
+    /// # Reference
+    ///
+    /// A saving throw represents an attempt to resist a spell, a trap, a poison, a disease,
+    /// or a similar threat.
+    SavingThrow(AbilityId),
+}
+
+impl CheckKind {
+    /// Returns the ability used by this kind of check.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the check uses a skill not existing in the compendium.
+    pub fn ability(&self) -> SRDResult<AbilityId> {
+        match self {
+            Self::Ability(id) | Self::SavingThrow(id) => Ok(*id),
+            Self::Skill(id) => compendium()
+                .associated_ability(id)
+                .copied()
+                .ok_or(SRDError::SkillNotFound(*id)),
+        }
+    }
+}
+
+/// A creature or character that can roll checks.
+pub trait CheckSubject {
+    /// Returns the id of the subject.
+    fn id(&self) -> &CharacterId;
+
+    /// Returns the subject's score in the given ability.
+    fn ability_score(&self, id: AbilityId) -> SRDResult<AbilityScore>;
+
+    /// Returns the subject's proficiency in the given skill.
+    fn skill_proficiency(&self, id: SkillId) -> SRDResult<Proficiency>;
+
+    /// Returns the subject's proficiency in checks of the given ability that don't use a skill.
+    fn ability_check_proficiency(&self, id: AbilityId) -> SRDResult<Proficiency>;
+
+    /// Returns the subject's proficiency in saving throws of the given ability.
+    fn saving_throw_proficiency(&self, id: AbilityId) -> SRDResult<Proficiency>;
+
+    /// Returns the subject's proficiency bonus.
+    fn proficiency_bonus(&self) -> SRDResult<ProficiencyBonus>;
+
+    /// Returns the armor worn by the subject. The default implementation returns none.
+    fn armor(&self) -> Option<Armor> {
+        None
+    }
+
+    /// Returns the conditions affecting the subject. The default implementation returns none.
+    fn conditions(&self) -> Conditions {
+        Conditions::default()
+    }
+}
+
+impl CheckSubject for Character {
+    fn id(&self) -> &CharacterId {
+        self.id()
+    }
+
+    fn ability_score(&self, id: AbilityId) -> SRDResult<AbilityScore> {
+        self.ability(id)
+            .ok_or(SRDError::StatisticNotFound(StatisticId::Ability(id)))
+    }
+
+    fn skill_proficiency(&self, id: SkillId) -> SRDResult<Proficiency> {
+        self.skill(id)
+            .ok_or(SRDError::StatisticNotFound(StatisticId::Skill(id)))
+    }
+
+    fn ability_check_proficiency(&self, id: AbilityId) -> SRDResult<Proficiency> {
+        Ok(self.ability_check(id))
+    }
+
+    fn saving_throw_proficiency(&self, id: AbilityId) -> SRDResult<Proficiency> {
+        Ok(self.saving_throw(id))
+    }
+
+    fn proficiency_bonus(&self) -> SRDResult<ProficiencyBonus> {
+        Ok(self.proficiency_bonus())
+    }
+
+    fn armor(&self) -> Option<Armor> {
+        self.armor()
+    }
+}
+
+/// Implements `CheckSubject` for a creature handle.
+macro_rules! impl_check_subject {
+    () => {
+        fn id(&self) -> &CharacterId {
+            self.id()
+        }
+
+        fn ability_score(&self, id: AbilityId) -> SRDResult<AbilityScore> {
+            self.ability(id).copied()
+        }
+
+        fn skill_proficiency(&self, id: SkillId) -> SRDResult<Proficiency> {
+            self.skill(id).copied()
+        }
+
+        fn ability_check_proficiency(&self, id: AbilityId) -> SRDResult<Proficiency> {
+            self.ability_check(id).copied()
+        }
+
+        fn saving_throw_proficiency(&self, id: AbilityId) -> SRDResult<Proficiency> {
+            self.saving_throw(id).copied()
+        }
+
+        fn proficiency_bonus(&self) -> SRDResult<ProficiencyBonus> {
+            self.proficiency_bonus().copied()
+        }
+
+        fn armor(&self) -> Option<Armor> {
+            self.armor().ok().copied()
+        }
+
+        fn conditions(&self) -> Conditions {
+            self.conditions().unwrap_or_default()
+        }
+    };
+}
+
+/// Returns the statistic of a creature with the given id or an error.
+fn creature_statistic(
+    creature: &Creature<SRDRules>,
+    id: StatisticId,
+) -> SRDResult<&crate::rules::core::Statistic> {
+    weasel::Character::statistic(creature, &id).ok_or(SRDError::StatisticNotFound(id))
+}
+
+impl CheckSubject for Creature<SRDRules> {
+    fn id(&self) -> &CharacterId {
+        weasel::Id::id(self)
+    }
+
+    fn ability_score(&self, id: AbilityId) -> SRDResult<AbilityScore> {
+        creature_statistic(self, StatisticId::Ability(id))?
+            .ability()
+            .copied()
+    }
+
+    fn skill_proficiency(&self, id: SkillId) -> SRDResult<Proficiency> {
+        creature_statistic(self, StatisticId::Skill(id))?
+            .skill()
+            .copied()
+    }
+
+    fn ability_check_proficiency(&self, id: AbilityId) -> SRDResult<Proficiency> {
+        creature_statistic(self, StatisticId::AbilityCheck(id))?
+            .proficiency()
+            .copied()
+    }
+
+    fn saving_throw_proficiency(&self, id: AbilityId) -> SRDResult<Proficiency> {
+        creature_statistic(self, StatisticId::SavingThrow(id))?
+            .proficiency()
+            .copied()
+    }
+
+    fn proficiency_bonus(&self) -> SRDResult<ProficiencyBonus> {
+        creature_statistic(self, StatisticId::ProficiencyBonus)?
+            .proficiency_bonus()
+            .copied()
+    }
+
+    fn armor(&self) -> Option<Armor> {
+        creature_statistic(self, StatisticId::Armor)
+            .and_then(|statistic| statistic.armor())
+            .ok()
+            .copied()
+    }
+
+    fn conditions(&self) -> Conditions {
+        Conditions::of(self)
+    }
+}
+
+impl<C: BattleController<SRDRules>> CheckSubject for CreatureHandle<'_, C> {
+    impl_check_subject! {}
+}
+
+impl<C> CheckSubject for CreatureHandleMut<'_, C>
+where
+    C: BattleController<SRDRules> + EventProcessor<SRDRules>,
+{
+    impl_check_subject! {}
+}
+
+/// A check to be rolled by a creature.
+///
+/// # Reference
+///
+/// To make an ability check, roll a d20 and add the relevant ability modifier. As with other d20
+/// rolls, apply bonuses and penalties, and compare the total to the DC. If the total equals or
+/// exceeds the DC, the ability check is a success.
+///
+/// # Examples
+///
+/// ```
+/// use srd::character::class::FIGHTER;
+/// use srd::character::race::hill_dwarf::HILL_DWARF;
+/// use srd::check::{Check, MEDIUM};
+/// use srd::dice::SeededRoller;
+/// use srd::skill::ATHLETICS;
+/// use srd::{init_srd_compendium, Character};
+///
+/// init_srd_compendium().unwrap();
+/// let character = Character::new("bob", HILL_DWARF, FIGHTER).unwrap();
+/// let mut check = Check::skill(ATHLETICS);
+/// check.add_advantage();
+/// let outcome = check
+///     .against(&character, MEDIUM, &mut SeededRoller::new(42))
+///     .unwrap();
+/// assert_eq!(outcome.success, outcome.result.total() >= 15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Check {
+    kind: CheckKind,
+    test: D20Test,
+}
+
+impl Check {
+    /// Constructs a new `Check` of the given kind.
+    pub fn new(kind: CheckKind) -> Self {
+        Self {
+            kind,
+            test: D20Test::default(),
+        }
+    }
+
+    /// Constructs a new ability check.
+    pub fn ability(id: AbilityId) -> Self {
+        Self::new(CheckKind::Ability(id))
+    }
+
+    /// Constructs a new skill check.
+    pub fn skill(id: SkillId) -> Self {
+        Self::new(CheckKind::Skill(id))
+    }
+
+    /// Constructs a new saving throw.
+    pub fn saving_throw(id: AbilityId) -> Self {
+        Self::new(CheckKind::SavingThrow(id))
+    }
+
+    /// Returns the kind of this check.
+    pub fn kind(&self) -> CheckKind {
+        self.kind
+    }
+
+    /// Adds a source of advantage.
+    pub fn add_advantage(&mut self) -> &mut Self {
+        self.test.add_advantage();
+        self
+    }
+
+    /// Adds a source of disadvantage.
+    pub fn add_disadvantage(&mut self) -> &mut Self {
+        self.test.add_disadvantage();
+        self
+    }
+
+    /// Adds a situational bonus (or penalty, if negative) to the roll.
+    pub fn add_bonus(&mut self, bonus: i16) -> &mut Self {
+        self.test.add_modifier(bonus);
+        self
+    }
+
+    /// Adds the bonus granted by `cover`, which only applies to Dexterity saving throws.
+    ///
+    /// # Reference
+    ///
+    /// A target with half cover has a +2 bonus to AC and Dexterity saving throws. A target with
+    /// three-quarters cover has a +5 bonus to AC and Dexterity saving throws.
+    pub fn add_cover(&mut self, cover: Cover) -> &mut Self {
+        self.add_bonus(cover.check_bonus(self.kind))
+    }
+
+    /// Returns the total modifier `subject` adds to the d20: ability modifier,
+    /// proficiency bonus and situational bonuses.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `subject` lacks the statistics required by this check.
+    pub fn modifier<S: CheckSubject + ?Sized>(&self, subject: &S) -> SRDResult<i16> {
+        let ability = subject.ability_score(self.kind.ability()?)?;
+        let proficiency = match self.kind {
+            CheckKind::Ability(id) => subject.ability_check_proficiency(id)?,
+            CheckKind::Skill(id) => subject.skill_proficiency(id)?,
+            CheckKind::SavingThrow(id) => subject.saving_throw_proficiency(id)?,
+        };
+        let bonus = i16::from(proficiency.bonus(subject.proficiency_bonus()?).0);
+        Ok(i16::from(ability.modifier()) + bonus + self.test.modifier())
+    }
+
+    /// Returns the `D20Test` that `subject` would roll for this check, including advantage
+    /// and disadvantage from its conditions and armor.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `subject` lacks the statistics required by this check.
+    pub fn to_test<S: CheckSubject + ?Sized>(&self, subject: &S) -> SRDResult<D20Test> {
+        let mut test = self.test;
+        test.add_modifier(self.modifier(subject)? - self.test.modifier());
+        subject.conditions().check(self.kind).apply(&mut test);
+        // Bulky armor hinders stealth.
+        if self.kind == CheckKind::Skill(STEALTH)
+            && subject
+                .armor()
+                .is_some_and(|armor| armor.stealth_disadvantage)
+        {
+            test.add_disadvantage();
+        }
+        Ok(test)
+    }
+
+    /// Returns the passive score of `subject` for this check, which is 10 plus all modifiers.
+    ///
+    /// # Reference
+    ///
+    /// A passive check is a special kind of ability check that doesn't involve any die rolls.\
+    /// Here's how to determine a character's total for a passive check: 10 + all modifiers that
+    /// normally apply to the check. If the character has advantage on the check, add 5. For
+    /// disadvantage, subtract 5.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `subject` lacks the statistics required by this check.
+    pub fn passive<S: CheckSubject + ?Sized>(&self, subject: &S) -> SRDResult<i32> {
+        let test = self.to_test(subject)?;
+        let bonus = match test.mode() {
+            RollMode::Normal => 0,
+            RollMode::Advantage => 5,
+            RollMode::Disadvantage => -5,
+        };
+        Ok(10 + i32::from(test.modifier()) + bonus)
+    }
+
+    /// Rolls this check for `subject`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `subject` lacks the statistics required by this check.
+    pub fn roll<S, R>(&self, subject: &S, roller: &mut R) -> SRDResult<CheckResult>
+    where
+        S: CheckSubject + ?Sized,
+        R: Roller + ?Sized,
+    {
+        let roll = self.to_test(subject)?.roll(roller);
+        log::trace!(
+            "{:?} rolled {:?}: {}",
+            subject.id(),
+            self.kind,
+            roll.total()
+        );
+        Ok(CheckResult {
+            subject: subject.id().clone(),
+            kind: self.kind,
+            roll,
+        })
+    }
+
+    /// Rolls this check for `subject` against a difficulty class.\
+    /// The check fails regardless of the roll if a condition makes `subject` fail it
+    /// automatically.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `subject` lacks the statistics required by this check.
+    pub fn against<S, R>(
+        &self,
+        subject: &S,
+        dc: DifficultyClass,
+        roller: &mut R,
+    ) -> SRDResult<CheckOutcome>
+    where
+        S: CheckSubject + ?Sized,
+        R: Roller + ?Sized,
+    {
+        let result = self.roll(subject, roller)?;
+        let success =
+            !subject.conditions().check(self.kind).auto_fail && result.total() >= i32::from(dc.0);
+        Ok(CheckOutcome {
+            result,
+            dc,
+            success,
+        })
+    }
+}
+
+/// The roll of a `Check`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckResult {
+    /// Who rolled the check.
+    pub subject: CharacterId,
+    /// The kind of check.
+    pub kind: CheckKind,
+    /// The d20 roll, with all modifiers.
+    pub roll: D20TestResult,
+}
+
+impl CheckResult {
+    /// Returns the face of the kept d20.
+    pub fn natural(&self) -> u8 {
+        self.roll.natural()
+    }
+
+    /// Returns the total of the check.
+    pub fn total(&self) -> i32 {
+        self.roll.total()
+    }
+}
+
+/// The outcome of a `Check` against a difficulty class.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckOutcome {
+    /// The roll of the check.
+    pub result: CheckResult,
+    /// The difficulty class to beat.
+    pub dc: DifficultyClass,
+    /// Whether or not the check was successful.
+    pub success: bool,
+}
+
+/// Which side won a contest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContestWinner {
+    /// The creature who started the contest.
+    First,
+    /// The creature who opposed the contest.
+    Second,
+}
+
+/// Rolls a contest between two subjects, each one with its own check.
+///
+/// # Reference
+///
+/// Both participants in a contest make ability checks appropriate to their efforts. They apply
+/// all appropriate bonuses and penalties, but instead of comparing the total to a DC, they
+/// compare the totals of their two checks. The participant with the higher check total wins
+/// the contest.
+///
+/// # Errors
+///
+/// An error is returned if any subject lacks the statistics required by its check.
+pub fn contest<A, B, R>(
+    first: (&A, &Check),
+    second: (&B, &Check),
+    roller: &mut R,
+) -> SRDResult<ContestOutcome>
+where
+    A: CheckSubject + ?Sized,
+    B: CheckSubject + ?Sized,
+    R: Roller + ?Sized,
+{
+    let (first_subject, first_check) = first;
+    let (second_subject, second_check) = second;
+    Ok(ContestOutcome {
+        first: first_check.roll(first_subject, roller)?,
+        second: second_check.roll(second_subject, roller)?,
+    })
+}
+
+/// The outcome of a contest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContestOutcome {
+    /// The check of the creature who started the contest.
+    pub first: CheckResult,
+    /// The check of the creature who opposed the contest.
+    pub second: CheckResult,
+}
+
+impl ContestOutcome {
+    /// Returns the winner of the contest, or `None` in case of a tie.
+    ///
+    /// # Reference
+    ///
+    /// If the contest results in a tie, the situation remains the same as it was before
+    /// the contest.
+    pub fn winner(&self) -> Option<ContestWinner> {
+        use std::cmp::Ordering::*;
+        match self.first.total().cmp(&self.second.total()) {
+            Greater => Some(ContestWinner::First),
+            Less => Some(ContestWinner::Second),
+            Equal => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability::{DEXTERITY, STRENGTH, WISDOM};
+    use crate::character::class::fighter::FIGHTER;
+    use crate::character::race::hill_dwarf::HILL_DWARF;
+    use crate::dice::roll::SequenceRoller;
+    use crate::dice::RollMode;
+    use crate::equipment::armor::SCALE_MAIL;
+    use crate::skill::{ACROBATICS, ATHLETICS};
+    use crate::util::simple_server;
+
+    fn character(id: &str) -> Character {
+        let _ = crate::init_srd_compendium();
+        let mut character = Character::new(id, HILL_DWARF, FIGHTER).unwrap();
+        character
+            .add_ability(STRENGTH, AbilityScore::new(16).unwrap())
+            .add_ability(DEXTERITY, AbilityScore::new(8).unwrap())
+            .add_skill(ATHLETICS, true);
+        character
+    }
+
+    #[test]
+    fn check_modifiers() {
+        let c = character("one");
+        assert_eq!(Check::ability(STRENGTH).modifier(&c), Ok(3));
+        assert_eq!(Check::skill(ATHLETICS).modifier(&c), Ok(5));
+        assert_eq!(Check::skill(ACROBATICS).modifier(&c), Ok(-1));
+        assert_eq!(Check::saving_throw(WISDOM).add_bonus(2).modifier(&c), Ok(2));
+        // Fighters are proficient in Strength saving throws.
+        assert_eq!(Check::saving_throw(STRENGTH).modifier(&c), Ok(5));
+        // Cover only helps Dexterity saving throws.
+        let mut check = Check::saving_throw(DEXTERITY);
+        assert_eq!(check.add_cover(Cover::ThreeQuarters).modifier(&c), Ok(4));
+        let mut check = Check::saving_throw(STRENGTH);
+        assert_eq!(check.add_cover(Cover::Half).modifier(&c), Ok(5));
+        assert_eq!(
+            Check::skill(SkillId(100)).modifier(&c),
+            Err(SRDError::SkillNotFound(SkillId(100)))
+        );
+    }
+
+    #[test]
+    fn passive_checks() {
+        let c = character("one");
+        assert_eq!(Check::skill(ATHLETICS).passive(&c), Ok(15));
+        let mut check = Check::skill(ATHLETICS);
+        assert_eq!(check.add_advantage().passive(&c), Ok(20));
+        assert_eq!(check.add_disadvantage().passive(&c), Ok(15));
+        // Scale mail hinders stealth.
+        let mut c = character("two");
+        c.wear_armor(Some(SCALE_MAIL)).unwrap();
+        assert_eq!(Check::skill(STEALTH).passive(&c), Ok(4));
+    }
+
+    #[test]
+    fn check_proficiency_levels() {
+        let mut c = character("one");
+        c.add_skill(ATHLETICS, Proficiency::Expertise)
+            .add_skill(ACROBATICS, Proficiency::HalfRoundedDown);
+        assert_eq!(Check::skill(ATHLETICS).modifier(&c), Ok(7));
+        assert_eq!(Check::skill(ACROBATICS).modifier(&c), Ok(0));
+        // Plain ability checks, such as initiative, use the ability check proficiency.
+        assert_eq!(Check::ability(DEXTERITY).modifier(&c), Ok(-1));
+        c.add_ability_check(DEXTERITY, Proficiency::HalfRoundedUp);
+        assert_eq!(Check::ability(DEXTERITY).modifier(&c), Ok(0));
+    }
+
+    #[test]
+    fn check_against_dc() {
+        let c = character("one");
+        let outcome = Check::skill(ATHLETICS)
+            .against(&c, MEDIUM, &mut SequenceRoller::new(vec![10]))
+            .unwrap();
+        assert_eq!(outcome.result.total(), 15);
+        assert!(outcome.success);
+        let mut check = Check::skill(ATHLETICS);
+        check.add_disadvantage();
+        let outcome = check
+            .against(&c, MEDIUM, &mut SequenceRoller::new(vec![10, 9]))
+            .unwrap();
+        assert_eq!(outcome.result.roll.mode, RollMode::Disadvantage);
+        assert_eq!(outcome.result.natural(), 9);
+        assert!(!outcome.success);
+    }
+
+    #[test]
+    fn check_from_creature_handle() {
+        let mut server = simple_server();
+        let c = character("one");
+        c.spawn(&mut server).unwrap();
+        let handle = CreatureHandle::new(c.id(), &server);
+        assert_eq!(
+            Check::skill(ATHLETICS).modifier(&handle),
+            Check::skill(ATHLETICS).modifier(&c)
+        );
+        let result = Check::ability(STRENGTH)
+            .roll(&handle, &mut SequenceRoller::new(vec![20]))
+            .unwrap();
+        assert_eq!(result.subject, "one".into());
+        assert_eq!(result.total(), 23);
+    }
+
+    #[test]
+    fn armor_hinders_stealth() {
+        let mut server = simple_server();
+        let mut c = character("one");
+        let stealth = Check::skill(STEALTH);
+        assert_eq!(stealth.to_test(&c).unwrap().mode(), RollMode::Normal);
+        c.wear_armor(Some(SCALE_MAIL)).unwrap();
+        assert_eq!(stealth.to_test(&c).unwrap().mode(), RollMode::Disadvantage);
+        assert_eq!(
+            Check::skill(ATHLETICS).to_test(&c).unwrap().mode(),
+            RollMode::Normal
+        );
+        c.spawn(&mut server).unwrap();
+        let handle = CreatureHandle::new(c.id(), &server);
+        assert_eq!(
+            stealth.to_test(&handle).unwrap().mode(),
+            RollMode::Disadvantage
+        );
+    }
+
+    #[test]
+    fn contest_winner_and_tie() {
+        let grappler = character("grappler");
+        let mut target = character("target");
+        target.add_skill(ACROBATICS, true);
+        let grapple = Check::skill(ATHLETICS);
+        let escape = Check::skill(ACROBATICS);
+        // Athletics +5 against Acrobatics +1.
+        let outcome = contest(
+            (&grappler, &grapple),
+            (&target, &escape),
+            &mut SequenceRoller::new(vec![10, 14]),
+        )
+        .unwrap();
+        assert_eq!(outcome.winner(), None);
+        let outcome = contest(
+            (&grappler, &grapple),
+            (&target, &escape),
+            &mut SequenceRoller::new(vec![10, 15]),
+        )
+        .unwrap();
+        assert_eq!(outcome.winner(), Some(ContestWinner::Second));
+    }
+}
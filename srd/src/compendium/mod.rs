@@ -4,6 +4,7 @@ pub mod standard_compendium;
 pub use self::standard_compendium::StandardCompendium;
 
 use crate::character::{ClassId, ClassModel, RaceId, RaceModel};
+use crate::equipment::{Weapon, WeaponId};
 use crate::{AbilityId, SkillId};
 use crate::{SRDError, SRDResult};
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -17,12 +18,12 @@ pub trait Compendium {
     }
 
     /// Returns all existing abilities.
-    fn abilities<'a>(&'a self) -> Box<dyn Iterator<Item = &AbilityId> + 'a> {
+    fn abilities<'a>(&'a self) -> Box<dyn Iterator<Item = &'a AbilityId> + 'a> {
         Box::new(std::iter::empty())
     }
 
     /// Returns all existing skills.
-    fn skills<'a>(&'a self) -> Box<dyn Iterator<Item = &SkillId> + 'a> {
+    fn skills<'a>(&'a self) -> Box<dyn Iterator<Item = &'a SkillId> + 'a> {
         Box::new(std::iter::empty())
     }
 
@@ -32,7 +33,7 @@ pub trait Compendium {
     }
 
     /// Returns all existing character races.
-    fn races<'a>(&'a self) -> Box<dyn Iterator<Item = &RaceId> + 'a> {
+    fn races<'a>(&'a self) -> Box<dyn Iterator<Item = &'a RaceId> + 'a> {
         Box::new(std::iter::empty())
     }
 
@@ -42,7 +43,7 @@ pub trait Compendium {
     }
 
     /// Returns all existing character classes.
-    fn classes<'a>(&'a self) -> Box<dyn Iterator<Item = &ClassId> + 'a> {
+    fn classes<'a>(&'a self) -> Box<dyn Iterator<Item = &'a ClassId> + 'a> {
         Box::new(std::iter::empty())
     }
 
@@ -50,6 +51,16 @@ pub trait Compendium {
     fn class_model(&self, _: &ClassId) -> Option<&dyn ClassModel> {
         None
     }
+
+    /// Returns all existing weapons.
+    fn weapons<'a>(&'a self) -> Box<dyn Iterator<Item = &'a WeaponId> + 'a> {
+        Box::new(std::iter::empty())
+    }
+
+    /// Returns the given weapon, or `None` if the weapon doesn't exist.
+    fn weapon(&self, _: &WeaponId) -> Option<&Weapon> {
+        None
+    }
 }
 
 struct EmptyCompendium;
@@ -103,7 +114,7 @@ pub fn set_boxed_compendium(compendium: Box<dyn Compendium>) -> SRDResult<()> {
 /// struct MyCompendium;
 ///
 /// impl Compendium for MyCompendium {
-///     fn abilities<'a>(&'a self) -> Box<dyn Iterator<Item = &AbilityId> + 'a> {
+///     fn abilities<'a>(&'a self) -> Box<dyn Iterator<Item = &'a AbilityId> + 'a> {
 ///         Box::new(std::iter::once(&AbilityId(1)))
 ///     }
 /// }
@@ -123,8 +134,13 @@ fn set_compendium_inner<F>(make_compendium: F) -> SRDResult<()>
 where
     F: FnOnce() -> &'static dyn Compendium,
 {
-    match STATE.compare_and_swap(UNINITIALIZED, INITIALIZING, Ordering::SeqCst) {
-        UNINITIALIZED => {
+    match STATE.compare_exchange(
+        UNINITIALIZED,
+        INITIALIZING,
+        Ordering::SeqCst,
+        Ordering::SeqCst,
+    ) {
+        Ok(UNINITIALIZED) => {
             unsafe {
                 COMPENDIUM = make_compendium();
             }
@@ -132,9 +148,9 @@ where
             log::info!("registered a compendium");
             Ok(())
         }
-        INITIALIZING => {
+        Err(INITIALIZING) => {
             while STATE.load(Ordering::SeqCst) == INITIALIZING {
-                std::sync::atomic::spin_loop_hint();
+                std::hint::spin_loop();
             }
             Err(SRDError::SetCompendiumError)
         }
@@ -3,6 +3,8 @@
 use crate::ability::*;
 use crate::character::class::*;
 use crate::character::race::*;
+use crate::equipment::weapon::srd_weapons;
+use crate::equipment::{Weapon, WeaponId};
 use crate::skill::*;
 use crate::Compendium;
 use std::collections::{HashMap, HashSet};
@@ -14,6 +16,7 @@ pub struct StandardCompendium {
     skills: HashMap<SkillId, AbilityId>,
     races: HashMap<RaceId, Box<dyn RaceModel>>,
     classes: HashMap<ClassId, Box<dyn ClassModel>>,
+    weapons: HashMap<WeaponId, Weapon>,
     version: u32,
 }
 
@@ -24,6 +27,7 @@ impl StandardCompendium {
             skills: HashMap::new(),
             races: HashMap::new(),
             classes: HashMap::new(),
+            weapons: HashMap::new(),
             version: 0,
         }
     }
@@ -35,7 +39,8 @@ impl StandardCompendium {
             .add_srd_abilities()
             .add_srd_skills()
             .add_srd_races()
-            .add_srd_classes();
+            .add_srd_classes()
+            .add_srd_weapons();
         log::debug!("created a standard compendium with the core SRD modules");
         compendium
     }
@@ -116,7 +121,23 @@ impl StandardCompendium {
 
     /// Adds all character classes from the SRD.
     pub fn add_srd_classes(&mut self) -> &mut Self {
-        self.add_class(FIGHTER, Box::new(fighter::FighterModel::default()))
+        self.add_class(BARBARIAN, Box::new(barbarian::BarbarianModel::default()))
+            .add_class(FIGHTER, Box::new(fighter::FighterModel::default()))
+            .add_class(MONK, Box::new(monk::MonkModel::default()))
+    }
+
+    /// Adds or replaces a weapon.
+    pub fn add_weapon<T: Into<WeaponId>>(&mut self, id: T, weapon: Weapon) -> &mut Self {
+        self.weapons.insert(id.into(), weapon);
+        self
+    }
+
+    /// Adds all simple and martial weapons from the SRD.
+    pub fn add_srd_weapons(&mut self) -> &mut Self {
+        for (id, weapon) in srd_weapons() {
+            self.add_weapon(id, weapon);
+        }
+        self
     }
 }
 
@@ -134,11 +155,11 @@ impl Compendium for StandardCompendium {
         self.version
     }
 
-    fn abilities<'a>(&'a self) -> Box<dyn Iterator<Item = &AbilityId> + 'a> {
+    fn abilities<'a>(&'a self) -> Box<dyn Iterator<Item = &'a AbilityId> + 'a> {
         Box::new(self.abilities.iter())
     }
 
-    fn skills<'a>(&'a self) -> Box<dyn Iterator<Item = &SkillId> + 'a> {
+    fn skills<'a>(&'a self) -> Box<dyn Iterator<Item = &'a SkillId> + 'a> {
         Box::new(self.skills.keys())
     }
 
@@ -146,7 +167,7 @@ impl Compendium for StandardCompendium {
         self.skills.get(skill)
     }
 
-    fn races<'a>(&'a self) -> Box<dyn Iterator<Item = &RaceId> + 'a> {
+    fn races<'a>(&'a self) -> Box<dyn Iterator<Item = &'a RaceId> + 'a> {
         Box::new(self.races.keys())
     }
 
@@ -154,13 +175,21 @@ impl Compendium for StandardCompendium {
         self.races.get(race).map(|e| &**e)
     }
 
-    fn classes<'a>(&'a self) -> Box<dyn Iterator<Item = &ClassId> + 'a> {
+    fn classes<'a>(&'a self) -> Box<dyn Iterator<Item = &'a ClassId> + 'a> {
         Box::new(self.classes.keys())
     }
 
     fn class_model(&self, class: &ClassId) -> Option<&dyn ClassModel> {
         self.classes.get(class).map(|e| &**e)
     }
+
+    fn weapons<'a>(&'a self) -> Box<dyn Iterator<Item = &'a WeaponId> + 'a> {
+        Box::new(self.weapons.keys())
+    }
+
+    fn weapon(&self, weapon: &WeaponId) -> Option<&Weapon> {
+        self.weapons.get(weapon)
+    }
 }
 
 #[cfg(test)]
@@ -169,6 +198,8 @@ mod tests {
     use crate::ability::{DEXTERITY, RESERVED_ABILITIES};
     use crate::character::class::FIGHTER;
     use crate::character::race::HILL_DWARF;
+    use crate::equipment::weapon::{LONGSWORD, RESERVED_WEAPONS};
+    use crate::equipment::WeaponCategory;
     use crate::skill::{SkillId, ACROBATICS, RESERVED_SKILLS};
     use crate::{compendium, init_srd_compendium, Compendium};
     use std::thread;
@@ -180,6 +211,7 @@ mod tests {
         assert_eq!(compendium.skills().count(), 0);
         assert_eq!(compendium.races().count(), 0);
         assert_eq!(compendium.classes().count(), 0);
+        assert_eq!(compendium.weapons().count(), 0);
     }
 
     #[test]
@@ -187,6 +219,7 @@ mod tests {
         let compendium = StandardCompendium::with_srd();
         assert_eq!(compendium.abilities().count(), RESERVED_ABILITIES.into());
         assert_eq!(compendium.skills().count(), RESERVED_SKILLS.into());
+        assert_eq!(compendium.weapons().count(), RESERVED_WEAPONS.into());
         // TODO enable when we have all races and classes
         // assert_eq!(compendium.races().count(), RESERVED_RACES.into());
         // assert_eq!(compendium.classes().count(), RESERVED_CLASSES.into());
@@ -222,7 +255,19 @@ mod tests {
     #[test]
     fn standard_compendium_returns_correct_class_model() {
         let compendium = StandardCompendium::with_srd();
+        assert!(compendium.class_model(&BARBARIAN.into()).is_some());
         assert!(compendium.class_model(&FIGHTER.into()).is_some());
+        assert!(compendium.class_model(&MONK.into()).is_some());
         assert!(compendium.class_model(&"test".into()).is_none());
     }
+
+    #[test]
+    fn standard_compendium_returns_correct_weapon() {
+        let compendium = StandardCompendium::with_srd();
+        assert_eq!(
+            compendium.weapon(&LONGSWORD).map(|w| w.category),
+            Some(WeaponCategory::Martial)
+        );
+        assert!(compendium.weapon(&RESERVED_WEAPONS.into()).is_none());
+    }
 }
@@ -0,0 +1,188 @@
+//! Damage types, damage rolls and defenses against damage.
+
+use crate::dice::{DiceRolls, DiceRollsResult, Roller};
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+/// Number of damage types.
+pub const DAMAGE_TYPES_COUNT: usize = 13;
+
+/// All damage types, in alphabetical order.
+pub const DAMAGE_TYPES: [DamageType; DAMAGE_TYPES_COUNT] = [
+    DamageType::Acid,
+    DamageType::Bludgeoning,
+    DamageType::Cold,
+    DamageType::Fire,
+    DamageType::Force,
+    DamageType::Lightning,
+    DamageType::Necrotic,
+    DamageType::Piercing,
+    DamageType::Poison,
+    DamageType::Psychic,
+    DamageType::Radiant,
+    DamageType::Slashing,
+    DamageType::Thunder,
+];
+
+/// Types of damage.
+///
+/// # Reference
+///
+/// Different attacks, damaging spells, and other harmful effects deal different types of damage.
+/// Damage types have no rules of their own, but other rules, such as damage resistance,
+/// rely on the types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum DamageType {
+    Acid,
+    Bludgeoning,
+    Cold,
+    Fire,
+    Force,
+    Lightning,
+    Necrotic,
+    Piercing,
+    Poison,
+    Psychic,
+    Radiant,
+    Slashing,
+    Thunder,
+}
+
+/// How a creature reacts to a type of damage.
+///
+/// # Reference
+///
+/// Some creatures and objects are exceedingly difficult or unusually easy to hurt with certain
+/// types of damage.\
+/// If a creature or an object has resistance to a damage type, damage of that type is halved
+/// against it. If a creature or an object has vulnerability to a damage type, damage of that
+/// type is doubled against it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DamageDefense {
+    /// Damage is halved.
+    Resistance,
+    /// Damage is doubled.
+    Vulnerability,
+    /// Damage is ignored.
+    Immunity,
+}
+
+impl DamageDefense {
+    /// Returns the damage taken by a creature having this defense, when it would
+    /// otherwise take `amount` damage.
+    pub fn apply(&self, amount: u16) -> u16 {
+        match self {
+            Self::Resistance => amount / 2,
+            Self::Vulnerability => amount.saturating_mul(2),
+            Self::Immunity => 0,
+        }
+    }
+}
+
+/// Damage of a single type, yet to be rolled.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Damage {
+    /// The type of damage.
+    pub damage_type: DamageType,
+    /// The damage roll.
+    pub roll: DiceRolls,
+}
+
+impl Damage {
+    /// Constructs a new `Damage`.
+    pub fn new<R: Into<DiceRolls>>(damage_type: DamageType, roll: R) -> Self {
+        Self {
+            damage_type,
+            roll: roll.into(),
+        }
+    }
+
+    /// Rolls this damage.
+    pub fn roll<R: Roller + ?Sized>(&self, roller: &mut R) -> DamageResult {
+        DamageResult {
+            damage_type: self.damage_type,
+            roll: self.roll.roll(roller),
+        }
+    }
+}
+
+/// Rolled damage of a single type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DamageResult {
+    /// The type of damage.
+    pub damage_type: DamageType,
+    /// The damage roll.
+    pub roll: DiceRollsResult,
+}
+
+impl DamageResult {
+    /// Returns the amount of damage, before applying any defense.
+    pub fn amount(&self) -> u16 {
+        self.roll.total().max(0).try_into().unwrap_or(u16::MAX)
+    }
+
+    /// Returns the amount of damage taken by a creature with the given defenses
+    /// against this damage type.
+    ///
+    /// # Reference
+    ///
+    /// Resistance and then vulnerability are applied after all other modifiers to damage.\
+    /// Multiple instances of resistance or vulnerability that affect the same damage type count
+    /// as only one instance.
+    pub fn amount_against(&self, defenses: &[DamageDefense]) -> u16 {
+        if defenses.contains(&DamageDefense::Immunity) {
+            return 0;
+        }
+        [DamageDefense::Resistance, DamageDefense::Vulnerability]
+            .iter()
+            .filter(|defense| defenses.contains(defense))
+            .fold(self.amount(), |amount, defense| defense.apply(amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::roll::SequenceRoller;
+    use crate::dice::{Dice, DiceRoll, Die};
+
+    #[test]
+    fn defenses() {
+        assert_eq!(DamageDefense::Resistance.apply(7), 3);
+        assert_eq!(DamageDefense::Vulnerability.apply(7), 14);
+        assert_eq!(DamageDefense::Immunity.apply(7), 0);
+        assert_eq!(DamageDefense::Vulnerability.apply(u16::MAX), u16::MAX);
+    }
+
+    #[test]
+    fn typed_damage() {
+        let mut roller = SequenceRoller::new(vec![5, 3]);
+        let slashing = Damage::new(
+            DamageType::Slashing,
+            DiceRoll::with_modifier(Dice::new(1, Die::D8), 2),
+        )
+        .roll(&mut roller);
+        let fire =
+            Damage::new(DamageType::Fire, DiceRoll::new(Dice::new(1, Die::D6))).roll(&mut roller);
+        assert_eq!(slashing.damage_type, DamageType::Slashing);
+        assert_eq!(slashing.amount(), 7);
+        assert_eq!(fire.amount(), 3);
+        assert_eq!(fire.amount_against(&[]), 3);
+        assert_eq!(fire.amount_against(&[DamageDefense::Resistance]), 1);
+        // Resistance applies before vulnerability, and immunity wins over both.
+        let (resistance, vulnerability) = (DamageDefense::Resistance, DamageDefense::Vulnerability);
+        assert_eq!(slashing.amount_against(&[vulnerability, resistance]), 6);
+        assert_eq!(slashing.amount_against(&[resistance, resistance]), 3);
+        assert_eq!(
+            slashing.amount_against(&[resistance, DamageDefense::Immunity]),
+            0
+        );
+        let negative = Damage::new(
+            DamageType::Piercing,
+            DiceRoll::with_modifier(Dice::new(1, Die::D4), -3),
+        )
+        .roll(&mut SequenceRoller::new(vec![1]));
+        assert_eq!(negative.amount(), 0);
+    }
+}
@@ -0,0 +1,396 @@
+//! Rolls with additional rules: advantage, disadvantage, keeping or dropping dice and rerolls.
+
+use crate::dice::{Dice, DiceRoll, Die, Roller};
+use serde::{Deserialize, Serialize};
+
+/// Tells which dice contribute to the result of a roll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Keep {
+    /// All dice are kept.
+    #[default]
+    All,
+    /// Only the given number of highest dice are kept.
+    Highest(u8),
+    /// Only the given number of lowest dice are kept.
+    Lowest(u8),
+}
+
+/// A `DiceRoll` with additional rules to decide which faces are kept.
+///
+/// # Examples
+///
+/// ```
+/// use srd::dice::{AdvancedRoll, Dice, DiceRoll, Die, Keep};
+///
+/// // Roll 4d6 and drop the lowest die.
+/// let ability_score = AdvancedRoll::new(DiceRoll::new(Dice::new(4, Die::D6))).keep(Keep::Highest(3));
+/// // Great Weapon Fighting: reroll 1s and 2s on the damage dice.
+/// let greatsword = AdvancedRoll::new(DiceRoll::new(Dice::new(2, Die::D6))).reroll_below(3);
+/// # let _ = (ability_score, greatsword);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AdvancedRoll {
+    /// The dice to be rolled and the modifier.
+    pub roll: DiceRoll,
+    /// Which dice are kept.
+    pub keep: Keep,
+    /// Dice showing a face lower than this value are rolled again, once.
+    /// The new face must be used.
+    pub reroll_below: Option<u8>,
+}
+
+impl AdvancedRoll {
+    /// Constructs a new `AdvancedRoll` keeping all dice, without rerolls.
+    pub const fn new(roll: DiceRoll) -> Self {
+        Self {
+            roll,
+            keep: Keep::All,
+            reroll_below: None,
+        }
+    }
+
+    /// Sets which dice are kept.
+    pub const fn keep(mut self, keep: Keep) -> Self {
+        self.keep = keep;
+        self
+    }
+
+    /// Rerolls once all dice whose face is lower than `face`.
+    pub const fn reroll_below(mut self, face: u8) -> Self {
+        self.reroll_below = Some(face);
+        self
+    }
+
+    /// Rolls the dice and returns the result.
+    pub fn roll<R: Roller + ?Sized>(&self, roller: &mut R) -> AdvancedRollResult {
+        let Dice { n, die } = self.roll.dice;
+        let mut dice: Vec<RolledDie> = (0..n)
+            .map(|_| {
+                let face = roller.roll_die(die);
+                match self.reroll_below {
+                    Some(threshold) if face < threshold => RolledDie {
+                        die,
+                        face: roller.roll_die(die),
+                        rerolled: Some(face),
+                        kept: true,
+                    },
+                    _ => RolledDie {
+                        die,
+                        face,
+                        rerolled: None,
+                        kept: true,
+                    },
+                }
+            })
+            .collect();
+        // Discard the dice not selected by the keep rule.
+        let mut order: Vec<usize> = (0..dice.len()).collect();
+        let kept = match self.keep {
+            Keep::All => dice.len(),
+            Keep::Highest(k) => {
+                order.sort_by(|a, b| dice[*b].face.cmp(&dice[*a].face));
+                usize::from(k)
+            }
+            Keep::Lowest(k) => {
+                order.sort_by(|a, b| dice[*a].face.cmp(&dice[*b].face));
+                usize::from(k)
+            }
+        };
+        for index in order.into_iter().skip(kept) {
+            dice[index].kept = false;
+        }
+        AdvancedRollResult {
+            dice,
+            modifier: self.roll.modifier,
+        }
+    }
+}
+
+impl From<DiceRoll> for AdvancedRoll {
+    fn from(item: DiceRoll) -> Self {
+        Self::new(item)
+    }
+}
+
+/// A single die of an `AdvancedRoll`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RolledDie {
+    /// The die that was rolled.
+    pub die: Die,
+    /// The final face of the die.
+    pub face: u8,
+    /// The original face, if the die was rerolled.
+    pub rerolled: Option<u8>,
+    /// Whether or not this die contributes to the result.
+    pub kept: bool,
+}
+
+/// The outcome of an `AdvancedRoll`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdvancedRollResult {
+    /// All dice, in the order they were rolled. Includes the discarded ones.
+    pub dice: Vec<RolledDie>,
+    /// The modifier added to the dice' result.
+    pub modifier: i16,
+}
+
+impl AdvancedRollResult {
+    /// Returns an iterator over the dice contributing to the result.
+    pub fn kept(&self) -> impl Iterator<Item = &RolledDie> {
+        self.dice.iter().filter(|die| die.kept)
+    }
+
+    /// Returns the sum of all kept faces, without the modifier.
+    pub fn dice_total(&self) -> i32 {
+        self.kept().map(|die| i32::from(die.face)).sum()
+    }
+
+    /// Returns the final result: the sum of all kept faces plus the modifier.
+    pub fn total(&self) -> i32 {
+        self.dice_total() + i32::from(self.modifier)
+    }
+}
+
+/// The way a d20 is rolled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RollMode {
+    /// Roll one d20.
+    Normal,
+    /// Roll two d20 and use the higher roll.
+    Advantage,
+    /// Roll two d20 and use the lower roll.
+    Disadvantage,
+}
+
+impl RollMode {
+    /// Returns the rule deciding which d20 is kept.
+    const fn keep(&self) -> Keep {
+        match self {
+            Self::Normal => Keep::All,
+            Self::Advantage => Keep::Highest(1),
+            Self::Disadvantage => Keep::Lowest(1),
+        }
+    }
+
+    /// Returns how many d20 are rolled.
+    const fn dice(&self) -> u8 {
+        match self {
+            Self::Normal => 1,
+            Self::Advantage | Self::Disadvantage => 2,
+        }
+    }
+}
+
+/// A d20 roll for an attack, an ability check or a saving throw.
+///
+/// # Reference
+///
+/// Sometimes a special ability or spell tells you that you have advantage or disadvantage on
+/// an ability check, a saving throw, or an attack roll. When that happens, you roll a second
+/// d20 when you make the roll. Use the higher of the two rolls if you have advantage, and use
+/// the lower roll if you have disadvantage.\
+/// If multiple situations affect a roll and each one grants advantage or imposes disadvantage
+/// on it, you don't roll more than one additional d20.\
+/// If circumstances cause a roll to have both advantage and disadvantage, you are considered to
+/// have neither of them, and you roll one d20. This is true even if multiple circumstances
+/// impose disadvantage and only one grants advantage or vice versa.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct D20Test {
+    advantages: u8,
+    disadvantages: u8,
+    reroll_below: Option<u8>,
+    modifier: i16,
+}
+
+impl D20Test {
+    /// Constructs a new `D20Test` with the given modifier.
+    pub fn new(modifier: i16) -> Self {
+        Self {
+            modifier,
+            ..Self::default()
+        }
+    }
+
+    /// Adds a source of advantage.
+    pub fn add_advantage(&mut self) -> &mut Self {
+        self.advantages = self.advantages.saturating_add(1);
+        self
+    }
+
+    /// Adds a source of disadvantage.
+    pub fn add_disadvantage(&mut self) -> &mut Self {
+        self.disadvantages = self.disadvantages.saturating_add(1);
+        self
+    }
+
+    /// Adds `n` to the modifier.
+    pub fn add_modifier(&mut self, n: i16) -> &mut Self {
+        self.modifier += n;
+        self
+    }
+
+    /// Rerolls once any d20 whose face is lower than `face`.
+    pub fn reroll_below(&mut self, face: u8) -> &mut Self {
+        self.reroll_below = Some(face);
+        self
+    }
+
+    /// Returns the number of sources of advantage.
+    pub fn advantages(&self) -> u8 {
+        self.advantages
+    }
+
+    /// Returns the number of sources of disadvantage.
+    pub fn disadvantages(&self) -> u8 {
+        self.disadvantages
+    }
+
+    /// Returns the modifier.
+    pub fn modifier(&self) -> i16 {
+        self.modifier
+    }
+
+    /// Returns how the d20 will be rolled, after advantage and disadvantage cancel each other.
+    pub fn mode(&self) -> RollMode {
+        match (self.advantages > 0, self.disadvantages > 0) {
+            (true, false) => RollMode::Advantage,
+            (false, true) => RollMode::Disadvantage,
+            _ => RollMode::Normal,
+        }
+    }
+
+    /// Returns the `AdvancedRoll` equivalent to this test.
+    pub fn to_roll(&self) -> AdvancedRoll {
+        let mode = self.mode();
+        let roll = AdvancedRoll::new(DiceRoll::with_modifier(
+            Dice::new(mode.dice(), Die::D20),
+            self.modifier,
+        ))
+        .keep(mode.keep());
+        match self.reroll_below {
+            Some(face) => roll.reroll_below(face),
+            None => roll,
+        }
+    }
+
+    /// Rolls the d20 and returns the result.
+    pub fn roll<R: Roller + ?Sized>(&self, roller: &mut R) -> D20TestResult {
+        D20TestResult {
+            mode: self.mode(),
+            roll: self.to_roll().roll(roller),
+        }
+    }
+}
+
+/// The outcome of a `D20Test`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct D20TestResult {
+    /// How the d20 was rolled.
+    pub mode: RollMode,
+    /// All dice rolled, including the discarded one.
+    pub roll: AdvancedRollResult,
+}
+
+impl D20TestResult {
+    /// Returns the face of the kept d20.
+    pub fn natural(&self) -> u8 {
+        self.roll
+            .kept()
+            .next()
+            .map(|die| die.face)
+            .expect("a d20 test must keep one die")
+    }
+
+    /// Returns the final result: the kept d20 plus the modifier.
+    pub fn total(&self) -> i32 {
+        self.roll.total()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::roll::SequenceRoller;
+
+    #[test]
+    fn keep_highest() {
+        let roll = AdvancedRoll::new(DiceRoll::new(Dice::new(4, Die::D6))).keep(Keep::Highest(3));
+        let result = roll.roll(&mut SequenceRoller::new(vec![3, 1, 6, 4]));
+        assert_eq!(result.total(), 13);
+        assert_eq!(
+            result.dice.iter().map(|d| d.kept).collect::<Vec<_>>(),
+            vec![true, false, true, true]
+        );
+    }
+
+    #[test]
+    fn keep_lowest() {
+        let roll = AdvancedRoll::new(DiceRoll::with_modifier(Dice::new(3, Die::D8), 2))
+            .keep(Keep::Lowest(2));
+        let result = roll.roll(&mut SequenceRoller::new(vec![5, 5, 2]));
+        assert_eq!(result.dice_total(), 7);
+        assert_eq!(result.total(), 9);
+        assert_eq!(result.kept().count(), 2);
+    }
+
+    #[test]
+    fn keep_more_than_rolled() {
+        let roll = AdvancedRoll::new(DiceRoll::new(Dice::new(2, Die::D4))).keep(Keep::Highest(5));
+        let result = roll.roll(&mut SequenceRoller::new(vec![1, 2]));
+        assert_eq!(result.total(), 3);
+    }
+
+    #[test]
+    fn reroll_below() {
+        let roll = AdvancedRoll::new(DiceRoll::new(Dice::new(2, Die::D6))).reroll_below(3);
+        // The first die is rerolled and the second roll must be used, even if lower.
+        let result = roll.roll(&mut SequenceRoller::new(vec![2, 1, 5]));
+        assert_eq!(result.dice[0].face, 1);
+        assert_eq!(result.dice[0].rerolled, Some(2));
+        assert_eq!(result.dice[1].face, 5);
+        assert_eq!(result.dice[1].rerolled, None);
+        assert_eq!(result.total(), 6);
+    }
+
+    #[test]
+    fn advantage_and_disadvantage_cancel() {
+        let mut test = D20Test::new(0);
+        assert_eq!(test.mode(), RollMode::Normal);
+        test.add_advantage();
+        assert_eq!(test.mode(), RollMode::Advantage);
+        test.add_advantage();
+        assert_eq!(test.mode(), RollMode::Advantage);
+        test.add_disadvantage();
+        assert_eq!(test.mode(), RollMode::Normal);
+        let mut test = D20Test::new(0);
+        test.add_disadvantage().add_disadvantage();
+        assert_eq!(test.mode(), RollMode::Disadvantage);
+        assert_eq!(test.disadvantages(), 2);
+    }
+
+    #[test]
+    fn d20_test_records_both_dice() {
+        let mut test = D20Test::new(3);
+        test.add_advantage();
+        let result = test.roll(&mut SequenceRoller::new(vec![7, 15]));
+        assert_eq!(result.mode, RollMode::Advantage);
+        assert_eq!(result.roll.dice.len(), 2);
+        assert_eq!(result.natural(), 15);
+        assert_eq!(result.total(), 18);
+        let mut test = D20Test::new(3);
+        test.add_disadvantage();
+        let result = test.roll(&mut SequenceRoller::new(vec![7, 15]));
+        assert_eq!(result.natural(), 7);
+        assert!(!result.roll.dice[1].kept);
+    }
+
+    #[test]
+    fn d20_test_reroll() {
+        // Halfling Lucky.
+        let mut test = D20Test::new(0);
+        test.reroll_below(2);
+        let result = test.roll(&mut SequenceRoller::new(vec![1, 12]));
+        assert_eq!(result.natural(), 12);
+        assert_eq!(result.roll.dice[0].rerolled, Some(1));
+    }
+}
@@ -0,0 +1,363 @@
+//! Exact probability distributions of dice rolls.
+
+use crate::dice::{AdvancedRoll, D20Test, Dice, DicePool, DiceRoll, DiceRolls, Die, Keep};
+
+/// The probability distribution of the total of a roll, computed analytically.
+///
+/// # Examples
+///
+/// ```
+/// use srd::dice::{D20Test, Dice, DiceRoll, Die};
+///
+/// let mut attack = D20Test::new(5);
+/// attack.add_advantage();
+/// let to_hit = attack.distribution().probability_at_least(16);
+/// assert!((to_hit - 0.75).abs() < 1e-9);
+///
+/// let damage = DiceRoll::with_modifier(Dice::new(1, Die::D8), 3).distribution();
+/// assert_eq!(damage.mean(), 7.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    /// The lowest total with a non zero probability.
+    min: i32,
+    /// Probability of each total, starting from `min`.
+    probabilities: Vec<f64>,
+}
+
+impl Distribution {
+    /// Constructs a distribution in which `value` is certain.
+    pub fn constant(value: i32) -> Self {
+        Self {
+            min: value,
+            probabilities: vec![1.0],
+        }
+    }
+
+    /// Constructs a distribution from the probability of each total, starting from `min`.
+    /// Totals with zero probability at the edges are discarded.
+    fn from_probabilities(mut min: i32, mut probabilities: Vec<f64>) -> Self {
+        let first = probabilities.iter().position(|p| *p > 0.0).unwrap_or(0);
+        let last = probabilities
+            .iter()
+            .rposition(|p| *p > 0.0)
+            .unwrap_or(first);
+        probabilities.truncate(last + 1);
+        probabilities.drain(..first);
+        min += first as i32;
+        if probabilities.is_empty() {
+            return Self::constant(min);
+        }
+        Self { min, probabilities }
+    }
+
+    /// Returns the distribution of the sum of a value from `self` and one from `other`.
+    pub fn sum(&self, other: &Self) -> Self {
+        let mut probabilities = vec![0.0; self.probabilities.len() + other.probabilities.len() - 1];
+        for (i, p) in self.probabilities.iter().enumerate() {
+            for (j, q) in other.probabilities.iter().enumerate() {
+                probabilities[i + j] += p * q;
+            }
+        }
+        Self::from_probabilities(self.min + other.min, probabilities)
+    }
+
+    /// Returns this distribution with all totals increased by `n`.
+    pub fn shift(&self, n: i32) -> Self {
+        Self {
+            min: self.min + n,
+            probabilities: self.probabilities.clone(),
+        }
+    }
+
+    /// Returns the lowest possible total.
+    pub fn min(&self) -> i32 {
+        self.min
+    }
+
+    /// Returns the highest possible total.
+    pub fn max(&self) -> i32 {
+        self.min + self.probabilities.len() as i32 - 1
+    }
+
+    /// Returns the expected value.
+    pub fn mean(&self) -> f64 {
+        self.iter().map(|(total, p)| f64::from(total) * p).sum()
+    }
+
+    /// Returns the variance.
+    pub fn variance(&self) -> f64 {
+        let mean = self.mean();
+        self.iter()
+            .map(|(total, p)| (f64::from(total) - mean).powi(2) * p)
+            .sum()
+    }
+
+    /// Returns the standard deviation.
+    pub fn standard_deviation(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Returns the probability that the total is exactly `total`.
+    pub fn probability(&self, total: i32) -> f64 {
+        if total < self.min || total > self.max() {
+            0.0
+        } else {
+            self.probabilities[(total - self.min) as usize]
+        }
+    }
+
+    /// Returns the probability that the total is greater than or equal to `total`.
+    pub fn probability_at_least(&self, total: i32) -> f64 {
+        self.iter()
+            .filter(|(value, _)| *value >= total)
+            .map(|(_, p)| p)
+            .sum()
+    }
+
+    /// Returns the probability that the total is lower than or equal to `total`.
+    pub fn probability_at_most(&self, total: i32) -> f64 {
+        self.iter()
+            .filter(|(value, _)| *value <= total)
+            .map(|(_, p)| p)
+            .sum()
+    }
+
+    /// Returns an iterator over all possible totals and their probability.
+    pub fn iter(&self) -> impl Iterator<Item = (i32, f64)> + '_ {
+        self.probabilities
+            .iter()
+            .enumerate()
+            .map(move |(i, p)| (self.min + i as i32, *p))
+    }
+}
+
+/// Returns the probability of each face of `die`, starting from 1.\
+/// Faces lower than `reroll_below` are rolled again once.
+fn face_probabilities(die: Die, reroll_below: Option<u8>) -> Vec<f64> {
+    let sides = die.sides();
+    let p = 1.0 / f64::from(sides);
+    let rerolled = reroll_below.map_or(0, |face| face.saturating_sub(1).min(sides));
+    (1..=sides)
+        .map(|face| {
+            let first = if face > rerolled { p } else { 0.0 };
+            first + f64::from(rerolled) * p * p
+        })
+        .collect()
+}
+
+/// Returns the distribution of the sum of `n` dice, each one having the given face probabilities.
+fn summed_distribution(faces: &[f64], n: usize) -> Distribution {
+    let die = Distribution::from_probabilities(1, faces.to_vec());
+    (0..n).fold(Distribution::constant(0), |acc, _| acc.sum(&die))
+}
+
+/// Returns the distribution of the sum of the `keep` highest (or lowest) faces out of `n` dice,
+/// each one having the given face probabilities.
+fn kept_distribution(faces: &[f64], n: usize, keep: usize, highest: bool) -> Distribution {
+    // Binomial coefficients up to n.
+    let mut binomial = vec![vec![1.0; n + 1]; n + 1];
+    for i in 1..=n {
+        for j in 1..i {
+            binomial[i][j] = binomial[i - 1][j - 1] + binomial[i - 1][j];
+        }
+    }
+    let sides = faces.len();
+    // Faces are assigned from the most to the least favourable. Each state tracks how many
+    // dice were already assigned a face and the sum of the kept ones.
+    let mut states = vec![vec![0.0; keep * sides + 1]; n + 1];
+    states[0][0] = 1.0;
+    let order: Vec<usize> = if highest {
+        (1..=sides).rev().collect()
+    } else {
+        (1..=sides).collect()
+    };
+    for face in order {
+        let q = faces[face - 1];
+        let mut next = vec![vec![0.0; keep * sides + 1]; n + 1];
+        for (assigned, sums) in states.iter().enumerate() {
+            for (sum, p) in sums.iter().enumerate().filter(|(_, p)| **p > 0.0) {
+                for count in 0..=n - assigned {
+                    let kept = count.min(keep.saturating_sub(assigned));
+                    next[assigned + count][sum + kept * face] +=
+                        p * binomial[n - assigned][count] * q.powi(count as i32);
+                }
+            }
+        }
+        states = next;
+    }
+    Distribution::from_probabilities(0, states.swap_remove(n))
+}
+
+impl Die {
+    /// Returns the distribution of a single roll of this die.
+    pub fn distribution(&self) -> Distribution {
+        Distribution::from_probabilities(0, {
+            let mut probabilities = face_probabilities(*self, None);
+            probabilities.insert(0, 0.0);
+            probabilities
+        })
+    }
+}
+
+impl Dice {
+    /// Returns the distribution of the sum of all dice.
+    pub fn distribution(&self) -> Distribution {
+        let die = self.die.distribution();
+        (0..self.n).fold(Distribution::constant(0), |acc, _| acc.sum(&die))
+    }
+}
+
+impl DicePool {
+    /// Returns the distribution of the sum of all dice in this pool.
+    pub fn distribution(&self) -> Distribution {
+        self.dice().fold(Distribution::constant(0), |acc, dice| {
+            acc.sum(&dice.distribution())
+        })
+    }
+}
+
+impl DiceRoll {
+    /// Returns the distribution of the total, modifier included.
+    pub fn distribution(&self) -> Distribution {
+        self.dice.distribution().shift(i32::from(self.modifier))
+    }
+}
+
+impl DiceRolls {
+    /// Returns the distribution of the total, modifier included.
+    pub fn distribution(&self) -> Distribution {
+        self.dice_pool
+            .distribution()
+            .shift(i32::from(self.modifier))
+    }
+}
+
+impl AdvancedRoll {
+    /// Returns the distribution of the total, taking into account rerolls and discarded dice.
+    pub fn distribution(&self) -> Distribution {
+        let Dice { n, die } = self.roll.dice;
+        let faces = face_probabilities(die, self.reroll_below);
+        let n = usize::from(n);
+        let dice = match self.keep {
+            Keep::Highest(k) if usize::from(k) < n => {
+                kept_distribution(&faces, n, usize::from(k), true)
+            }
+            Keep::Lowest(k) if usize::from(k) < n => {
+                kept_distribution(&faces, n, usize::from(k), false)
+            }
+            // Keeping every die doesn't need to track which dice are discarded.
+            _ => summed_distribution(&faces, n),
+        };
+        dice.shift(i32::from(self.roll.modifier))
+    }
+}
+
+impl D20Test {
+    /// Returns the distribution of the total, modifier included.
+    pub fn distribution(&self) -> Distribution {
+        self.to_roll().distribution()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < EPSILON, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn single_die() {
+        let d = Die::D6.distribution();
+        assert_eq!(d.min(), 1);
+        assert_eq!(d.max(), 6);
+        assert_close(d.mean(), 3.5);
+        assert_close(d.variance(), 35.0 / 12.0);
+        assert_close(d.probability(4), 1.0 / 6.0);
+        assert_eq!(d.probability(7), 0.0);
+    }
+
+    #[test]
+    fn dice_rolls() {
+        let mut rolls = DiceRolls::from_roll(DiceRoll::with_modifier(Dice::new(2, Die::D6), 3));
+        let d = rolls.distribution();
+        assert_eq!(d.min(), 5);
+        assert_eq!(d.max(), 15);
+        assert_close(d.probability(10), 1.0 / 6.0);
+        assert_close(d.iter().map(|(_, p)| p).sum(), 1.0);
+        rolls.add_dice(Dice::new(1, Die::D4));
+        let d = rolls.distribution();
+        assert_eq!(d.min(), 6);
+        assert_eq!(d.max(), 19);
+        assert_close(d.mean(), 12.5);
+        assert_close(d.probability_at_least(6), 1.0);
+        assert_close(d.probability_at_most(6), 1.0 / 144.0);
+        assert_eq!(
+            DiceRolls::default().distribution(),
+            Distribution::constant(0)
+        );
+    }
+
+    #[test]
+    fn advantage_and_disadvantage() {
+        let mut test = D20Test::new(0);
+        test.add_advantage();
+        let d = test.distribution();
+        assert_close(d.probability_at_least(11), 0.75);
+        assert_close(d.probability(20), 39.0 / 400.0);
+        assert_close(d.mean(), 13.825);
+        let mut test = D20Test::new(2);
+        test.add_disadvantage();
+        let d = test.distribution();
+        assert_eq!(d.min(), 3);
+        assert_close(d.probability_at_least(13), 0.25);
+        assert_close(d.mean(), 9.175);
+    }
+
+    #[test]
+    fn keep_highest() {
+        let roll = AdvancedRoll::new(DiceRoll::new(Dice::new(4, Die::D6))).keep(Keep::Highest(3));
+        let d = roll.distribution();
+        assert_eq!(d.min(), 3);
+        assert_eq!(d.max(), 18);
+        assert_close(d.mean(), 15869.0 / 1296.0);
+        assert_close(d.probability(18), 21.0 / 1296.0);
+    }
+
+    #[test]
+    fn keep_all_matches_plain_dice() {
+        let dice = Dice::new(3, Die::D8);
+        let plain = dice.distribution();
+        for keep in &[Keep::All, Keep::Lowest(3), Keep::Highest(4)] {
+            let advanced = AdvancedRoll::new(DiceRoll::new(dice))
+                .keep(*keep)
+                .distribution();
+            assert_eq!(plain.min(), advanced.min());
+            assert_eq!(plain.max(), advanced.max());
+            for (total, p) in plain.iter() {
+                assert_close(p, advanced.probability(total));
+            }
+        }
+        // Large pools are summed directly.
+        let pool = AdvancedRoll::new(DiceRoll::new(Dice::new(50, Die::D20))).distribution();
+        assert_close(pool.mean(), 525.0);
+    }
+
+    #[test]
+    fn reroll() {
+        let roll = AdvancedRoll::new(DiceRoll::new(Dice::new(2, Die::D6))).reroll_below(3);
+        let d = roll.distribution();
+        assert_close(d.mean(), 2.0 * 150.0 / 36.0);
+        assert_close(d.probability(2), (2.0 / 36.0) * (2.0 / 36.0));
+        // Rerolls and discarded dice together.
+        let kept = AdvancedRoll::new(DiceRoll::new(Dice::new(3, Die::D6)))
+            .reroll_below(3)
+            .keep(Keep::Highest(2))
+            .distribution();
+        assert_eq!((kept.min(), kept.max()), (2, 12));
+    }
+}
@@ -1,9 +1,33 @@
 //! Die and dice module.
 
+pub mod advanced;
+pub use self::advanced::{
+    AdvancedRoll, AdvancedRollResult, D20Test, D20TestResult, Keep, RollMode, RolledDie,
+};
+
+pub mod distribution;
+pub use self::distribution::Distribution;
+
+pub mod notation;
+
+pub mod roll;
+pub use self::roll::{DiceRollsResult, DieResult, Roller, SeededRoller};
+
 use serde::{Deserialize, Serialize};
 
 const DIE_TYPES_COUNT: usize = 7;
 
+/// All types of die, ordered by their id.
+const DIE_TYPES: [Die; DIE_TYPES_COUNT] = [
+    Die::D4,
+    Die::D6,
+    Die::D8,
+    Die::D10,
+    Die::D12,
+    Die::D20,
+    Die::D100,
+];
+
 /// Types of die used in the SRD.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Die {
@@ -61,11 +85,28 @@ impl Die {
     pub const fn max(&self) -> u8 {
         self.sides()
     }
+
+    /// Returns the die having the given number of sides, if it exists.
+    pub const fn from_sides(sides: u8) -> Option<Self> {
+        use Die::*;
+        match sides {
+            4 => Some(D4),
+            6 => Some(D6),
+            8 => Some(D8),
+            10 => Some(D10),
+            12 => Some(D12),
+            20 => Some(D20),
+            100 => Some(D100),
+            _ => None,
+        }
+    }
 }
 
 /// A dice represents how many times a particular die should be rolled.
 /// It is the primary way to resolve random actions in the SRD.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, new)]
+///
+/// `Dice` can be parsed from and formatted to the standard notation, for instance `2d6`.
+#[derive(Debug, Clone, Copy, PartialEq, new)]
 pub struct Dice {
     /// Number of times the die should be rolled.
     pub n: u8,
@@ -93,10 +134,26 @@ impl DicePool {
         self.pool[dice.die.id()] += dice.n;
         self
     }
+
+    /// Returns how many times `die` should be rolled.
+    pub const fn count(&self, die: Die) -> u8 {
+        self.pool[die.id()]
+    }
+
+    /// Returns an iterator over all dice in this pool, from the smallest to the largest die.\
+    /// Dice with zero rolls are skipped.
+    pub fn dice(&self) -> impl Iterator<Item = Dice> + '_ {
+        DIE_TYPES
+            .iter()
+            .filter(move |die| self.count(**die) > 0)
+            .map(move |die| Dice::new(self.count(*die), *die))
+    }
 }
 
 /// A dice roll is a `Dice` plus a modifier to be added to the final result.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+///
+/// `DiceRoll` can be parsed from and formatted to the standard notation, for instance `2d6+3`.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DiceRoll {
     /// The dice to be rolled.
     pub dice: Dice,
@@ -119,7 +176,10 @@ impl DiceRoll {
 /// Similar to `DicePool` with the addition of a modifier.
 /// Internal dice roll(s) are compacted, in the sense that
 /// there's a single modifier and roll(s) of an individual die are repeated only once.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+///
+/// `DiceRolls` can be parsed from and formatted to the standard notation,
+/// for instance `1d8+1d6-1`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct DiceRolls {
     /// The dice pool to be rolled.
     pub dice_pool: DicePool,
@@ -185,6 +245,18 @@ mod tests {
         assert_eq!(dc.pool, [1, 0, 1, 0, 0, 0, 0]);
     }
 
+    #[test]
+    fn dice_pool_iteration() {
+        let mut dc = DicePool::default();
+        assert_eq!(dc.dice().count(), 0);
+        dc.add_dice(Dice::new(2, Die::D8))
+            .add_dice(Dice::new(1, Die::D4));
+        assert_eq!(dc.count(Die::D8), 2);
+        assert_eq!(dc.count(Die::D6), 0);
+        let dice: Vec<_> = dc.dice().collect();
+        assert_eq!(dice, vec![Dice::new(1, Die::D4), Dice::new(2, Die::D8)]);
+    }
+
     #[test]
     fn dice_rolls_add() {
         let mut dc = DiceRolls::from_roll(DiceRoll::with_modifier(Dice::new(2, Die::D4), 3));
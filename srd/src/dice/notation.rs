@@ -0,0 +1,560 @@
+//! Dice notation.
+//!
+//! Dice are written in the form `NdS`, where `N` is the number of dice (`1` when omitted)
+//! and `S` the number of sides of the die. Dice and numerical modifiers can be
+//! combined with `+` and `-`, for example `2d6+3`, `1d8 + 1d6 - 1` or `d100`.
+//!
+//! In human readable formats `Dice`, `DiceRoll` and `DiceRolls` are serialized using
+//! this notation. Deserialization accepts both the notation and the struct form.
+
+use crate::dice::{Dice, DicePool, DiceRoll, DiceRolls, Die};
+use crate::error::{SRDError, SRDResult};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+impl fmt::Display for Die {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "d{}", self.sides())
+    }
+}
+
+impl fmt::Display for Dice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.n, self.die)
+    }
+}
+
+impl fmt::Display for DiceRoll {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.dice)?;
+        write_modifier(f, self.modifier)
+    }
+}
+
+impl fmt::Display for DiceRolls {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut empty = true;
+        for dice in self.dice_pool.dice() {
+            if !empty {
+                f.write_str("+")?;
+            }
+            write!(f, "{}", dice)?;
+            empty = false;
+        }
+        if empty {
+            write!(f, "{}", self.modifier)
+        } else {
+            write_modifier(f, self.modifier)
+        }
+    }
+}
+
+/// Writes a modifier following a dice term. Zero modifiers are omitted.
+fn write_modifier(f: &mut fmt::Formatter, modifier: i16) -> fmt::Result {
+    match modifier {
+        0 => Ok(()),
+        n if n > 0 => write!(f, "+{}", n),
+        n => write!(f, "-{}", n.unsigned_abs()),
+    }
+}
+
+impl FromStr for Dice {
+    type Err = SRDError;
+
+    fn from_str(s: &str) -> SRDResult<Self> {
+        let terms = parse_terms(s)?;
+        match terms.as_slice() {
+            [Term {
+                value: TermValue::Dice(dice),
+                ..
+            }] => Ok(*dice),
+            _ => Err(invalid_notation(s, "expected a single dice, such as '2d6'")),
+        }
+    }
+}
+
+impl FromStr for DiceRoll {
+    type Err = SRDError;
+
+    fn from_str(s: &str) -> SRDResult<Self> {
+        let mut dice = None;
+        let mut modifier: i16 = 0;
+        for term in parse_terms(s)? {
+            match term.value {
+                TermValue::Dice(value) => {
+                    if dice.is_some() {
+                        return Err(term.error(s, "a dice roll can contain only one dice"));
+                    }
+                    dice = Some(value);
+                }
+                TermValue::Constant(value) => {
+                    modifier = modifier
+                        .checked_add(value)
+                        .ok_or_else(|| term.error(s, "modifier is too large"))?;
+                }
+            }
+        }
+        match dice {
+            Some(dice) => Ok(Self::with_modifier(dice, modifier)),
+            None => Err(invalid_notation(s, "a dice roll must contain a dice")),
+        }
+    }
+}
+
+impl FromStr for DiceRolls {
+    type Err = SRDError;
+
+    fn from_str(s: &str) -> SRDResult<Self> {
+        let mut rolls = Self::default();
+        for term in parse_terms(s)? {
+            match term.value {
+                TermValue::Dice(dice) => {
+                    if rolls
+                        .dice_pool
+                        .count(dice.die)
+                        .checked_add(dice.n)
+                        .is_none()
+                    {
+                        return Err(term.error(s, "too many dice"));
+                    }
+                    rolls.add_dice(dice);
+                }
+                TermValue::Constant(value) => {
+                    rolls.modifier = rolls
+                        .modifier
+                        .checked_add(value)
+                        .ok_or_else(|| term.error(s, "modifier is too large"))?;
+                }
+            }
+        }
+        Ok(rolls)
+    }
+}
+
+/// A single signed term of the notation.
+struct Term<'a> {
+    /// The token as written in the notation.
+    token: &'a str,
+    /// Position (in characters, starting from 1) of the token.
+    column: usize,
+    value: TermValue,
+}
+
+impl Term<'_> {
+    fn error(&self, notation: &str, reason: &str) -> SRDError {
+        token_error(notation, self.token, self.column, reason)
+    }
+}
+
+enum TermValue {
+    Dice(Dice),
+    Constant(i16),
+}
+
+/// Splits a notation into its terms.
+fn parse_terms(s: &str) -> SRDResult<Vec<Term<'_>>> {
+    let mut terms = Vec::new();
+    // Sign of the next term, with the operator that introduced it and its column.
+    let mut sign: Option<(bool, &str, usize)> = None;
+    let mut chars = s.char_indices().enumerate().peekable();
+    while let Some((column, (start, c))) = chars.next() {
+        let column = column + 1;
+        if c.is_whitespace() {
+            continue;
+        }
+        if c == '+' || c == '-' {
+            // An operator is allowed only between terms or before the first term.
+            if sign.is_some() {
+                return Err(token_error(
+                    s,
+                    &s[start..=start],
+                    column,
+                    "unexpected operator",
+                ));
+            }
+            sign = Some((c == '-', &s[start..=start], column));
+            continue;
+        }
+        if !c.is_ascii_alphanumeric() {
+            return Err(token_error(
+                s,
+                &s[start..start + c.len_utf8()],
+                column,
+                "unexpected character",
+            ));
+        }
+        if !terms.is_empty() && sign.is_none() {
+            return Err(token_error(
+                s,
+                &s[start..start + c.len_utf8()],
+                column,
+                "expected '+' or '-'",
+            ));
+        }
+        // Consume the whole token.
+        let mut end = start + c.len_utf8();
+        while let Some((_, (i, next))) = chars.peek() {
+            if next.is_ascii_alphanumeric() {
+                end = i + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        let token = &s[start..end];
+        let negative = matches!(sign, Some((true, _, _)));
+        let value = parse_term(s, token, column, negative)?;
+        terms.push(Term {
+            token,
+            column,
+            value,
+        });
+        sign = None;
+    }
+    if let Some((_, operator, column)) = sign {
+        return Err(token_error(
+            s,
+            operator,
+            column,
+            "missing term after operator",
+        ));
+    }
+    if terms.is_empty() {
+        return Err(invalid_notation(s, "notation is empty"));
+    }
+    Ok(terms)
+}
+
+/// Parses a single token into either a dice or a constant.
+fn parse_term(notation: &str, token: &str, column: usize, negative: bool) -> SRDResult<TermValue> {
+    let error = |reason| token_error(notation, token, column, reason);
+    if let Some(index) = token.find(['d', 'D']) {
+        if negative {
+            return Err(error("dice can't be subtracted"));
+        }
+        let (count, sides) = (&token[..index], &token[index + 1..]);
+        let n = if count.is_empty() {
+            1
+        } else {
+            count
+                .parse::<u8>()
+                .map_err(|_| error("invalid number of dice"))?
+        };
+        let die = sides
+            .parse::<u8>()
+            .ok()
+            .and_then(Die::from_sides)
+            .ok_or_else(|| error("unknown die"))?;
+        Ok(TermValue::Dice(Dice::new(n, die)))
+    } else {
+        let value = token
+            .parse::<i16>()
+            .map_err(|_| error("invalid modifier"))?;
+        Ok(TermValue::Constant(if negative { -value } else { value }))
+    }
+}
+
+fn token_error(notation: &str, token: &str, column: usize, reason: &str) -> SRDError {
+    SRDError::InvalidArgument(format!(
+        "{} '{}' at column {} in dice notation '{}'",
+        reason, token, column, notation
+    ))
+}
+
+fn invalid_notation(notation: &str, reason: &str) -> SRDError {
+    SRDError::InvalidArgument(format!("{} in dice notation '{}'", reason, notation))
+}
+
+/// Struct form of `Dice`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Dice")]
+struct DiceRepr {
+    n: u8,
+    die: Die,
+}
+
+impl From<Dice> for DiceRepr {
+    fn from(item: Dice) -> Self {
+        Self {
+            n: item.n,
+            die: item.die,
+        }
+    }
+}
+
+impl From<DiceRepr> for Dice {
+    fn from(item: DiceRepr) -> Self {
+        Self::new(item.n, item.die)
+    }
+}
+
+/// Struct form of `DiceRoll`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "DiceRoll")]
+struct DiceRollRepr {
+    dice: Dice,
+    modifier: i16,
+}
+
+impl From<DiceRoll> for DiceRollRepr {
+    fn from(item: DiceRoll) -> Self {
+        Self {
+            dice: item.dice,
+            modifier: item.modifier,
+        }
+    }
+}
+
+impl From<DiceRollRepr> for DiceRoll {
+    fn from(item: DiceRollRepr) -> Self {
+        Self::with_modifier(item.dice, item.modifier)
+    }
+}
+
+/// Struct form of `DiceRolls`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "DiceRolls")]
+struct DiceRollsRepr {
+    dice_pool: DicePool,
+    modifier: i16,
+}
+
+impl From<DiceRolls> for DiceRollsRepr {
+    fn from(item: DiceRolls) -> Self {
+        Self {
+            dice_pool: item.dice_pool,
+            modifier: item.modifier,
+        }
+    }
+}
+
+impl From<DiceRollsRepr> for DiceRolls {
+    fn from(item: DiceRollsRepr) -> Self {
+        Self {
+            dice_pool: item.dice_pool,
+            modifier: item.modifier,
+        }
+    }
+}
+
+/// Any of the accepted forms in human readable formats.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HumanReadable<T> {
+    Notation(String),
+    Struct(T),
+}
+
+/// Implements `Serialize` and `Deserialize` for a type having a notation and a struct form.
+macro_rules! notation_serde {
+    ($type:ident, $repr:ident) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.collect_str(self)
+                } else {
+                    $repr::from(*self).serialize(serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    match HumanReadable::<$repr>::deserialize(deserializer)? {
+                        HumanReadable::Notation(s) => s.parse().map_err(de::Error::custom),
+                        HumanReadable::Struct(repr) => Ok(repr.into()),
+                    }
+                } else {
+                    $repr::deserialize(deserializer).map(Into::into)
+                }
+            }
+        }
+    };
+}
+
+notation_serde! {Dice, DiceRepr}
+
+notation_serde! {DiceRoll, DiceRollRepr}
+
+notation_serde! {DiceRolls, DiceRollsRepr}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+    fn error_message<T: FromStr<Err = SRDError>>(s: &str) -> String {
+        match s.parse::<T>() {
+            Err(SRDError::InvalidArgument(msg)) => msg,
+            _ => panic!("'{}' should not be valid", s),
+        }
+    }
+
+    #[test]
+    fn parse_dice() {
+        assert_eq!("2d6".parse(), Ok(Dice::new(2, Die::D6)));
+        assert_eq!("d100".parse(), Ok(Dice::new(1, Die::D100)));
+        assert_eq!(" 3D4 ".parse(), Ok(Dice::new(3, Die::D4)));
+        assert!("2d6+1".parse::<Dice>().is_err());
+        assert!("3".parse::<Dice>().is_err());
+    }
+
+    #[test]
+    fn parse_dice_roll() {
+        assert_eq!(
+            "2d6+3".parse(),
+            Ok(DiceRoll::with_modifier(Dice::new(2, Die::D6), 3))
+        );
+        assert_eq!(
+            "1d20 - 1 + 4".parse(),
+            Ok(DiceRoll::with_modifier(Dice::new(1, Die::D20), 3))
+        );
+        assert_eq!("d8".parse(), Ok(DiceRoll::new(Dice::new(1, Die::D8))));
+        assert!("1d8+1d6".parse::<DiceRoll>().is_err());
+        assert!("5".parse::<DiceRoll>().is_err());
+    }
+
+    #[test]
+    fn parse_dice_rolls() {
+        let mut expected = DiceRolls::from_roll(DiceRoll::with_modifier(Dice::new(1, Die::D8), -1));
+        expected.add_dice(Dice::new(1, Die::D6));
+        assert_eq!("1d8 + 1d6 - 1".parse(), Ok(expected));
+        let mut expected = DiceRolls::from_roll(DiceRoll::new(Dice::new(3, Die::D6)));
+        expected.modifier = 2;
+        assert_eq!("2d6+1+d6+1".parse(), Ok(expected));
+        let expected = DiceRolls {
+            modifier: -3,
+            ..Default::default()
+        };
+        assert_eq!("-3".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn parse_errors_point_at_token() {
+        assert_eq!(
+            error_message::<DiceRolls>("1d8+d7"),
+            "unknown die 'd7' at column 5 in dice notation '1d8+d7'"
+        );
+        assert_eq!(
+            error_message::<DiceRolls>("1d8-1d6"),
+            "dice can't be subtracted '1d6' at column 5 in dice notation '1d8-1d6'"
+        );
+        assert_eq!(
+            error_message::<DiceRolls>("2d6 3"),
+            "expected '+' or '-' '3' at column 5 in dice notation '2d6 3'"
+        );
+        assert_eq!(
+            error_message::<DiceRolls>("2d6+"),
+            "missing term after operator '+' at column 4 in dice notation '2d6+'"
+        );
+        assert_eq!(
+            error_message::<DiceRolls>("2d6*2"),
+            "unexpected character '*' at column 4 in dice notation '2d6*2'"
+        );
+        assert_eq!(
+            error_message::<DiceRoll>("1d8+1d6"),
+            "a dice roll can contain only one dice '1d6' at column 5 in dice notation '1d8+1d6'"
+        );
+        assert!(error_message::<DiceRolls>("").contains("empty"));
+        assert!(error_message::<DiceRolls>("2d6++1").contains("unexpected operator"));
+        assert!(error_message::<DiceRolls>("xd6").contains("invalid number of dice"));
+        assert!(error_message::<DiceRolls>("200d6+100d6").contains("too many dice"));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(Die::D12.to_string(), "d12");
+        assert_eq!(Dice::new(2, Die::D6).to_string(), "2d6");
+        assert_eq!(
+            DiceRoll::with_modifier(Dice::new(1, Die::D8), 0).to_string(),
+            "1d8"
+        );
+        assert_eq!(
+            DiceRoll::with_modifier(Dice::new(1, Die::D8), -2).to_string(),
+            "1d8-2"
+        );
+        let mut rolls = DiceRolls::from_roll(DiceRoll::with_modifier(Dice::new(1, Die::D8), 4));
+        rolls.add_dice(Dice::new(2, Die::D4));
+        assert_eq!(rolls.to_string(), "2d4+1d8+4");
+        assert_eq!(DiceRolls::default().to_string(), "0");
+    }
+
+    #[test]
+    fn format_parse_round_trip() {
+        for s in &[
+            "1d4",
+            "3d6+2",
+            "1d6+1d8-1",
+            "2d10+1d12+1d20+1d100",
+            "7",
+            "-2",
+        ] {
+            let rolls: DiceRolls = s.parse().unwrap();
+            assert_eq!(rolls.to_string(), *s);
+        }
+    }
+
+    #[test]
+    fn serde_human_readable() {
+        let dice = Dice::new(2, Die::D6);
+        assert_tokens(&dice.readable(), &[Token::Str("2d6")]);
+        let roll = DiceRoll::with_modifier(dice, 3);
+        assert_tokens(&roll.readable(), &[Token::Str("2d6+3")]);
+        let rolls = DiceRolls::from_roll(roll);
+        assert_tokens(&rolls.readable(), &[Token::Str("2d6+3")]);
+        // Zero dice are valid and survive the round trip.
+        let none = DiceRoll::with_modifier(Dice::new(0, Die::D6), 1);
+        assert_tokens(&none.dice.readable(), &[Token::Str("0d6")]);
+        assert_tokens(&none.readable(), &[Token::Str("0d6+1")]);
+        // The struct form is accepted as well.
+        assert_de_tokens(
+            &dice.readable(),
+            &[
+                Token::Struct {
+                    name: "Dice",
+                    len: 2,
+                },
+                Token::Str("n"),
+                Token::U8(2),
+                Token::Str("die"),
+                Token::UnitVariant {
+                    name: "Die",
+                    variant: "D6",
+                },
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn serde_compact() {
+        let roll = DiceRoll::with_modifier(Dice::new(1, Die::D8), -1);
+        assert_tokens(
+            &roll.compact(),
+            &[
+                Token::Struct {
+                    name: "DiceRoll",
+                    len: 2,
+                },
+                Token::Str("dice"),
+                Token::Struct {
+                    name: "Dice",
+                    len: 2,
+                },
+                Token::Str("n"),
+                Token::U8(1),
+                Token::Str("die"),
+                Token::UnitVariant {
+                    name: "Die",
+                    variant: "D8",
+                },
+                Token::StructEnd,
+                Token::Str("modifier"),
+                Token::I16(-1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Rolling of dice.
+
+use crate::dice::{Dice, DicePool, DiceRoll, DiceRolls, Die, DIE_TYPES_COUNT};
+use crate::rules::SRDRules;
+use serde::{Deserialize, Serialize};
+use weasel::entropy::EntropyRules;
+use weasel::rules::entropy::UniformDistribution;
+use weasel::Entropy;
+
+/// A source of randomness capable of rolling dice.
+pub trait Roller {
+    /// Rolls a single die and returns the face that came up.
+    fn roll_die(&mut self, die: Die) -> u8;
+}
+
+/// Dice are rolled with the battle's entropy, so that results are deterministic and
+/// replicated on all clients.
+impl Roller for Entropy<SRDRules> {
+    fn roll_die(&mut self, die: Die) -> u8 {
+        // Upper bound is exclusive.
+        self.generate(die.min(), die.max() + 1)
+    }
+}
+
+/// A roller to be used outside of battles, for instance during character creation.
+///
+/// It uses the same pseudo random number generator as `SRDRules`, thus starting from the
+/// same seed it rolls the same sequence of faces as a battle.
+pub struct SeededRoller {
+    rules: UniformDistribution<u8>,
+    model: <UniformDistribution<u8> as EntropyRules>::EntropyModel,
+}
+
+impl SeededRoller {
+    /// Constructs a new `SeededRoller` from the given seed.
+    pub fn new(seed: u64) -> Self {
+        let rules = UniformDistribution::default();
+        let model = rules.generate_model(&Some(seed));
+        Self { rules, model }
+    }
+}
+
+impl Roller for SeededRoller {
+    fn roll_die(&mut self, die: Die) -> u8 {
+        // Upper bound is exclusive.
+        self.rules
+            .generate(&mut self.model, die.min(), die.max() + 1)
+    }
+}
+
+/// A roller returning a predefined sequence of faces, starting over when exhausted.
+#[cfg(test)]
+pub(crate) struct SequenceRoller {
+    faces: Vec<u8>,
+    index: usize,
+}
+
+#[cfg(test)]
+impl SequenceRoller {
+    pub(crate) fn new(faces: Vec<u8>) -> Self {
+        Self { faces, index: 0 }
+    }
+}
+
+#[cfg(test)]
+impl Roller for SequenceRoller {
+    fn roll_die(&mut self, _: Die) -> u8 {
+        let face = self.faces[self.index % self.faces.len()];
+        self.index += 1;
+        face
+    }
+}
+
+/// The face obtained by rolling a single die.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DieResult {
+    /// The die that was rolled.
+    pub die: Die,
+    /// The face that came up.
+    pub face: u8,
+}
+
+/// The outcome of rolling a `DiceRolls`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiceRollsResult {
+    /// The result of each individual die, in the order they were rolled.
+    pub dice: Vec<DieResult>,
+    /// The sum of faces for each type of die.
+    subtotals: [u16; DIE_TYPES_COUNT],
+    /// The modifier added to the dice' result.
+    pub modifier: i16,
+}
+
+impl DiceRollsResult {
+    fn new(modifier: i16) -> Self {
+        Self {
+            dice: Vec::new(),
+            subtotals: [0; DIE_TYPES_COUNT],
+            modifier,
+        }
+    }
+
+    fn add_result(&mut self, result: DieResult) {
+        self.subtotals[result.die.id()] += u16::from(result.face);
+        self.dice.push(result);
+    }
+
+    /// Returns the sum of all faces rolled with the given type of die.
+    pub const fn subtotal(&self, die: Die) -> u16 {
+        self.subtotals[die.id()]
+    }
+
+    /// Returns the sum of all faces, without the modifier.
+    pub fn dice_total(&self) -> i32 {
+        self.subtotals.iter().map(|n| i32::from(*n)).sum()
+    }
+
+    /// Returns the final result: the sum of all faces plus the modifier.
+    pub fn total(&self) -> i32 {
+        self.dice_total() + i32::from(self.modifier)
+    }
+}
+
+impl Dice {
+    /// Rolls this dice and returns the result.
+    pub fn roll<R: Roller + ?Sized>(&self, roller: &mut R) -> DiceRollsResult {
+        DiceRolls::from_pool(DicePool::from_dice(*self)).roll(roller)
+    }
+}
+
+impl DicePool {
+    /// Rolls all dice in this pool and returns the result.
+    pub fn roll<R: Roller + ?Sized>(&self, roller: &mut R) -> DiceRollsResult {
+        DiceRolls::from_pool(*self).roll(roller)
+    }
+}
+
+impl DiceRoll {
+    /// Rolls the dice, adds the modifier and returns the result.
+    pub fn roll<R: Roller + ?Sized>(&self, roller: &mut R) -> DiceRollsResult {
+        DiceRolls::from_roll(*self).roll(roller)
+    }
+}
+
+impl DiceRolls {
+    /// Rolls all dice, adds the modifier and returns the result.\
+    /// Dice are rolled from the smallest to the largest die.
+    pub fn roll<R: Roller + ?Sized>(&self, roller: &mut R) -> DiceRollsResult {
+        let mut result = DiceRollsResult::new(self.modifier);
+        for dice in self.dice_pool.dice() {
+            for _ in 0..dice.n {
+                let face = roller.roll_die(dice.die);
+                result.add_result(DieResult {
+                    die: dice.die,
+                    face,
+                });
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::DIE_TYPES;
+    use crate::rules::narrator::NopNarrator;
+    use weasel::Battle;
+
+    /// A roller returning always the same face.
+    struct FixedRoller(u8);
+
+    impl Roller for FixedRoller {
+        fn roll_die(&mut self, _: Die) -> u8 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn faces_are_within_bounds() {
+        let mut roller = SeededRoller::new(42);
+        for die in DIE_TYPES.iter() {
+            for _ in 0..200 {
+                let face = roller.roll_die(*die);
+                assert!(face >= die.min() && face <= die.max());
+            }
+        }
+    }
+
+    #[test]
+    fn seeded_roller_is_deterministic() {
+        let rolls = DiceRolls::from_roll(DiceRoll::with_modifier(Dice::new(10, Die::D20), 2));
+        let first = rolls.roll(&mut SeededRoller::new(7));
+        let second = rolls.roll(&mut SeededRoller::new(7));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn seeded_roller_matches_battle_entropy() {
+        let rules = SRDRules::new(std::sync::Arc::new(NopNarrator::default()));
+        let mut battle = Battle::builder(rules).build();
+        // A battle without an explicit seed uses the default one.
+        let mut roller = SeededRoller::new(0);
+        for die in DIE_TYPES.iter() {
+            assert_eq!(battle.entropy_mut().roll_die(*die), roller.roll_die(*die));
+        }
+    }
+
+    #[test]
+    fn result_totals() {
+        let mut rolls = DiceRolls::from_roll(DiceRoll::with_modifier(Dice::new(2, Die::D6), -1));
+        rolls.add_dice(Dice::new(1, Die::D8));
+        let result = rolls.roll(&mut FixedRoller(3));
+        assert_eq!(result.dice.len(), 3);
+        assert_eq!(result.subtotal(Die::D6), 6);
+        assert_eq!(result.subtotal(Die::D8), 3);
+        assert_eq!(result.subtotal(Die::D20), 0);
+        assert_eq!(result.modifier, -1);
+        assert_eq!(result.dice_total(), 9);
+        assert_eq!(result.total(), 8);
+    }
+
+    #[test]
+    fn roll_single_dice() {
+        let result = Dice::new(4, Die::D4).roll(&mut FixedRoller(4));
+        assert_eq!(result.total(), 16);
+        let result = DiceRoll::with_modifier(Dice::new(1, Die::D100), 5).roll(&mut FixedRoller(1));
+        assert_eq!(result.total(), 6);
+    }
+}
@@ -0,0 +1,73 @@
+//! Ammunition for ranged weapons.
+
+use crate::error::{SRDError, SRDResult};
+use serde::{Deserialize, Serialize};
+
+/// The pieces of ammunition carried by a creature during a battle, such as arrows, bolts,
+/// needles or sling bullets.
+///
+/// # Reference
+///
+/// Each time you attack with the weapon, you expend one piece of ammunition. Drawing the
+/// ammunition from a quiver, case, or other container is part of the attack. At the end of the
+/// battle, you can recover half your expended ammunition by taking a minute to search the
+/// battlefield.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Ammunition {
+    left: u16,
+    spent: u16,
+}
+
+impl Ammunition {
+    /// Creates a new `Ammunition` with `left` pieces.
+    pub const fn new(left: u16) -> Self {
+        Self { left, spent: 0 }
+    }
+
+    /// Returns the number of pieces left.
+    pub const fn left(&self) -> u16 {
+        self.left
+    }
+
+    /// Returns the number of pieces expended so far.
+    pub const fn spent(&self) -> u16 {
+        self.spent
+    }
+
+    /// Expends one piece of ammunition.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if there's no ammunition left.
+    pub fn spend(&mut self) -> SRDResult<()> {
+        if self.left == 0 {
+            return Err(SRDError::OutOfAmmunition);
+        }
+        self.left -= 1;
+        self.spent += 1;
+        Ok(())
+    }
+
+    /// Returns the number of pieces owned after searching the battlefield, that is the pieces
+    /// left plus half of those expended, rounded down.
+    pub const fn recovered(&self) -> u16 {
+        self.left + self.spent / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spend_and_recover() {
+        let mut ammunition = Ammunition::new(3);
+        for _ in 0..3 {
+            assert!(ammunition.spend().is_ok());
+        }
+        assert_eq!(ammunition.spend(), Err(SRDError::OutOfAmmunition));
+        assert_eq!(ammunition.left(), 0);
+        assert_eq!(ammunition.spent(), 3);
+        assert_eq!(ammunition.recovered(), 1);
+    }
+}
@@ -0,0 +1,207 @@
+//! Armor and shields.
+
+use crate::ability::{AbilityId, AbilityScore};
+use serde::{Deserialize, Serialize};
+
+/// Categories of armor.
+///
+/// # Reference
+///
+/// The Armor table collects the most commonly available types of armor found in the game and
+/// separates them into three categories: light armor, medium armor, and heavy armor. Many
+/// warriors supplement their armor with a shield.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub enum ArmorCategory {
+    /// Light armor.
+    Light,
+    /// Medium armor.
+    Medium,
+    /// Heavy armor.
+    Heavy,
+    /// Shields.
+    Shield,
+}
+
+/// Base armor class of a creature without armor or shield.
+const UNARMORED_BASE: u8 = 10;
+
+/// Armor class bonus granted by a shield.
+///
+/// # Reference
+///
+/// A shield is made from wood or metal and is carried in one hand. Wielding a shield increases
+/// your Armor Class by 2.
+pub const SHIELD_BONUS: u8 = 2;
+
+/// Highest Dexterity modifier added to the armor class of medium armor.
+const MEDIUM_ARMOR_MAX_DEXTERITY: i8 = 2;
+
+/// Speed lost by a creature wearing heavy armor without the required Strength.
+pub const HEAVY_ARMOR_SPEED_PENALTY_FT: u16 = 10;
+
+/// A suit of armor.
+///
+/// # Reference
+///
+/// **Armor Class (AC):** Armor protects its wearer from attacks. The armor (and shield) you wear
+/// determines your base Armor Class.\
+/// **Strength:** If the Armor table shows "Str 13" or "Str 15" in the Strength column for an
+/// armor type, the armor reduces the wearer's speed by 10 feet unless the wearer has a Strength
+/// score equal to or higher than the listed score.\
+/// **Stealth:** If the Armor table shows "Disadvantage" in the Stealth column, the wearer has
+/// disadvantage on Dexterity (Stealth) checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Armor {
+    /// The category of the armor. Shields are carried with `Character::equip_shield` instead.
+    pub category: ArmorCategory,
+    /// The base armor class.
+    pub base: u8,
+    /// The Strength score needed to wear the armor without losing speed.
+    pub strength: Option<u8>,
+    /// Whether or not the wearer has disadvantage on Dexterity (Stealth) checks.
+    pub stealth_disadvantage: bool,
+}
+
+impl Armor {
+    const fn new(
+        category: ArmorCategory,
+        base: u8,
+        strength: Option<u8>,
+        stealth_disadvantage: bool,
+    ) -> Self {
+        Self {
+            category,
+            base,
+            strength,
+            stealth_disadvantage,
+        }
+    }
+
+    /// Returns the armor class of a creature wearing this armor, without shield.
+    ///
+    /// # Reference
+    ///
+    /// **Light Armor:** you add your Dexterity modifier to the base number from your armor type
+    /// to determine your Armor Class.\
+    /// **Medium Armor:** you add your Dexterity modifier, to a maximum of +2, to the base number
+    /// from your armor type to determine your Armor Class.\
+    /// **Heavy Armor:** you don't add your Dexterity modifier to your Armor Class, but you also
+    /// don't suffer a penalty if your Dexterity modifier is negative.
+    pub fn armor_class(&self, dexterity: AbilityScore) -> ArmorClass {
+        let modifier = dexterity.modifier();
+        let bonus = match self.category {
+            ArmorCategory::Light => modifier,
+            ArmorCategory::Medium => modifier.min(MEDIUM_ARMOR_MAX_DEXTERITY),
+            ArmorCategory::Heavy | ArmorCategory::Shield => 0,
+        };
+        ArmorClass((i16::from(self.base) + i16::from(bonus)).max(0) as u8)
+    }
+
+    /// Returns true if a creature with the given Strength score loses speed while wearing
+    /// this armor.
+    pub fn slows(&self, strength: AbilityScore) -> bool {
+        self.strength
+            .is_some_and(|required| strength.value() < required)
+    }
+}
+
+/// Padded armor.
+pub const PADDED: Armor = Armor::new(ArmorCategory::Light, 11, None, true);
+/// Leather armor.
+pub const LEATHER: Armor = Armor::new(ArmorCategory::Light, 11, None, false);
+/// Studded leather armor.
+pub const STUDDED_LEATHER: Armor = Armor::new(ArmorCategory::Light, 12, None, false);
+/// Hide armor.
+pub const HIDE: Armor = Armor::new(ArmorCategory::Medium, 12, None, false);
+/// Chain shirt.
+pub const CHAIN_SHIRT: Armor = Armor::new(ArmorCategory::Medium, 13, None, false);
+/// Scale mail.
+pub const SCALE_MAIL: Armor = Armor::new(ArmorCategory::Medium, 14, None, true);
+/// Breastplate.
+pub const BREASTPLATE: Armor = Armor::new(ArmorCategory::Medium, 14, None, false);
+/// Half plate.
+pub const HALF_PLATE: Armor = Armor::new(ArmorCategory::Medium, 15, None, true);
+/// Ring mail.
+pub const RING_MAIL: Armor = Armor::new(ArmorCategory::Heavy, 14, None, true);
+/// Chain mail.
+pub const CHAIN_MAIL: Armor = Armor::new(ArmorCategory::Heavy, 16, Some(13), true);
+/// Splint armor.
+pub const SPLINT: Armor = Armor::new(ArmorCategory::Heavy, 17, Some(15), true);
+/// Plate armor.
+pub const PLATE: Armor = Armor::new(ArmorCategory::Heavy, 18, Some(15), true);
+
+/// A class feature computing the armor class of a creature without armor from two abilities.
+///
+/// # Reference
+///
+/// **Unarmored Defense:** While you are not wearing any armor, your Armor Class equals
+/// 10 + your Dexterity modifier + your Constitution modifier (barbarian) or Wisdom modifier
+/// (monk). A barbarian can use a shield and still gain this benefit, a monk can't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UnarmoredDefense {
+    /// The ability whose modifier is added to the Dexterity modifier.
+    pub ability: AbilityId,
+    /// Whether or not the feature still works while wielding a shield.
+    pub allows_shield: bool,
+}
+
+/// How hard it is to hit a creature with an attack.
+///
+/// # Reference
+///
+/// Your Armor Class (AC) represents how well your character avoids being wounded in battle.
+/// Things that contribute to your AC include the armor you wear, the shield you carry, and
+/// your Dexterity modifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ArmorClass(pub u8);
+
+impl ArmorClass {
+    /// Returns the armor class of a creature without armor or shield.
+    ///
+    /// # Reference
+    ///
+    /// Without armor or a shield, your character's AC equals 10 + his or her Dexterity modifier.
+    pub fn unarmored(dexterity: AbilityScore) -> Self {
+        Self((i16::from(UNARMORED_BASE) + i16::from(dexterity.modifier())).max(0) as u8)
+    }
+
+    /// Returns the armor class of a creature without armor benefiting from Unarmored Defense,
+    /// given its Dexterity score and the score of the feature's other ability.
+    pub fn unarmored_defense(dexterity: AbilityScore, other: AbilityScore) -> Self {
+        let Self(base) = Self::unarmored(dexterity);
+        Self((i16::from(base) + i16::from(other.modifier())).max(0) as u8)
+    }
+}
+
+impl From<u8> for ArmorClass {
+    fn from(item: u8) -> Self {
+        Self(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unarmored_armor_class() {
+        assert_eq!(ArmorClass::unarmored(AbilityScore::capped(14)), 12.into());
+        assert_eq!(ArmorClass::unarmored(AbilityScore::capped(7)), 8.into());
+        assert_eq!(
+            ArmorClass::unarmored_defense(AbilityScore::capped(14), AbilityScore::capped(16)),
+            15.into()
+        );
+    }
+
+    #[test]
+    fn armor_categories() {
+        let dexterity = AbilityScore::capped(18);
+        assert_eq!(STUDDED_LEATHER.armor_class(dexterity), 16.into());
+        assert_eq!(BREASTPLATE.armor_class(dexterity), 16.into());
+        assert_eq!(PLATE.armor_class(dexterity), 18.into());
+        assert_eq!(CHAIN_MAIL.armor_class(AbilityScore::capped(6)), 16.into());
+        assert!(CHAIN_MAIL.slows(AbilityScore::capped(12)));
+        assert!(!CHAIN_MAIL.slows(AbilityScore::capped(13)));
+        assert!(!LEATHER.slows(AbilityScore::capped(3)));
+    }
+}
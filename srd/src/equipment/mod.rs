@@ -0,0 +1,16 @@
+//! Equipment: armor, weapons, ammunition and tools.
+
+pub mod ammunition;
+pub use self::ammunition::Ammunition;
+
+pub mod armor;
+pub use self::armor::{Armor, ArmorCategory, ArmorClass, UnarmoredDefense};
+
+pub mod tool;
+pub use self::tool::ToolId;
+
+pub mod weapon;
+pub use self::weapon::{
+    Weapon, WeaponCategory, WeaponId, WeaponKind, WeaponProficiency, WeaponProperty, WeaponRange,
+    WeaponSlots,
+};
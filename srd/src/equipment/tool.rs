@@ -0,0 +1,49 @@
+//! Tools.
+
+use serde::{Deserialize, Serialize};
+
+/// Identifies a tool.
+///
+/// # Reference
+///
+/// A tool helps you to do something you couldn't otherwise do, such as craft or repair an item,
+/// forge a document, or pick a lock. Your race, class, background, or feats give you
+/// proficiency with certain tools.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct ToolId(pub u8);
+
+impl From<u8> for ToolId {
+    fn from(item: u8) -> Self {
+        Self(item)
+    }
+}
+
+/// Id of the artisan's tools.
+pub const ARTISANS_TOOLS: ToolId = ToolId(0);
+
+/// Id of the disguise kit.
+pub const DISGUISE_KIT: ToolId = ToolId(1);
+
+/// Id of the forgery kit.
+pub const FORGERY_KIT: ToolId = ToolId(2);
+
+/// Id of the gaming set.
+pub const GAMING_SET: ToolId = ToolId(3);
+
+/// Id of the herbalism kit.
+pub const HERBALISM_KIT: ToolId = ToolId(4);
+
+/// Id of the musical instrument.
+pub const MUSICAL_INSTRUMENT: ToolId = ToolId(5);
+
+/// Id of the navigator's tools.
+pub const NAVIGATORS_TOOLS: ToolId = ToolId(6);
+
+/// Id of the poisoner's kit.
+pub const POISONERS_KIT: ToolId = ToolId(7);
+
+/// Id of the thieves' tools.
+pub const THIEVES_TOOLS: ToolId = ToolId(8);
+
+/// Number of core tools.
+pub const RESERVED_TOOLS: u8 = 9;
@@ -0,0 +1,544 @@
+//! Weapons.
+
+use crate::damage::DamageType;
+use crate::dice::{Dice, DiceRoll, DiceRolls, Die};
+use crate::rules::core::constants::DEFAULT_REACH_FT;
+use serde::{Deserialize, Serialize};
+
+/// Identifies a weapon.
+///
+/// Weapons from the SRD have an id lower than `RESERVED_WEAPONS`.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct WeaponId(pub u8);
+
+impl From<u8> for WeaponId {
+    fn from(item: u8) -> Self {
+        Self(item)
+    }
+}
+
+/// Id of the club: simple melee, 1d4 bludgeoning, light.
+pub const CLUB: WeaponId = WeaponId(0);
+/// Id of the dagger: simple melee, 1d4 piercing, finesse, light, thrown (20/60).
+pub const DAGGER: WeaponId = WeaponId(1);
+/// Id of the greatclub: simple melee, 1d8 bludgeoning, two-handed.
+pub const GREATCLUB: WeaponId = WeaponId(2);
+/// Id of the handaxe: simple melee, 1d6 slashing, light, thrown (20/60).
+pub const HANDAXE: WeaponId = WeaponId(3);
+/// Id of the javelin: simple melee, 1d6 piercing, thrown (30/120).
+pub const JAVELIN: WeaponId = WeaponId(4);
+/// Id of the light hammer: simple melee, 1d4 bludgeoning, light, thrown (20/60).
+pub const LIGHT_HAMMER: WeaponId = WeaponId(5);
+/// Id of the mace: simple melee, 1d6 bludgeoning.
+pub const MACE: WeaponId = WeaponId(6);
+/// Id of the quarterstaff: simple melee, 1d6 bludgeoning, versatile (1d8).
+pub const QUARTERSTAFF: WeaponId = WeaponId(7);
+/// Id of the sickle: simple melee, 1d4 slashing, light.
+pub const SICKLE: WeaponId = WeaponId(8);
+/// Id of the spear: simple melee, 1d6 piercing, thrown (20/60), versatile (1d8).
+pub const SPEAR: WeaponId = WeaponId(9);
+/// Id of the light crossbow: simple ranged, 1d8 piercing, ammunition (80/320), loading,
+/// two-handed.
+pub const LIGHT_CROSSBOW: WeaponId = WeaponId(10);
+/// Id of the dart: simple ranged, 1d4 piercing, finesse, thrown (20/60).
+pub const DART: WeaponId = WeaponId(11);
+/// Id of the shortbow: simple ranged, 1d6 piercing, ammunition (80/320), two-handed.
+pub const SHORTBOW: WeaponId = WeaponId(12);
+/// Id of the sling: simple ranged, 1d4 bludgeoning, ammunition (30/120).
+pub const SLING: WeaponId = WeaponId(13);
+/// Id of the battleaxe: martial melee, 1d8 slashing, versatile (1d10).
+pub const BATTLEAXE: WeaponId = WeaponId(14);
+/// Id of the flail: martial melee, 1d8 bludgeoning.
+pub const FLAIL: WeaponId = WeaponId(15);
+/// Id of the glaive: martial melee, 1d10 slashing, heavy, reach, two-handed.
+pub const GLAIVE: WeaponId = WeaponId(16);
+/// Id of the greataxe: martial melee, 1d12 slashing, heavy, two-handed.
+pub const GREATAXE: WeaponId = WeaponId(17);
+/// Id of the greatsword: martial melee, 2d6 slashing, heavy, two-handed.
+pub const GREATSWORD: WeaponId = WeaponId(18);
+/// Id of the halberd: martial melee, 1d10 slashing, heavy, reach, two-handed.
+pub const HALBERD: WeaponId = WeaponId(19);
+/// Id of the lance: martial melee, 1d12 piercing, reach, special.
+///
+/// # Reference
+///
+/// You have disadvantage when you use a lance to attack a target within 5 feet of you. Also,
+/// a lance requires two hands to wield when you aren't mounted.
+pub const LANCE: WeaponId = WeaponId(20);
+/// Id of the longsword: martial melee, 1d8 slashing, versatile (1d10).
+pub const LONGSWORD: WeaponId = WeaponId(21);
+/// Id of the maul: martial melee, 2d6 bludgeoning, heavy, two-handed.
+pub const MAUL: WeaponId = WeaponId(22);
+/// Id of the morningstar: martial melee, 1d8 piercing.
+pub const MORNINGSTAR: WeaponId = WeaponId(23);
+/// Id of the pike: martial melee, 1d10 piercing, heavy, reach, two-handed.
+pub const PIKE: WeaponId = WeaponId(24);
+/// Id of the rapier: martial melee, 1d8 piercing, finesse.
+pub const RAPIER: WeaponId = WeaponId(25);
+/// Id of the scimitar: martial melee, 1d6 slashing, finesse, light.
+pub const SCIMITAR: WeaponId = WeaponId(26);
+/// Id of the shortsword: martial melee, 1d6 piercing, finesse, light.
+pub const SHORTSWORD: WeaponId = WeaponId(27);
+/// Id of the trident: martial melee, 1d6 piercing, thrown (20/60), versatile (1d8).
+pub const TRIDENT: WeaponId = WeaponId(28);
+/// Id of the war pick: martial melee, 1d8 piercing.
+pub const WAR_PICK: WeaponId = WeaponId(29);
+/// Id of the warhammer: martial melee, 1d8 bludgeoning, versatile (1d10).
+pub const WARHAMMER: WeaponId = WeaponId(30);
+/// Id of the whip: martial melee, 1d4 slashing, finesse, reach.
+pub const WHIP: WeaponId = WeaponId(31);
+/// Id of the blowgun: martial ranged, 1 piercing, ammunition (25/100), loading.
+pub const BLOWGUN: WeaponId = WeaponId(32);
+/// Id of the hand crossbow: martial ranged, 1d6 piercing, ammunition (30/120), light, loading.
+pub const HAND_CROSSBOW: WeaponId = WeaponId(33);
+/// Id of the heavy crossbow: martial ranged, 1d10 piercing, ammunition (100/400), heavy,
+/// loading, two-handed.
+pub const HEAVY_CROSSBOW: WeaponId = WeaponId(34);
+/// Id of the longbow: martial ranged, 1d8 piercing, ammunition (150/600), heavy, two-handed.
+pub const LONGBOW: WeaponId = WeaponId(35);
+/// Id of the net: martial ranged, special, thrown (5/15).
+///
+/// # Reference
+///
+/// A Large or smaller creature hit by a net is restrained until it is freed. A net has no
+/// effect on creatures that are formless, or creatures that are Huge or larger.
+pub const NET: WeaponId = WeaponId(36);
+
+/// Number of weapons from the SRD.
+pub const RESERVED_WEAPONS: u8 = 37;
+
+/// Categories of weapons.
+///
+/// # Reference
+///
+/// Every weapon is classified as either simple or martial. Most people can use simple weapons
+/// with proficiency. Martial weapons require more specialized training to use effectively.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub enum WeaponCategory {
+    /// Simple weapons.
+    Simple,
+    /// Martial weapons.
+    Martial,
+}
+
+/// A proficiency with weapons.
+///
+/// # Reference
+///
+/// Your race, class, and feats can grant you proficiency with certain weapons or categories
+/// of weapons.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub enum WeaponProficiency {
+    /// Proficiency with all weapons of a category.
+    Category(WeaponCategory),
+    /// Proficiency with a single weapon.
+    Weapon(WeaponId),
+}
+
+/// Whether a weapon is used for melee or ranged attacks.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub enum WeaponKind {
+    /// A melee weapon.
+    Melee,
+    /// A ranged weapon.
+    Ranged,
+}
+
+/// The range of a weapon that can be thrown or fires ammunition, in feet.
+///
+/// # Reference
+///
+/// The range lists two numbers. The first is the weapon's normal range in feet, and the second
+/// indicates the weapon's long range. When attacking a target beyond normal range, you have
+/// disadvantage on the attack roll. You can't attack a target beyond the weapon's long range.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
+pub struct WeaponRange {
+    /// The normal range.
+    pub normal: u16,
+    /// The long range.
+    pub long: u16,
+}
+
+/// A property of a weapon.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WeaponProperty {
+    /// You can use a weapon that has the ammunition property to make a ranged attack only if you
+    /// have ammunition to fire from the weapon. Each time you attack with the weapon, you expend
+    /// one piece of ammunition.
+    Ammunition(WeaponRange),
+    /// When making an attack with a finesse weapon, you use your choice of your Strength or
+    /// Dexterity modifier for the attack and damage rolls.
+    Finesse,
+    /// Small creatures have disadvantage on attack rolls with heavy weapons.
+    Heavy,
+    /// A light weapon is small and easy to handle, making it ideal for use when fighting with
+    /// two weapons.
+    Light,
+    /// Because of the time required to load this weapon, you can fire only one piece of
+    /// ammunition from it when you use an action, bonus action, or reaction to fire it,
+    /// regardless of the number of attacks you can normally make.
+    Loading,
+    /// This weapon adds 5 feet to your reach when you attack with it.
+    Reach,
+    /// A weapon with the special property has unusual rules governing its use.
+    Special,
+    /// If a weapon has the thrown property, you can throw the weapon to make a ranged attack.
+    Thrown(WeaponRange),
+    /// This weapon requires two hands when you attack with it.
+    TwoHanded,
+    /// This weapon can be used with one or two hands. The value is the damage when the weapon is
+    /// used with two hands to make a melee attack.
+    Versatile(Dice),
+}
+
+/// A weapon, with its damage and properties.
+///
+/// # Reference
+///
+/// The Weapons table shows the most common weapons used in the fantasy gaming worlds, their
+/// price and weight, the damage they deal when they hit, and any special properties they
+/// possess.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Weapon {
+    /// The category of the weapon.
+    pub category: WeaponCategory,
+    /// Whether the weapon is used for melee or ranged attacks.
+    pub kind: WeaponKind,
+    /// The damage dealt on a hit, without ability modifier.
+    pub damage: DiceRolls,
+    /// The type of the damage.
+    pub damage_type: DamageType,
+    /// The properties of the weapon.
+    pub properties: Vec<WeaponProperty>,
+}
+
+impl Weapon {
+    /// Constructs a new `Weapon` without properties.
+    pub fn new<R: Into<DiceRolls>>(
+        category: WeaponCategory,
+        kind: WeaponKind,
+        damage: R,
+        damage_type: DamageType,
+    ) -> Self {
+        Self {
+            category,
+            kind,
+            damage: damage.into(),
+            damage_type,
+            properties: Vec::new(),
+        }
+    }
+
+    /// Adds a property to the weapon.
+    pub fn with(mut self, property: WeaponProperty) -> Self {
+        self.properties.push(property);
+        self
+    }
+
+    /// Returns true if the weapon has `property`.
+    pub fn has(&self, property: WeaponProperty) -> bool {
+        self.properties.contains(&property)
+    }
+
+    /// Returns the damage dice of the weapon when wielded with two hands, if it's versatile.
+    pub fn versatile(&self) -> Option<Dice> {
+        self.properties.iter().find_map(|property| match property {
+            WeaponProperty::Versatile(dice) => Some(*dice),
+            _ => None,
+        })
+    }
+
+    /// Returns the range of the weapon, if it fires ammunition or can be thrown.
+    pub fn range(&self) -> Option<WeaponRange> {
+        self.properties.iter().find_map(|property| match property {
+            WeaponProperty::Ammunition(range) | WeaponProperty::Thrown(range) => Some(*range),
+            _ => None,
+        })
+    }
+
+    /// Returns true if the weapon fires ammunition.
+    pub fn uses_ammunition(&self) -> bool {
+        self.properties
+            .iter()
+            .any(|property| matches!(property, WeaponProperty::Ammunition(_)))
+    }
+
+    /// Returns how far, in feet, a melee attack with the weapon can reach.
+    pub fn reach_ft(&self) -> u16 {
+        if self.has(WeaponProperty::Reach) {
+            DEFAULT_REACH_FT + 5
+        } else {
+            DEFAULT_REACH_FT
+        }
+    }
+
+    /// Returns true if the weapon can only be wielded with two hands.
+    pub fn two_handed(&self) -> bool {
+        self.has(WeaponProperty::TwoHanded)
+    }
+
+    /// Returns true if the weapon is a light melee weapon, suited to two-weapon fighting.
+    pub fn light_melee(&self) -> bool {
+        self.kind == WeaponKind::Melee && self.has(WeaponProperty::Light)
+    }
+}
+
+/// The weapons held in the hands of a creature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeaponSlots {
+    /// The weapon used by the Attack action.
+    pub main_hand: Option<WeaponId>,
+    /// The weapon held in the other hand.
+    pub off_hand: Option<WeaponId>,
+    /// Whether a shield is carried, taking up one hand.
+    pub shield: bool,
+}
+
+/// Returns all weapons from the SRD.
+pub(crate) fn srd_weapons() -> Vec<(WeaponId, Weapon)> {
+    use WeaponCategory::*;
+    use WeaponKind::*;
+    use WeaponProperty::*;
+    let dice = |n, die| -> DiceRolls { DiceRoll::new(Dice::new(n, die)).into() };
+    let range = |normal, long| WeaponRange { normal, long };
+    let simple_melee = |damage, damage_type| Weapon::new(Simple, Melee, damage, damage_type);
+    let simple_ranged = |damage, damage_type| Weapon::new(Simple, Ranged, damage, damage_type);
+    let martial_melee = |damage, damage_type| Weapon::new(Martial, Melee, damage, damage_type);
+    let martial_ranged = |damage, damage_type| Weapon::new(Martial, Ranged, damage, damage_type);
+    let (bludgeoning, piercing, slashing) = (
+        DamageType::Bludgeoning,
+        DamageType::Piercing,
+        DamageType::Slashing,
+    );
+    vec![
+        (
+            CLUB,
+            simple_melee(dice(1, Die::D4), bludgeoning).with(Light),
+        ),
+        (
+            DAGGER,
+            simple_melee(dice(1, Die::D4), piercing)
+                .with(Finesse)
+                .with(Light)
+                .with(Thrown(range(20, 60))),
+        ),
+        (
+            GREATCLUB,
+            simple_melee(dice(1, Die::D8), bludgeoning).with(TwoHanded),
+        ),
+        (
+            HANDAXE,
+            simple_melee(dice(1, Die::D6), slashing)
+                .with(Light)
+                .with(Thrown(range(20, 60))),
+        ),
+        (
+            JAVELIN,
+            simple_melee(dice(1, Die::D6), piercing).with(Thrown(range(30, 120))),
+        ),
+        (
+            LIGHT_HAMMER,
+            simple_melee(dice(1, Die::D4), bludgeoning)
+                .with(Light)
+                .with(Thrown(range(20, 60))),
+        ),
+        (MACE, simple_melee(dice(1, Die::D6), bludgeoning)),
+        (
+            QUARTERSTAFF,
+            simple_melee(dice(1, Die::D6), bludgeoning).with(Versatile(Dice::new(1, Die::D8))),
+        ),
+        (SICKLE, simple_melee(dice(1, Die::D4), slashing).with(Light)),
+        (
+            SPEAR,
+            simple_melee(dice(1, Die::D6), piercing)
+                .with(Thrown(range(20, 60)))
+                .with(Versatile(Dice::new(1, Die::D8))),
+        ),
+        (
+            LIGHT_CROSSBOW,
+            simple_ranged(dice(1, Die::D8), piercing)
+                .with(Ammunition(range(80, 320)))
+                .with(Loading)
+                .with(TwoHanded),
+        ),
+        (
+            DART,
+            simple_ranged(dice(1, Die::D4), piercing)
+                .with(Finesse)
+                .with(Thrown(range(20, 60))),
+        ),
+        (
+            SHORTBOW,
+            simple_ranged(dice(1, Die::D6), piercing)
+                .with(Ammunition(range(80, 320)))
+                .with(TwoHanded),
+        ),
+        (
+            SLING,
+            simple_ranged(dice(1, Die::D4), bludgeoning).with(Ammunition(range(30, 120))),
+        ),
+        (
+            BATTLEAXE,
+            martial_melee(dice(1, Die::D8), slashing).with(Versatile(Dice::new(1, Die::D10))),
+        ),
+        (FLAIL, martial_melee(dice(1, Die::D8), bludgeoning)),
+        (
+            GLAIVE,
+            martial_melee(dice(1, Die::D10), slashing)
+                .with(Heavy)
+                .with(Reach)
+                .with(TwoHanded),
+        ),
+        (
+            GREATAXE,
+            martial_melee(dice(1, Die::D12), slashing)
+                .with(Heavy)
+                .with(TwoHanded),
+        ),
+        (
+            GREATSWORD,
+            martial_melee(dice(2, Die::D6), slashing)
+                .with(Heavy)
+                .with(TwoHanded),
+        ),
+        (
+            HALBERD,
+            martial_melee(dice(1, Die::D10), slashing)
+                .with(Heavy)
+                .with(Reach)
+                .with(TwoHanded),
+        ),
+        (
+            LANCE,
+            martial_melee(dice(1, Die::D12), piercing)
+                .with(Reach)
+                .with(Special),
+        ),
+        (
+            LONGSWORD,
+            martial_melee(dice(1, Die::D8), slashing).with(Versatile(Dice::new(1, Die::D10))),
+        ),
+        (
+            MAUL,
+            martial_melee(dice(2, Die::D6), bludgeoning)
+                .with(Heavy)
+                .with(TwoHanded),
+        ),
+        (MORNINGSTAR, martial_melee(dice(1, Die::D8), piercing)),
+        (
+            PIKE,
+            martial_melee(dice(1, Die::D10), piercing)
+                .with(Heavy)
+                .with(Reach)
+                .with(TwoHanded),
+        ),
+        (
+            RAPIER,
+            martial_melee(dice(1, Die::D8), piercing).with(Finesse),
+        ),
+        (
+            SCIMITAR,
+            martial_melee(dice(1, Die::D6), slashing)
+                .with(Finesse)
+                .with(Light),
+        ),
+        (
+            SHORTSWORD,
+            martial_melee(dice(1, Die::D6), piercing)
+                .with(Finesse)
+                .with(Light),
+        ),
+        (
+            TRIDENT,
+            martial_melee(dice(1, Die::D6), piercing)
+                .with(Thrown(range(20, 60)))
+                .with(Versatile(Dice::new(1, Die::D8))),
+        ),
+        (WAR_PICK, martial_melee(dice(1, Die::D8), piercing)),
+        (
+            WARHAMMER,
+            martial_melee(dice(1, Die::D8), bludgeoning).with(Versatile(Dice::new(1, Die::D10))),
+        ),
+        (
+            WHIP,
+            martial_melee(dice(1, Die::D4), slashing)
+                .with(Finesse)
+                .with(Reach),
+        ),
+        (
+            BLOWGUN,
+            martial_ranged(
+                DiceRolls {
+                    modifier: 1,
+                    ..DiceRolls::default()
+                },
+                piercing,
+            )
+            .with(Ammunition(range(25, 100)))
+            .with(Loading),
+        ),
+        (
+            HAND_CROSSBOW,
+            martial_ranged(dice(1, Die::D6), piercing)
+                .with(Ammunition(range(30, 120)))
+                .with(Light)
+                .with(Loading),
+        ),
+        (
+            HEAVY_CROSSBOW,
+            martial_ranged(dice(1, Die::D10), piercing)
+                .with(Ammunition(range(100, 400)))
+                .with(Heavy)
+                .with(Loading)
+                .with(TwoHanded),
+        ),
+        (
+            LONGBOW,
+            martial_ranged(dice(1, Die::D8), piercing)
+                .with(Ammunition(range(150, 600)))
+                .with(Heavy)
+                .with(TwoHanded),
+        ),
+        (
+            NET,
+            martial_ranged(DiceRolls::default(), bludgeoning)
+                .with(Special)
+                .with(Thrown(range(5, 15))),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn srd_weapon_properties() {
+        let weapons = srd_weapons();
+        assert_eq!(weapons.len(), usize::from(RESERVED_WEAPONS));
+        let weapon = |id| {
+            weapons
+                .iter()
+                .find(|(weapon_id, _)| *weapon_id == id)
+                .map(|(_, weapon)| weapon.clone())
+                .unwrap()
+        };
+        let longsword = weapon(LONGSWORD);
+        assert_eq!(longsword.versatile(), Some(Dice::new(1, Die::D10)));
+        assert_eq!(longsword.reach_ft(), 5);
+        assert_eq!(weapon(GLAIVE).reach_ft(), 10);
+        assert!(weapon(GLAIVE).two_handed());
+        let longbow = weapon(LONGBOW);
+        assert_eq!(
+            longbow.range(),
+            Some(WeaponRange {
+                normal: 150,
+                long: 600
+            })
+        );
+        assert!(longbow.uses_ammunition());
+        assert!(!weapon(DAGGER).uses_ammunition());
+        assert!(weapon(DAGGER).has(WeaponProperty::Finesse));
+        assert_eq!(weapon(MACE).range(), None);
+    }
+}
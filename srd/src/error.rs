@@ -2,7 +2,10 @@
 
 use crate::character::class::ClassId;
 use crate::character::race::RaceId;
+use crate::equipment::WeaponId;
+use crate::rules::core::economy::ActionCost;
 use crate::rules::SRDRules;
+use crate::skill::SkillId;
 use std::error;
 use std::result::Result;
 use std::{fmt, fmt::Debug, fmt::Display};
@@ -29,12 +32,26 @@ pub enum SRDError {
     ClassNotFound(ClassId),
     /// Non existing race.
     RaceNotFound(RaceId),
+    /// Non existing skill.
+    SkillNotFound(SkillId),
+    /// Non existing weapon.
+    WeaponNotFound(WeaponId),
+    /// Skills that the class doesn't allow to choose.
+    InvalidSkillChoice(Vec<SkillId>),
     /// Non existing statistic.
     StatisticNotFound(StatisticId<SRDRules>),
     /// Max level has been reached.
     MaxLevelReached,
     /// An argument is invalid.
     InvalidArgument(String),
+    /// A position can't be occupied.
+    InvalidPosition(String),
+    /// A target is beyond the reach and range of an attack, in feet.
+    TargetOutOfRange(u16),
+    /// A creature has no ammunition left.
+    OutOfAmmunition,
+    /// A creature can't pay the cost of an action.
+    CostNotAffordable(ActionCost),
     /// Wrapper for a weasel error.
     WeaselError(WeaselErrorType<SRDRules>),
 }
@@ -54,9 +71,24 @@ impl Display for SRDError {
                 write!(f, "the class {:?} doesn't exist in the compendium", class)
             }
             RaceNotFound(race) => write!(f, "the race {:?} doesn't exist in the compendium", race),
+            SkillNotFound(skill) => {
+                write!(f, "the skill {:?} doesn't exist in the compendium", skill)
+            }
+            WeaponNotFound(weapon) => {
+                write!(f, "the weapon {:?} doesn't exist in the compendium", weapon)
+            }
+            InvalidSkillChoice(skills) => {
+                write!(f, "the skills {:?} are not a valid class choice", skills)
+            }
             StatisticNotFound(id) => write!(f, "statistic with id {:?} not found", id),
             MaxLevelReached => write!(f, "the maximum allowed level has been reached"),
             InvalidArgument(s) => write!(f, "invalid argument: {}", s),
+            InvalidPosition(s) => write!(f, "invalid position: {}", s),
+            TargetOutOfRange(distance) => {
+                write!(f, "the target is {} ft away, out of range", distance)
+            }
+            OutOfAmmunition => write!(f, "no ammunition left"),
+            CostNotAffordable(cost) => write!(f, "no {} left this turn", cost),
             WeaselError(err) => write!(f, "WeaselError {{{}}}", err),
         }
     }
@@ -4,16 +4,26 @@ use crate::ability::{AbilityId, AbilityScore};
 use crate::character::class::ClassId;
 use crate::character::level::Level;
 use crate::character::race::RaceId;
+use crate::damage::{DamageDefense, DamageType};
+use crate::equipment::{
+    Ammunition, Armor, ArmorCategory, ArmorClass, ToolId, WeaponProficiency, WeaponSlots,
+};
 use crate::error::{SRDError, SRDResult};
-use crate::hit_points::HitPoints;
-use crate::proficiency::Proficiency;
-use crate::rules::core::action::Action;
-use crate::rules::core::{CreatureSize, StatisticId};
+use crate::hit_points::{HitPoints, TemporaryHitPoints};
+use crate::proficiency::{Proficiency, ProficiencyBonus};
+use crate::rules::core::action::{Action, ActionId, Activation, ObjectInteraction};
+use crate::rules::core::{
+    ConditionId, Conditions, ConditionsAlteration, Cover, CreatureSize, CriticalHit, Footprint,
+    Help, LifeState, MovementMode, ReadyTrigger, ShoveEffect, Square, StatisticChange, StatisticId,
+    Tactics, TurnBudget,
+};
 use crate::rules::SRDRules;
 use crate::skill::SkillId;
+use std::convert::TryFrom;
 use weasel::{
-    creature::CreatureId, space::Position, Actor, BattleController, Character, Creature, Entity,
-    EventProcessor, Id, WeaselError, WeaselResult,
+    creature::CreatureId, space::Position, ActivateAbility, Actor, AlterStatistics, AlterStatuses,
+    BattleController, Character, Creature, Entity, EntityId, EventProcessor, EventTrigger, Id,
+    WeaselError, WeaselResult,
 };
 
 /// Macro to generate an accessor to a creature statistic.
@@ -70,8 +80,74 @@ macro_rules! generate_immutable_methods {
 
         statistic_accessor! {hit_points, HitPoints, HitPoints}
 
+        statistic_accessor! {temporary_hit_points, TemporaryHitPoints, TemporaryHitPoints}
+
+        statistic_accessor! {life_state, LifeState, LifeState}
+
+        /// Returns the creature's current hit points, without temporary hit points.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists or if it doesn't have hit points.
+        ///
+        pub fn current_hit_points(&self) -> SRDResult<u16> {
+            Ok(self.hit_points()?.value())
+        }
+
+        /// Returns the creature's hit point maximum, which can be lowered by conditions.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists or if it doesn't have hit points.
+        ///
+        pub fn max_hit_points(&self) -> SRDResult<u16> {
+            Ok(self.conditions()?.hit_points(self.hit_points()?).max())
+        }
+
         statistic_accessor! {size, Size, CreatureSize}
 
+        statistic_accessor! {armor_class, ArmorClass, ArmorClass}
+
+        statistic_accessor! {armor, Armor, Armor}
+
+        statistic_accessor! {ammunition, Ammunition, Ammunition}
+
+        statistic_accessor! {weapon_slots, WeaponSlots, WeaponSlots}
+
+        statistic_accessor! {critical_hit, CriticalHit, CriticalHit}
+
+        statistic_accessor! {turn_budget, TurnBudget, TurnBudget}
+
+        statistic_accessor! {tactics, Tactics, Tactics}
+
+        statistic_accessor! {proficiency_bonus, ProficiencyBonus, ProficiencyBonus}
+
+        /// Returns the creature's score in the given ability.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists or if it doesn't have such ability.
+        ///
+        pub fn ability(&self, id: AbilityId) -> SRDResult<&AbilityScore> {
+            self.creature()?
+                .statistic(&StatisticId::Ability(id))
+                .ok_or_else(|| SRDError::StatisticNotFound(StatisticId::Ability(id)))?
+                .ability()
+        }
+
+        /// Returns the creature's proficiency in the given skill.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists or if it doesn't have such skill.
+        ///
+        pub fn skill(&self, id: SkillId) -> SRDResult<&Proficiency> {
+            self.creature()?
+                .statistic(&StatisticId::Skill(id))
+                .ok_or_else(|| SRDError::StatisticNotFound(StatisticId::Skill(id)))?
+                .skill()
+        }
+
         /// Returns an iterator over the creature's abilities.
         ///
         /// # Errors
@@ -104,6 +180,158 @@ macro_rules! generate_immutable_methods {
             }))
         }
 
+        /// Returns the creature's proficiency in checks of the given ability that don't use a
+        /// skill.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists or if it doesn't have such
+        /// ability check.
+        ///
+        pub fn ability_check(&self, id: AbilityId) -> SRDResult<&Proficiency> {
+            self.creature()?
+                .statistic(&StatisticId::AbilityCheck(id))
+                .ok_or_else(|| SRDError::StatisticNotFound(StatisticId::AbilityCheck(id)))?
+                .proficiency()
+        }
+
+        /// Returns the creature's proficiency in saving throws of the given ability.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists or if it doesn't have such
+        /// saving throw.
+        ///
+        pub fn saving_throw(&self, id: AbilityId) -> SRDResult<&Proficiency> {
+            self.creature()?
+                .statistic(&StatisticId::SavingThrow(id))
+                .ok_or_else(|| SRDError::StatisticNotFound(StatisticId::SavingThrow(id)))?
+                .proficiency()
+        }
+
+        /// Returns an iterator over the armor categories the creature is proficient with.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists.
+        ///
+        pub fn armor_proficiencies(&self) -> SRDResult<impl Iterator<Item = &ArmorCategory>> {
+            Ok(self.creature()?.statistics().filter_map(|e| {
+                if let StatisticId::ArmorProficiency(category) = e.id() {
+                    Some(category)
+                } else {
+                    None
+                }
+            }))
+        }
+
+        /// Returns an iterator over the weapons the creature is proficient with.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists.
+        ///
+        pub fn weapon_proficiencies(&self) -> SRDResult<impl Iterator<Item = &WeaponProficiency>> {
+            Ok(self.creature()?.statistics().filter_map(|e| {
+                if let StatisticId::WeaponProficiency(weapon) = e.id() {
+                    Some(weapon)
+                } else {
+                    None
+                }
+            }))
+        }
+
+        /// Returns an iterator over the creature's tool proficiencies.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists.
+        ///
+        pub fn tool_proficiencies(
+            &self,
+        ) -> SRDResult<impl Iterator<Item = (&ToolId, &Proficiency)>> {
+            Ok(self.creature()?.statistics().filter_map(|e| {
+                if let StatisticId::ToolProficiency(id) = e.id() {
+                    e.proficiency().map_or(None, |prof| Some((id, prof)))
+                } else {
+                    None
+                }
+            }))
+        }
+
+        /// Returns an iterator over the creature's defenses against damage types.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists.
+        ///
+        pub fn damage_defenses(
+            &self,
+        ) -> SRDResult<impl Iterator<Item = (&DamageType, &DamageDefense)>> {
+            Ok(self.creature()?.statistics().filter_map(|e| {
+                if let StatisticId::DamageDefense(damage_type) = e.id() {
+                    e.damage_defense()
+                        .ok()
+                        .map(|defense| (damage_type, defense))
+                } else {
+                    None
+                }
+            }))
+        }
+
+        /// Returns the conditions affecting the creature.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists.
+        ///
+        pub fn conditions(&self) -> SRDResult<Conditions> {
+            Ok(Conditions::of(self.creature()?))
+        }
+
+        /// Returns the cover `target` has against attacks and effects coming from this creature.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if either creature no longer exists.
+        ///
+        pub fn cover_of(&self, target: &CreatureId<SRDRules>) -> SRDResult<Cover> {
+            let target = self
+                .controller
+                .battle()
+                .entities()
+                .creature(target)
+                .ok_or_else(|| WeaselError::CreatureNotFound(target.clone()))?;
+            Ok(self
+                .controller
+                .battle()
+                .space()
+                .model()
+                .cover(self.creature()?.position(), target.position()))
+        }
+
+        /// Returns the cover that something in `square` would have against attacks and effects
+        /// coming from this creature. Squares behind total cover can't be targeted.
+        ///
+        /// # Errors
+        ///
+        /// An error is returned if the creature no longer exists or if `square` is out of
+        /// the grid.
+        ///
+        pub fn cover_at(&self, square: Square) -> SRDResult<Cover> {
+            let grid = self.controller.battle().space().model();
+            if !grid.contains(square) {
+                return Err(SRDError::InvalidPosition(format!(
+                    "{:?} is out of the grid",
+                    square
+                )));
+            }
+            Ok(grid.cover(
+                self.creature()?.position(),
+                &Footprint::new(square, CreatureSize::Tiny),
+            ))
+        }
+
         /// Returns an iterator over the creature's actions.
         ///
         /// # Errors
@@ -146,6 +374,369 @@ where
     generate_immutable_methods! {}
 }
 
+impl<'a, C> CreatureHandleMut<'a, C>
+where
+    C: BattleController<SRDRules>
+        + EventProcessor<SRDRules, ProcessOutput = WeaselResult<(), SRDRules>>,
+{
+    /// Moves this creature along `path`, the successive origins of its footprint.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the path is invalid or the creature doesn't have enough
+    /// movement left.
+    ///
+    pub fn move_along(&mut self, path: Vec<Square>, mode: MovementMode) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Movement,
+        )
+        .activation(Activation::Movement { path, mode })
+        .fire()?;
+        Ok(())
+    }
+
+    /// Takes the Dash action, gaining extra movement for the current turn.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't take an action.
+    ///
+    pub fn dash(&mut self) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Dash,
+        )
+        .fire()?;
+        Ok(())
+    }
+
+    /// Takes the Disengage action, so that moving doesn't provoke opportunity attacks for the
+    /// rest of the turn.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't take an action.
+    ///
+    pub fn disengage(&mut self) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Disengage,
+        )
+        .fire()?;
+        Ok(())
+    }
+
+    /// Stands up from prone, spending half of this creature's speed.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature isn't prone or doesn't have enough movement left.
+    ///
+    pub fn stand_up(&mut self) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::StandUp,
+        )
+        .fire()?;
+        Ok(())
+    }
+
+    /// Attacks `target` with this creature's attack action.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't attack `target`.
+    ///
+    pub fn attack(&mut self, target: &CreatureId<SRDRules>) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Attack,
+        )
+        .activation(Activation::Attack {
+            target: target.clone(),
+        })
+        .fire()?;
+        Ok(())
+    }
+
+    /// Attacks `target` with the weapon in this creature's off hand, spending its bonus action.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature didn't take the Attack action this turn, if it isn't
+    /// holding a light melee weapon in each hand or if it can't attack `target`.
+    ///
+    pub fn off_hand_attack(&mut self, target: &CreatureId<SRDRules>) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::OffHandAttack,
+        )
+        .activation(Activation::Attack {
+            target: target.clone(),
+        })
+        .fire()?;
+        Ok(())
+    }
+
+    /// Administers first aid to the dying creature `target`, trying to stabilize it.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't stabilize `target`.
+    ///
+    pub fn stabilize(&mut self, target: &CreatureId<SRDRules>) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Stabilize,
+        )
+        .activation(Activation::Stabilize {
+            target: target.clone(),
+        })
+        .fire()?;
+        Ok(())
+    }
+
+    /// Tries to grapple `target`, replacing one of this creature's attacks.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't attack or if `target` is too large or out
+    /// of reach.
+    ///
+    pub fn grapple(&mut self, target: &CreatureId<SRDRules>) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Grapple,
+        )
+        .activation(Activation::Grapple {
+            target: target.clone(),
+        })
+        .fire()?;
+        Ok(())
+    }
+
+    /// Tries to shove `target`, either to knock it prone or to push it away, replacing one of
+    /// this creature's attacks.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't attack or if `target` is too large or out
+    /// of reach.
+    ///
+    pub fn shove(&mut self, target: &CreatureId<SRDRules>, effect: ShoveEffect) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Shove,
+        )
+        .activation(Activation::Shove {
+            target: target.clone(),
+            effect,
+        })
+        .fire()?;
+        Ok(())
+    }
+
+    /// Tries to escape the grapple this creature is subject to.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't take an action or isn't grappled.
+    ///
+    pub fn escape(&mut self) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Escape,
+        )
+        .fire()?;
+        Ok(())
+    }
+
+    /// Takes the Dodge action: attacks against this creature have disadvantage until the start
+    /// of its next turn.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't take an action.
+    ///
+    pub fn dodge(&mut self) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Dodge,
+        )
+        .fire()?;
+        Ok(())
+    }
+
+    /// Takes the Help action, giving advantage to an ally on its next attack against a target
+    /// or on its next ability check.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't take an action, if the ally doesn't exist or
+    /// if the target isn't within 5 feet of this creature.
+    ///
+    pub fn help(&mut self, help: Help) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Help,
+        )
+        .activation(Activation::Help(help))
+        .fire()?;
+        Ok(())
+    }
+
+    /// Takes the Hide action, trying to hide with a Dexterity (Stealth) check.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't take an action or if another creature can
+    /// see it clearly.
+    ///
+    pub fn hide(&mut self) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Hide,
+        )
+        .fire()?;
+        Ok(())
+    }
+
+    /// Takes the Ready action, readying an attack to be made with this creature's reaction when
+    /// `trigger` occurs before the start of its next turn.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't take an action or can't attack.
+    ///
+    pub fn ready(&mut self, trigger: ReadyTrigger) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Ready,
+        )
+        .activation(Activation::Ready { trigger })
+        .fire()?;
+        Ok(())
+    }
+
+    /// Takes the Search action with a Wisdom (Perception) or Intelligence (Investigation) check.
+    /// Perception reveals the hidden creatures this creature can see.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't take an action or if `skill` can't be used to
+    /// search.
+    ///
+    pub fn search(&mut self, skill: SkillId) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::Search,
+        )
+        .activation(Activation::Search { skill })
+        .fire()?;
+        Ok(())
+    }
+
+    /// Interacts with an object, such as drawing or stowing weapons. The first interaction of a
+    /// turn is free, any other takes an action.\
+    /// The creature must be the one currently acting.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't interact with another object or if it can't
+    /// wield the weapons.
+    ///
+    pub fn use_object(&mut self, interaction: ObjectInteraction) -> SRDResult<()> {
+        ActivateAbility::trigger(
+            &mut *self.controller,
+            EntityId::Creature(self.id.clone()),
+            ActionId::UseObject,
+        )
+        .activation(Activation::UseObject(interaction))
+        .fire()?;
+        Ok(())
+    }
+
+    /// Finishes a long rest: this creature loses its temporary hit points, reduces its
+    /// exhaustion level by 1 and then regains all lost hit points.
+    ///
+    /// # Reference
+    ///
+    /// At the end of a long rest, a character regains all lost hit points.\
+    /// A character must have at least 1 hit point at the start of the rest to gain its benefits.\
+    /// Unless a feature that grants you temporary hit points has a duration, they last until
+    /// they're depleted or you finish a long rest.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature doesn't have hit points or has 0 hit points.
+    ///
+    pub fn long_rest(&mut self) -> SRDResult<()> {
+        if self.current_hit_points()? == 0 {
+            return Err(SRDError::InvalidArgument(
+                "a creature with 0 hit points can't benefit from a long rest".to_string(),
+            ));
+        }
+        let entity = EntityId::Creature(self.id.clone());
+        AlterStatistics::trigger(
+            &mut *self.controller,
+            entity.clone(),
+            StatisticChange::RemoveTemporaryHitPoints,
+        )
+        .fire()?;
+        if self.conditions()?.get(ConditionId::Exhaustion).is_some() {
+            AlterStatuses::trigger(
+                &mut *self.controller,
+                entity.clone(),
+                ConditionsAlteration::Exhaustion(-1),
+            )
+            .fire()?;
+        }
+        let lost = self
+            .max_hit_points()?
+            .saturating_sub(self.current_hit_points()?);
+        if lost > 0 {
+            AlterStatistics::trigger(
+                &mut *self.controller,
+                entity,
+                StatisticChange::HitPoints(i16::try_from(lost).unwrap_or(i16::MAX)),
+            )
+            .fire()?;
+        }
+        Ok(())
+    }
+}
+
 impl<'a, C> From<CreatureHandleMut<'a, C>> for CreatureHandle<'a, C>
 where
     C: BattleController<SRDRules> + EventProcessor<SRDRules>,
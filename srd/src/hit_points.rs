@@ -16,6 +16,92 @@ use serde::{Deserialize, Serialize};
 /// hit point maximum down to 0. This number changes frequently as a creature takes damage or receives healing.
 pub type HitPoints = PositiveBoundedValue<u16>;
 
+/// A buffer against losing actual hit points.
+///
+/// # Reference
+///
+/// Some spells and special abilities confer temporary hit points to a creature. Temporary hit
+/// points aren't actual hit points; they are a buffer against damage, a pool of hit points that
+/// protect you from injury.\
+/// When you have temporary hit points and take damage, the temporary hit points are lost first,
+/// and any leftover damage carries over to your normal hit points.\
+/// Because temporary hit points are separate from your actual hit points, they can exceed your
+/// hit point maximum.\
+/// Temporary hit points can't be added together. If you have temporary hit points and receive
+/// more of them, you decide whether to keep the ones you have or to gain the new ones.\
+/// Unless a feature that grants you temporary hit points has a duration, they last until
+/// they're depleted or you finish a long rest.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemporaryHitPoints {
+    value: u16,
+    duration: Option<u32>,
+}
+
+impl TemporaryHitPoints {
+    /// Constructs a new `TemporaryHitPoints` lasting until depleted or until a long rest.
+    pub const fn new(value: u16) -> Self {
+        Self {
+            value,
+            duration: None,
+        }
+    }
+
+    /// Constructs a new `TemporaryHitPoints` vanishing after `turns` starts of the creature's
+    /// turn.
+    pub const fn with_duration(value: u16, turns: u32) -> Self {
+        Self {
+            value,
+            duration: Some(turns),
+        }
+    }
+
+    /// Returns the current amount of temporary hit points.
+    pub const fn value(&self) -> u16 {
+        self.value
+    }
+
+    /// Returns after how many starts of the creature's turn the temporary hit points vanish.
+    /// `None` means that they last until depleted or until a long rest.
+    pub const fn duration(&self) -> Option<u32> {
+        self.duration
+    }
+
+    /// Receives new temporary hit points, keeping the higher between the current and the new
+    /// ones together with their duration.
+    pub fn grant(&mut self, granted: Self) {
+        if granted.value > self.value {
+            *self = granted;
+        }
+    }
+
+    /// Absorbs up to `damage` points of damage and returns the leftover damage.
+    pub fn absorb(&mut self, damage: u16) -> u16 {
+        let absorbed = self.value.min(damage);
+        self.value -= absorbed;
+        if self.value == 0 {
+            self.clear();
+        }
+        damage - absorbed
+    }
+
+    /// Counts down the duration by one start of the creature's turn, removing all temporary
+    /// hit points when it ends.
+    pub fn count_down(&mut self) {
+        if let Some(duration) = self.duration {
+            if duration <= 1 {
+                self.clear();
+            } else {
+                self.duration = Some(duration - 1);
+            }
+        }
+    }
+
+    /// Removes all temporary hit points.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
 /// The dice used to generate a creature's hit points.
 ///
 /// # Reference
@@ -85,6 +171,38 @@ mod tests {
         assert_eq!(hp.add_result(3).err(), Some(SRDError::MaxLevelReached));
     }
 
+    #[test]
+    fn temporary_hit_points() {
+        let mut temporary = TemporaryHitPoints::new(5);
+        temporary.grant(TemporaryHitPoints::new(3));
+        assert_eq!(temporary.value(), 5);
+        temporary.grant(TemporaryHitPoints::new(8));
+        assert_eq!(temporary.value(), 8);
+        assert_eq!(temporary.absorb(6), 0);
+        assert_eq!(temporary.value(), 2);
+        assert_eq!(temporary.absorb(6), 4);
+        assert_eq!(temporary.value(), 0);
+        temporary.grant(TemporaryHitPoints::new(4));
+        temporary.clear();
+        assert_eq!(temporary, TemporaryHitPoints::default());
+    }
+
+    #[test]
+    fn temporary_hit_points_duration() {
+        let mut temporary = TemporaryHitPoints::with_duration(5, 2);
+        temporary.count_down();
+        assert_eq!(temporary.value(), 5);
+        assert_eq!(temporary.duration(), Some(1));
+        temporary.count_down();
+        assert_eq!(temporary, TemporaryHitPoints::default());
+        // Higher temporary hit points replace the current ones along with their duration.
+        temporary.grant(TemporaryHitPoints::with_duration(5, 1));
+        temporary.grant(TemporaryHitPoints::new(8));
+        temporary.count_down();
+        assert_eq!(temporary.value(), 8);
+        assert_eq!(temporary.duration(), None);
+    }
+
     #[test]
     fn hp_history_add_result_invalid() {
         let mut hp = HitPointsHistory::default();
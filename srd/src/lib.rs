@@ -15,6 +15,9 @@ pub use self::ability::{AbilityId, AbilityScore};
 pub mod character;
 pub use crate::character::Character;
 
+pub mod check;
+pub use crate::check::{Check, CheckKind, DifficultyClass};
+
 pub mod compendium;
 pub use crate::compendium::{
     compendium, init_srd_compendium, set_boxed_compendium, set_compendium, Compendium,
@@ -22,17 +25,22 @@ pub use crate::compendium::{
 
 pub mod constants;
 
+pub mod damage;
+pub use crate::damage::{Damage, DamageDefense, DamageType};
+
 pub mod dice;
 pub use crate::dice::{Dice, DicePool, DiceRoll, DiceRolls, Die};
 
+pub mod equipment;
+
 pub mod error;
 pub use crate::error::{SRDError, SRDResult};
 
 pub mod handle;
-pub use crate::handle::creature_handle::CreatureHandle;
+pub use crate::handle::creature_handle::{CreatureHandle, CreatureHandleMut};
 
 pub mod hit_points;
-pub use crate::hit_points::{HitDice, HitPoints};
+pub use crate::hit_points::{HitDice, HitPoints, TemporaryHitPoints};
 
 pub mod proficiency;
 pub use crate::proficiency::{Proficiency, ProficiencyBonus};
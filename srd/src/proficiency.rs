@@ -2,18 +2,75 @@
 
 use serde::{Deserialize, Serialize};
 
-/// Tells whether or not a proficiency is known.
-#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize, Deserialize)]
-pub struct Proficiency(pub bool);
+/// Tells how the proficiency bonus applies to a skill, tool or saving throw.
+///
+/// Levels are ordered from the weakest to the strongest, so that the best of multiple
+/// sources can be picked with `max`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Hash, Eq, Serialize, Deserialize)]
+pub enum Proficiency {
+    /// The proficiency bonus is not added.
+    None,
+    /// Half the proficiency bonus, rounded down, is added.
+    ///
+    /// # Reference
+    ///
+    /// Jack of All Trades: you can add half your proficiency bonus, rounded down, to any ability
+    /// check you make that doesn't already include your proficiency bonus.
+    HalfRoundedDown,
+    /// Half the proficiency bonus, rounded up, is added.
+    ///
+    /// # Reference
+    ///
+    /// Remarkable Athlete: you can add half your proficiency bonus (round up) to any Strength,
+    /// Dexterity, or Constitution check you make that doesn't already use your proficiency bonus.
+    HalfRoundedUp,
+    /// The proficiency bonus is added.
+    Proficient,
+    /// The proficiency bonus is doubled.
+    ///
+    /// # Reference
+    ///
+    /// Expertise: your proficiency bonus is doubled for any ability check you make that uses
+    /// either of the chosen proficiencies.
+    Expertise,
+}
+
+impl Proficiency {
+    /// Returns true if this level counts as being proficient.
+    pub const fn is_proficient(&self) -> bool {
+        matches!(self, Self::Proficient | Self::Expertise)
+    }
+
+    /// Returns the bonus to add to a roll, given the creature's proficiency bonus.
+    pub const fn bonus(&self, bonus: ProficiencyBonus) -> ProficiencyBonus {
+        match self {
+            Self::None => ProficiencyBonus(0),
+            Self::HalfRoundedDown => bonus.divide_rounded_down(2),
+            Self::HalfRoundedUp => bonus.divide_rounded_up(2),
+            Self::Proficient => bonus,
+            Self::Expertise => bonus.multiply(2),
+        }
+    }
+}
+
+impl Default for Proficiency {
+    fn default() -> Self {
+        DEFAULT_PROFICIENCY
+    }
+}
 
 impl From<bool> for Proficiency {
     fn from(item: bool) -> Self {
-        Self(item)
+        if item {
+            Self::Proficient
+        } else {
+            Self::None
+        }
     }
 }
 
-/// Default proficiency (value is false).
-pub(crate) static DEFAULT_PROFICIENCY: Proficiency = Proficiency(false);
+/// Default proficiency (no bonus).
+pub(crate) static DEFAULT_PROFICIENCY: Proficiency = Proficiency::None;
 
 /// Proficiency bonus.
 ///
@@ -41,7 +98,7 @@ impl ProficiencyBonus {
     /// Divides this object by `n` and returns the result (rounded up) in a new `ProficiencyBonus`.
     pub const fn divide_rounded_up(&self, n: u8) -> Self {
         let Self(value) = self;
-        Self((*value + (n - 1)) / n)
+        Self(value.div_ceil(n))
     }
 }
 
@@ -55,6 +112,22 @@ impl From<u8> for ProficiencyBonus {
 mod tests {
     use super::*;
 
+    #[test]
+    fn proficiency_levels() {
+        let bonus = ProficiencyBonus(3);
+        assert_eq!(Proficiency::None.bonus(bonus), 0.into());
+        assert_eq!(Proficiency::HalfRoundedDown.bonus(bonus), 1.into());
+        assert_eq!(Proficiency::HalfRoundedUp.bonus(bonus), 2.into());
+        assert_eq!(Proficiency::Proficient.bonus(bonus), 3.into());
+        assert_eq!(Proficiency::Expertise.bonus(bonus), 6.into());
+        assert_eq!(Proficiency::from(true), Proficiency::Proficient);
+        assert_eq!(
+            std::cmp::max(Proficiency::HalfRoundedDown, Proficiency::Expertise),
+            Proficiency::Expertise
+        );
+        assert!(!Proficiency::HalfRoundedUp.is_proficient());
+    }
+
     #[test]
     fn bonus_multiply() {
         let bonus = ProficiencyBonus(3);
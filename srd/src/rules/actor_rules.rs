@@ -1,29 +1,65 @@
 //! Implementation of rules for actors.
 
-use crate::rules::core::action::{Action, ActionsSeed};
-use crate::rules::narrator::Narrator;
+use crate::character::CharacterId;
+use crate::character::FightingStyle;
+use crate::check::{contest, Check, ContestWinner};
+use crate::compendium::compendium;
+use crate::equipment::{Weapon, WeaponId, WeaponKind, WeaponProficiency, WeaponSlots};
+use crate::error::SRDError;
+use crate::hit_points::TemporaryHitPoints;
+use crate::rules::core::action::{
+    Action, ActionId, ActionInitializer, ActionsAlteration, ActionsSeed, Activation,
+    ObjectInteraction,
+};
+use crate::rules::core::attack::{
+    resolve_attack, AttackCircumstances, AttackOutcome, AttackProfile, AttackReach,
+};
+use crate::rules::core::battlegrid::{can_move_through_hostile, Footprint};
+use crate::rules::core::condition::{ConditionId, ConditionPotency, Conditions};
+use crate::rules::core::constants::DEFAULT_REACH_FT;
+use crate::rules::core::cover::Cover;
+use crate::rules::core::death::{DeathSavingThrowResult, LifeState, STABILIZE_DC};
+use crate::rules::core::economy::{ActionCost, TurnBudget};
+use crate::rules::core::grapple::{
+    drags_at_half_speed, grapple_check, resisting_check, within_size_limit, ShoveEffect,
+    SHOVE_DISTANCE_FT,
+};
+use crate::rules::core::movement::{ft_to_squares, squares_to_ft, MovementMode, Speeds};
+use crate::rules::core::size::CreatureSize;
+use crate::rules::core::statistic::{StatisticChange, StatisticId};
+use crate::rules::core::tactics::{
+    is_hidden, is_search_skill, tactics, Help, ReadyTrigger, Tactics, HELP_DISTANCE_FT,
+};
+use crate::rules::fight_rules::damage_against;
+use crate::rules::narrator::{Episode, Narrator};
+use crate::rules::reaction::{ReactionOpportunity, Reactor};
 use crate::rules::SRDRules;
+use crate::skill::{SkillId, MEDICINE, PERCEPTION, STEALTH};
 use std::sync::Arc;
-use weasel::{Actor, ActorRules, BattleState, Entropy, EventQueue, WeaselResult, WriteMetrics};
+use weasel::{
+    Actor, ActorRules, AlterAbilities, AlterStatistics, ApplyImpact, BattleState, Character,
+    ClearStatus, Creature, Entities, Entity, EntityId, Entropy, EventQueue, EventTrigger, Id,
+    InflictStatus, MoveEntity, Relation, WeaselError, WeaselResult, WriteMetrics,
+};
 
 /// Rules to manage abilities that can be activated and any action a character can take.
 pub struct SRDActorRules {
-    #[allow(dead_code)] // TODO remove
     narrator: Arc<dyn Narrator>,
+    reactor: Arc<dyn Reactor>,
 }
 
 impl SRDActorRules {
     /// Creates a new instance.
-    pub(crate) fn new(narrator: Arc<dyn Narrator>) -> Self {
-        Self { narrator }
+    pub(crate) fn new(narrator: Arc<dyn Narrator>, reactor: Arc<dyn Reactor>) -> Self {
+        Self { narrator, reactor }
     }
 }
 
 impl ActorRules<SRDRules> for SRDActorRules {
     type Ability = Action;
     type AbilitiesSeed = ActionsSeed;
-    type Activation = (); // TODO use a real type
-    type AbilitiesAlteration = (); // TODO use a real type
+    type Activation = Activation;
+    type AbilitiesAlteration = ActionsAlteration;
 
     fn generate_abilities(
         &self,
@@ -43,30 +79,3035 @@ impl ActorRules<SRDRules> for SRDActorRules {
 
     fn activable(
         &self,
-        _state: &BattleState<SRDRules>,
-        _action: weasel::Action<SRDRules>,
+        state: &BattleState<SRDRules>,
+        action: weasel::Action<SRDRules>,
     ) -> WeaselResult<(), SRDRules> {
-        unimplemented!()
+        // Unconscious creatures can't take actions.
+        let actor = creature(state, &action.actor.entity_id().creature()?)?;
+        if !is_conscious(actor) {
+            return Err(WeaselError::UserError(format!(
+                "{:?} is not conscious",
+                actor.id()
+            )));
+        }
+        // Incapacitated creatures can't take actions, although they may still move.
+        if action.ability.cost() != ActionCost::Movement && Conditions::of(actor).is_incapacitated()
+        {
+            return Err(WeaselError::UserError(format!(
+                "{:?} is incapacitated",
+                actor.id()
+            )));
+        }
+        // The creature must be able to pay for the action. Attacks left from an earlier Attack
+        // action are free, and grapples and shoves replace one of them.
+        if let Some(budget) = turn_budget(actor) {
+            let affordable = match action.ability.id() {
+                ActionId::Attack | ActionId::Grapple | ActionId::Shove => budget.can_attack(),
+                _ => budget.can_afford(action.ability.cost()),
+            };
+            if !affordable {
+                return Err(WeaselError::UserError(
+                    SRDError::CostNotAffordable(action.ability.cost()).to_string(),
+                ));
+            }
+        }
+        match action.ability.id() {
+            ActionId::Movement => {
+                movement_path(state, &action)?;
+                Ok(())
+            }
+            ActionId::Attack => {
+                attack_target(state, &action)?;
+                Ok(())
+            }
+            ActionId::OffHandAttack => {
+                two_weapon_fighting(actor)?;
+                attack_target(state, &action)?;
+                Ok(())
+            }
+            ActionId::Stabilize => {
+                stabilize_target(state, &action)?;
+                Ok(())
+            }
+            ActionId::StandUp => {
+                stand_up_cost(actor)?;
+                Ok(())
+            }
+            ActionId::Grapple | ActionId::Shove => {
+                grapple_target(state, &action)?;
+                Ok(())
+            }
+            ActionId::Escape => {
+                grappler(state, actor)?;
+                Ok(())
+            }
+            ActionId::Help => {
+                helped_ally(state, &action)?;
+                Ok(())
+            }
+            ActionId::Hide => hiding_spot(state, actor),
+            ActionId::Ready => {
+                ready_trigger(state, &action)?;
+                Ok(())
+            }
+            ActionId::Search => {
+                search_skill(&action)?;
+                Ok(())
+            }
+            ActionId::UseObject => {
+                wielded_weapons(actor, &action)?;
+                Ok(())
+            }
+            ActionId::Dash | ActionId::Disengage | ActionId::Dodge => Ok(()),
+        }
     }
 
     fn activate(
         &self,
-        _state: &BattleState<SRDRules>,
-        _action: weasel::Action<SRDRules>,
-        _event_queue: &mut Option<EventQueue<SRDRules>>,
-        _entropy: &mut Entropy<SRDRules>,
+        state: &BattleState<SRDRules>,
+        action: weasel::Action<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
         _metrics: &mut WriteMetrics<SRDRules>,
     ) {
-        unimplemented!()
+        let spending = match action.ability.id() {
+            ActionId::Attack | ActionId::Grapple | ActionId::Shove => {
+                StatisticChange::SpendAttack(attacks_per_action(state, &action))
+            }
+            _ => StatisticChange::SpendBudget(action.ability.cost()),
+        };
+        AlterStatistics::trigger(event_queue, action.actor.entity_id().clone(), spending).fire();
+        match action.ability.id() {
+            ActionId::Movement => self.movement(state, &action, event_queue, entropy),
+            ActionId::Attack | ActionId::OffHandAttack => {
+                self.attack(state, &action, event_queue, entropy)
+            }
+            ActionId::Stabilize => self.stabilize(state, &action, event_queue, entropy),
+            ActionId::Dash => {
+                AlterStatistics::trigger(
+                    event_queue,
+                    action.actor.entity_id().clone(),
+                    StatisticChange::GrantBudget(ActionCost::Movement),
+                )
+                .fire();
+            }
+            ActionId::Disengage => {
+                AlterStatistics::trigger(
+                    event_queue,
+                    action.actor.entity_id().clone(),
+                    StatisticChange::Disengage,
+                )
+                .fire();
+            }
+            ActionId::StandUp => stand_up(state, &action, event_queue),
+            ActionId::Grapple | ActionId::Shove => {
+                self.grapple(state, &action, event_queue, entropy)
+            }
+            ActionId::Escape => self.escape(state, &action, event_queue, entropy),
+            ActionId::Dodge => {
+                AlterStatistics::trigger(
+                    event_queue,
+                    action.actor.entity_id().clone(),
+                    StatisticChange::Dodge,
+                )
+                .fire();
+            }
+            ActionId::Help => match helped_ally(state, &action) {
+                Ok(help) => AlterStatistics::trigger(
+                    event_queue,
+                    action.actor.entity_id().clone(),
+                    StatisticChange::Help(help),
+                )
+                .fire(),
+                Err(err) => log::error!("failed to help a creature: {}", err),
+            },
+            ActionId::Hide => self.hide(state, &action, event_queue, entropy),
+            ActionId::Ready => match ready_trigger(state, &action) {
+                Ok(trigger) => AlterStatistics::trigger(
+                    event_queue,
+                    action.actor.entity_id().clone(),
+                    StatisticChange::Ready(trigger),
+                )
+                .fire(),
+                Err(err) => log::error!("failed to ready an attack: {}", err),
+            },
+            ActionId::Search => self.search(state, &action, event_queue, entropy),
+            ActionId::UseObject => use_object(state, &action, event_queue),
+        }
+    }
+
+    fn on_turn_start(
+        &self,
+        state: &BattleState<SRDRules>,
+        actor: &dyn Actor<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
+        _metrics: &mut WriteMetrics<SRDRules>,
+    ) {
+        let id = match actor.entity_id().creature() {
+            Ok(id) => id,
+            Err(_) => return,
+        };
+        let creature = match state.entities().creature(&id) {
+            Some(creature) => creature,
+            None => return,
+        };
+        // Creatures regain their actions, movement and reaction.
+        if turn_budget(creature).is_some() {
+            AlterStatistics::trigger(
+                event_queue,
+                EntityId::Creature(id.clone()),
+                StatisticChange::RefreshBudget,
+            )
+            .fire();
+        }
+        // Dodging, help and readied attacks last until the start of the creature's next turn.
+        if tactics(creature).is_some() {
+            AlterStatistics::trigger(
+                event_queue,
+                EntityId::Creature(id.clone()),
+                StatisticChange::RefreshTactics,
+            )
+            .fire();
+        }
+        // Temporary hit points with a duration vanish once it ends.
+        if temporary_hit_points(creature).is_some_and(|temporary| temporary.duration().is_some()) {
+            AlterStatistics::trigger(
+                event_queue,
+                EntityId::Creature(id.clone()),
+                StatisticChange::CountDownTemporaryHitPoints,
+            )
+            .fire();
+        }
+        // Dying creatures make a death saving throw at the start of each of their turns.
+        if life_state(creature).is_some_and(|life_state| life_state.is_dying()) {
+            let result = DeathSavingThrowResult::roll(id.clone(), entropy);
+            self.narrator.episode(&Episode::DeathSavingThrow(&result));
+            AlterStatistics::trigger(
+                event_queue,
+                EntityId::Creature(id),
+                StatisticChange::DeathSavingThrow(result.roll.natural()),
+            )
+            .fire();
+        }
     }
 
     fn alter_abilities(
         &self,
-        _actor: &mut dyn Actor<SRDRules>,
-        _alteration: &Self::AbilitiesAlteration,
+        actor: &mut dyn Actor<SRDRules>,
+        alteration: &Self::AbilitiesAlteration,
         _entropy: &mut Entropy<SRDRules>,
         _metrics: &mut WriteMetrics<SRDRules>,
     ) {
-        unimplemented!()
+        match alteration {
+            ActionsAlteration::Add(initializer) => {
+                actor.add_ability(initializer.clone().into());
+            }
+            ActionsAlteration::Remove(id) => {
+                actor.remove_ability(id);
+            }
+        }
+    }
+}
+
+/// An attack made with a reaction while another creature moves.
+struct ReactionAttack<'a> {
+    opportunity: ReactionOpportunity,
+    attacker: &'a Creature<SRDRules>,
+    profile: &'a AttackProfile,
+    helper: Option<&'a Creature<SRDRules>>,
+    outcome: AttackOutcome,
+}
+
+impl SRDActorRules {
+    /// Moves a creature along a path, spending its movement. Leaving the reach of other
+    /// creatures offers them an opportunity attack. Creatures grappled by the mover are dragged
+    /// along.
+    ///
+    /// # Reference
+    ///
+    /// You can break up your movement on your turn, using some of your speed before and after
+    /// your action.
+    fn movement(
+        &self,
+        state: &BattleState<SRDRules>,
+        action: &weasel::Action<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
+    ) {
+        let result = movement_path(state, action).and_then(|(mode, steps)| {
+            let mover = creature(state, &action.actor.entity_id().creature()?)?;
+            let (taken, reactions) = self.reactions(state, mover, &steps, entropy);
+            let extra_cost =
+                turn_budget(mover).map_or(0, |budget| budget.speeds().extra_cost(mode));
+            let path: Vec<_> = steps[..taken].iter().map(|step| step.origin).collect();
+            let cost = if path.is_empty() {
+                0
+            } else {
+                state
+                    .space()
+                    .model()
+                    .path_cost(
+                        mover.id(),
+                        &path,
+                        extra_cost,
+                        &blocking_creatures(state, mover),
+                    )
+                    .map_err(|err| WeaselError::UserError(err.to_string()))?
+                    .saturating_mul(drag_factor(state, mover))
+            };
+            let position = steps[..taken].last().copied();
+            let dragged: Vec<_> = position
+                .map(|position| dragged_positions(state, mover, &position))
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(creature, position)| (EntityId::Creature(creature.id().clone()), position))
+                .collect();
+            Ok((mode, cost, position, dragged, reactions))
+        });
+        match result {
+            Ok((mode, cost, position, dragged, reactions)) => {
+                if let Some(position) = position {
+                    AlterStatistics::trigger(
+                        event_queue,
+                        action.actor.entity_id().clone(),
+                        StatisticChange::SpendMovement(mode, cost),
+                    )
+                    .fire();
+                    MoveEntity::trigger(event_queue, action.actor.entity_id().clone(), position)
+                        .fire();
+                }
+                for (id, position) in dragged {
+                    match position {
+                        Some(position) => MoveEntity::trigger(event_queue, id, position).fire(),
+                        // A creature left behind is no longer within the grappler's reach.
+                        None => ClearStatus::trigger(event_queue, id, ConditionId::Grappled).fire(),
+                    }
+                }
+                for reaction in reactions {
+                    let reactor = EntityId::Creature(reaction.attacker.id().clone());
+                    AlterStatistics::trigger(
+                        event_queue,
+                        reactor.clone(),
+                        StatisticChange::SpendBudget(ActionCost::Reaction),
+                    )
+                    .fire();
+                    if let ReactionOpportunity::ReadiedAttack { .. } = reaction.opportunity {
+                        AlterStatistics::trigger(event_queue, reactor, StatisticChange::EndReady)
+                            .fire();
+                    }
+                    attack_aftermath(
+                        reaction.attacker,
+                        reaction.profile,
+                        reaction.helper,
+                        reaction.outcome,
+                        event_queue,
+                    );
+                }
+            }
+            Err(err) => log::error!("failed to move a creature: {}", err),
+        }
+    }
+
+    /// Resolves the attacks that other creatures make with their reaction while `mover` moves
+    /// along `steps`: opportunity attacks and readied attacks.\
+    /// Returns how many steps the mover takes, since a creature dropped to 0 hit points stops
+    /// moving, and the attacks.
+    fn reactions<'a>(
+        &self,
+        state: &'a BattleState<SRDRules>,
+        mover: &Creature<SRDRules>,
+        steps: &[Footprint],
+        entropy: &mut Entropy<SRDRules>,
+    ) -> (usize, Vec<ReactionAttack<'a>>) {
+        let mut taken = steps.len();
+        let mut attacks: Vec<ReactionAttack> = Vec::new();
+        let mut hit_points = remaining_hit_points(mover);
+        let grid = state.space().model();
+        for (step, reactor, opportunity) in provoked_reactors(state, mover, steps) {
+            // A creature can take only one reaction.
+            if attacks
+                .iter()
+                .any(|attack| attack.attacker.id() == reactor.id())
+                || !self.reactor.react(state, &opportunity)
+            {
+                continue;
+            }
+            let profile = match attack_profile(reactor) {
+                Some(profile) => profile,
+                None => continue,
+            };
+            // Opportunity attacks occur right before the mover leaves the reach, readied attacks
+            // right after it enters the reach.
+            let (position, reach, stop) = match opportunity {
+                ReactionOpportunity::OpportunityAttack { .. } => {
+                    let position = step
+                        .checked_sub(1)
+                        .map_or(mover.position(), |previous| &steps[previous]);
+                    (position, Some(AttackReach::Melee), step)
+                }
+                ReactionOpportunity::ReadiedAttack { .. } => {
+                    let distance = squares_to_ft(reactor.position().distance_sq(&steps[step]));
+                    (&steps[step], profile.reach(distance), step + 1)
+                }
+            };
+            let reach = match reach {
+                Some(reach) => reach,
+                None => continue,
+            };
+            // Reactions can't target creatures behind total cover.
+            let cover = grid.cover(reactor.position(), position);
+            if !cover.allows_targeting() || (profile.ammunition && !has_ammunition(reactor)) {
+                continue;
+            }
+            let helper = helper(state, reactor.id(), Some(mover.id()));
+            let circumstances = AttackCircumstances {
+                reach,
+                threatened: is_threatened(state.entities(), reactor),
+                cover,
+                helped: helper.is_some(),
+            };
+            let outcome = match resolve_attack(reactor, mover, profile, circumstances, entropy) {
+                Ok(outcome) => outcome,
+                Err(err) => {
+                    log::error!("failed to resolve a reaction attack: {}", err);
+                    continue;
+                }
+            };
+            self.narrator.episode(&Episode::Attack(&outcome));
+            if let Some(impact) = &outcome.impact {
+                hit_points = hit_points.saturating_sub(damage_against(mover, impact));
+            }
+            attacks.push(ReactionAttack {
+                opportunity,
+                attacker: reactor,
+                profile,
+                helper,
+                outcome,
+            });
+            if hit_points == 0 {
+                taken = stop;
+                break;
+            }
+        }
+        // The creature can't end its movement in the space of another creature.
+        while taken > 0 && grid.check(mover.id(), &steps[taken - 1]).is_err() {
+            taken -= 1;
+        }
+        (taken, attacks)
+    }
+
+    fn attack(
+        &self,
+        state: &BattleState<SRDRules>,
+        action: &weasel::Action<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
+    ) {
+        let result = attack_target(state, action).and_then(|(target, reach, cover)| {
+            let attacker = creature(state, &action.actor.entity_id().creature()?)?;
+            let profile = action
+                .ability
+                .attack()
+                .map_err(|err| WeaselError::UserError(format!("invalid attack action: {}", err)))?;
+            let helper = helper(state, attacker.id(), Some(target.id()));
+            let circumstances = AttackCircumstances {
+                reach,
+                threatened: is_threatened(state.entities(), attacker),
+                cover,
+                helped: helper.is_some(),
+            };
+            let outcome = resolve_attack(attacker, target, profile, circumstances, entropy)
+                .map_err(|err| WeaselError::UserError(err.to_string()))?;
+            Ok((attacker, profile, helper, outcome))
+        });
+        match result {
+            Ok((attacker, profile, helper, outcome)) => {
+                self.narrator.episode(&Episode::Attack(&outcome));
+                attack_aftermath(attacker, profile, helper, outcome, event_queue);
+            }
+            Err(err) => log::error!("failed to resolve an attack: {}", err),
+        }
+    }
+
+    /// Attempts to stabilize a dying creature with a Wisdom (Medicine) check.
+    ///
+    /// # Reference
+    ///
+    /// You can use your action to administer first aid to an unconscious creature and attempt to
+    /// stabilize it, which requires a successful DC 10 Wisdom (Medicine) check.
+    fn stabilize(
+        &self,
+        state: &BattleState<SRDRules>,
+        action: &weasel::Action<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
+    ) {
+        let result = stabilize_target(state, action).and_then(|target| {
+            let healer = creature(state, &action.actor.entity_id().creature()?)?;
+            let outcome = Check::skill(MEDICINE)
+                .against(healer, STABILIZE_DC, entropy)
+                .map_err(|err| WeaselError::UserError(err.to_string()))?;
+            Ok((target.id().clone(), outcome))
+        });
+        match result {
+            Ok((target, outcome)) => {
+                self.narrator.episode(&Episode::CheckAgainstDC(&outcome));
+                if outcome.success {
+                    AlterStatistics::trigger(
+                        event_queue,
+                        EntityId::Creature(target),
+                        StatisticChange::Stabilize,
+                    )
+                    .fire();
+                }
+            }
+            Err(err) => log::error!("failed to stabilize a creature: {}", err),
+        }
+    }
+
+    /// Grapples or shoves a creature with a Strength (Athletics) check contested by the target's
+    /// Strength (Athletics) or Dexterity (Acrobatics) check.
+    ///
+    /// # Reference
+    ///
+    /// When you want to grab a creature or wrestle with it, you can use the Attack action to make
+    /// a special melee attack, a grapple. If you're able to make multiple attacks with the Attack
+    /// action, this attack replaces one of them.\
+    /// If you succeed, you subject the target to the grappled condition.
+    fn grapple(
+        &self,
+        state: &BattleState<SRDRules>,
+        action: &weasel::Action<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
+    ) {
+        let result = grapple_target(state, action).and_then(|(target, effect)| {
+            let attacker = creature(state, &action.actor.entity_id().creature()?)?;
+            let resisting =
+                resisting_check(target).map_err(|err| WeaselError::UserError(err.to_string()))?;
+            let (check, helper) = helped_check(state, attacker, grapple_check());
+            let (resisting, resisting_helper) = helped_check(state, target, resisting);
+            let outcome = contest((attacker, &check), (target, &resisting), entropy)
+                .map_err(|err| WeaselError::UserError(err.to_string()))?;
+            Ok((
+                attacker,
+                target,
+                effect,
+                outcome,
+                [helper, resisting_helper],
+            ))
+        });
+        match result {
+            Ok((attacker, target, effect, outcome, helpers)) => {
+                self.narrator.episode(&Episode::Contest(&outcome));
+                for helper in helpers {
+                    end_help(helper, event_queue);
+                }
+                if outcome.winner() != Some(ContestWinner::First) {
+                    return;
+                }
+                let id = EntityId::Creature(target.id().clone());
+                match effect {
+                    None => InflictStatus::trigger(event_queue, id, ConditionId::Grappled)
+                        .potency(ConditionPotency {
+                            source: Some(attacker.id().clone()),
+                            ..ConditionPotency::default()
+                        })
+                        .fire(),
+                    Some(ShoveEffect::KnockProne) => {
+                        InflictStatus::trigger(event_queue, id, ConditionId::Prone).fire()
+                    }
+                    Some(ShoveEffect::Push) => push(state, attacker, target, event_queue),
+                }
+            }
+            Err(err) => log::error!("failed to grapple or shove a creature: {}", err),
+        }
+    }
+
+    /// Attempts to escape a grapple with a Strength (Athletics) or Dexterity (Acrobatics) check
+    /// contested by the grappler's Strength (Athletics) check.
+    ///
+    /// # Reference
+    ///
+    /// A grappled creature can use its action to escape. To do so, it must succeed on a Strength
+    /// (Athletics) or Dexterity (Acrobatics) check contested by your Strength (Athletics) check.
+    fn escape(
+        &self,
+        state: &BattleState<SRDRules>,
+        action: &weasel::Action<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
+    ) {
+        let result = action.actor.entity_id().creature().and_then(|id| {
+            let escaping = creature(state, &id)?;
+            let grappler = grappler(state, escaping)?;
+            let check =
+                resisting_check(escaping).map_err(|err| WeaselError::UserError(err.to_string()))?;
+            let (check, helper) = helped_check(state, escaping, check);
+            let (grappling, grappler_helper) = helped_check(state, grappler, grapple_check());
+            let outcome = contest((escaping, &check), (grappler, &grappling), entropy)
+                .map_err(|err| WeaselError::UserError(err.to_string()))?;
+            Ok((outcome, [helper, grappler_helper]))
+        });
+        match result {
+            Ok((outcome, helpers)) => {
+                self.narrator.episode(&Episode::Contest(&outcome));
+                for helper in helpers {
+                    end_help(helper, event_queue);
+                }
+                if outcome.winner() == Some(ContestWinner::First) {
+                    ClearStatus::trigger(
+                        event_queue,
+                        action.actor.entity_id().clone(),
+                        ConditionId::Grappled,
+                    )
+                    .fire();
+                }
+            }
+            Err(err) => log::error!("failed to escape a grapple: {}", err),
+        }
+    }
+
+    /// Hides a creature with a Dexterity (Stealth) check. The creature stays hidden if the
+    /// check's total beats the passive Wisdom (Perception) of every other creature, otherwise it's
+    /// noticed at once.
+    ///
+    /// # Reference
+    ///
+    /// When you take the Hide action, you make a Dexterity (Stealth) check in an attempt to hide.
+    /// If you succeed, you gain certain benefits.\
+    /// When you hide, there's a chance someone will notice you even if they aren't searching. To
+    /// determine whether such a creature notices you, the GM compares your Dexterity (Stealth)
+    /// check with that creature's passive Wisdom (Perception) score.
+    fn hide(
+        &self,
+        state: &BattleState<SRDRules>,
+        action: &weasel::Action<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
+    ) {
+        let result = action.actor.entity_id().creature().and_then(|id| {
+            let hider = creature(state, &id)?;
+            let (check, helper) = helped_check(state, hider, Check::skill(STEALTH));
+            let result = check
+                .roll(hider, entropy)
+                .map_err(|err| WeaselError::UserError(err.to_string()))?;
+            let mut noticed = false;
+            for observer in observers(state, hider) {
+                let passive = Check::skill(PERCEPTION)
+                    .passive(observer)
+                    .map_err(|err| WeaselError::UserError(err.to_string()))?;
+                noticed |= passive >= result.total();
+            }
+            Ok((result, helper, noticed))
+        });
+        match result {
+            Ok((result, helper, noticed)) => {
+                self.narrator.episode(&Episode::Check(&result));
+                end_help(helper, event_queue);
+                let change = if noticed {
+                    StatisticChange::Reveal
+                } else {
+                    StatisticChange::Hide(result.total())
+                };
+                AlterStatistics::trigger(event_queue, action.actor.entity_id().clone(), change)
+                    .fire();
+            }
+            Err(err) => log::error!("failed to hide: {}", err),
+        }
+    }
+
+    /// Searches with a Wisdom (Perception) or Intelligence (Investigation) check. A Perception
+    /// check finds every hidden creature in view whose Dexterity (Stealth) total doesn't beat it.
+    ///
+    /// # Reference
+    ///
+    /// When you take the Search action, you devote your attention to finding something.\
+    /// Until you are discovered or you stop hiding, that check's total is contested by the Wisdom
+    /// (Perception) check of any creature that actively searches for signs of your presence.
+    fn search(
+        &self,
+        state: &BattleState<SRDRules>,
+        action: &weasel::Action<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        entropy: &mut Entropy<SRDRules>,
+    ) {
+        let result = search_skill(action).and_then(|skill| {
+            let searcher = creature(state, &action.actor.entity_id().creature()?)?;
+            let (check, helper) = helped_check(state, searcher, Check::skill(skill));
+            let result = check
+                .roll(searcher, entropy)
+                .map_err(|err| WeaselError::UserError(err.to_string()))?;
+            let grid = state.space().model();
+            let found: Vec<_> = state
+                .entities()
+                .creatures()
+                .filter(|_| skill == PERCEPTION)
+                .filter(|hidden| {
+                    tactics(*hidden)
+                        .and_then(Tactics::stealth)
+                        .is_some_and(|stealth| stealth <= result.total())
+                })
+                .filter(|hidden| {
+                    hidden.id() != searcher.id()
+                        && grid
+                            .cover(searcher.position(), hidden.position())
+                            .allows_targeting()
+                })
+                .map(|hidden| EntityId::Creature(hidden.id().clone()))
+                .collect();
+            Ok((result, helper, found))
+        });
+        match result {
+            Ok((result, helper, found)) => {
+                self.narrator.episode(&Episode::Check(&result));
+                end_help(helper, event_queue);
+                for id in found {
+                    AlterStatistics::trigger(event_queue, id, StatisticChange::Reveal).fire();
+                }
+            }
+            Err(err) => log::error!("failed to search: {}", err),
+        }
+    }
+}
+
+/// Pushes `target` 5 feet away from `attacker`, or less if a wall, another creature or the edge
+/// of the grid is in the way. The grapple on `target` ends if it's pushed out of the grappler's
+/// reach.
+fn push(
+    state: &BattleState<SRDRules>,
+    attacker: &Creature<SRDRules>,
+    target: &Creature<SRDRules>,
+    event_queue: &mut Option<EventQueue<SRDRules>>,
+) {
+    let grid = state.space().model();
+    let (dx, dy) = target.position().direction_from(attacker.position());
+    let destination = (1..=i32::from(ft_to_squares(SHOVE_DISTANCE_FT)))
+        .map(|squares| target.position().shifted(dx * squares, dy * squares))
+        .take_while(|footprint| {
+            footprint.is_some_and(|footprint| grid.check(target.id(), &footprint).is_ok())
+        })
+        .last()
+        .flatten();
+    let destination = match destination {
+        Some(destination) => destination,
+        None => return,
+    };
+    let id = EntityId::Creature(target.id().clone());
+    MoveEntity::trigger(event_queue, id.clone(), destination).fire();
+    if grappler(state, target).is_ok_and(|grappler| {
+        squares_to_ft(grappler.position().distance_sq(&destination)) > melee_reach(grappler)
+    }) {
+        ClearStatus::trigger(event_queue, id, ConditionId::Grappled).fire();
+    }
+}
+
+/// Stands up a prone creature, spending half of its speed.
+///
+/// # Reference
+///
+/// Standing up takes more effort; doing so costs an amount of movement equal to half your speed.
+fn stand_up(
+    state: &BattleState<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+    event_queue: &mut Option<EventQueue<SRDRules>>,
+) {
+    let result = action
+        .actor
+        .entity_id()
+        .creature()
+        .and_then(|id| stand_up_cost(creature(state, &id)?));
+    match result {
+        Ok(cost) => {
+            AlterStatistics::trigger(
+                event_queue,
+                action.actor.entity_id().clone(),
+                StatisticChange::SpendMovement(MovementMode::Walk, cost),
+            )
+            .fire();
+            ClearStatus::trigger(
+                event_queue,
+                action.actor.entity_id().clone(),
+                ConditionId::Prone,
+            )
+            .fire();
+        }
+        Err(err) => log::error!("failed to stand up: {}", err),
+    }
+}
+
+/// Returns the creature with the given id.
+fn creature<'a>(
+    state: &'a BattleState<SRDRules>,
+    id: &CharacterId,
+) -> WeaselResult<&'a Creature<SRDRules>, SRDRules> {
+    state
+        .entities()
+        .creature(id)
+        .ok_or_else(|| WeaselError::CreatureNotFound(id.clone()))
+}
+
+/// Returns the life state of a creature, if it has one.
+fn life_state(creature: &Creature<SRDRules>) -> Option<LifeState> {
+    creature
+        .statistic(&StatisticId::LifeState)
+        .and_then(|statistic| statistic.life_state().ok().copied())
+}
+
+/// Returns the turn budget of a creature, if it has one.
+fn turn_budget(creature: &Creature<SRDRules>) -> Option<TurnBudget> {
+    creature
+        .statistic(&StatisticId::TurnBudget)
+        .and_then(|statistic| statistic.turn_budget().ok().copied())
+}
+
+/// Returns the turn budget of a creature, with its speeds reduced by its conditions.
+fn conditioned_turn_budget(creature: &Creature<SRDRules>) -> TurnBudget {
+    let budget = turn_budget(creature).unwrap_or_else(|| TurnBudget::new(Speeds::default()));
+    budget.with_speeds(Conditions::of(creature).speeds(budget.speeds()))
+}
+
+/// Returns the movement, in squares, that a prone creature spends to stand up.
+fn stand_up_cost(creature: &Creature<SRDRules>) -> WeaselResult<u16, SRDRules> {
+    if !Conditions::of(creature).has(ConditionId::Prone) {
+        return Err(WeaselError::UserError(format!(
+            "{:?} is not prone",
+            creature.id()
+        )));
+    }
+    let budget = conditioned_turn_budget(creature);
+    let cost = ft_to_squares(budget.speed()) / 2;
+    // You can't stand up if you don't have enough movement left or if your speed is 0.
+    if budget.speed() == 0 || cost > budget.movement_sq(MovementMode::Walk) {
+        return Err(WeaselError::UserError(
+            SRDError::CostNotAffordable(ActionCost::Movement).to_string(),
+        ));
+    }
+    Ok(cost)
+}
+
+/// Returns true if the creature is conscious. Creatures without a life state always are.
+fn is_conscious(creature: &Creature<SRDRules>) -> bool {
+    life_state(creature).is_none_or(|state| state.is_conscious())
+}
+
+/// Returns true if the creature is surprised and its first turn hasn't passed yet.
+///
+/// # Reference
+///
+/// If you're surprised, you can't move or take an action on your first turn of the combat, and
+/// you can't take a reaction until that turn ends.
+fn is_surprised(state: &BattleState<SRDRules>, creature: &Creature<SRDRules>) -> bool {
+    state
+        .rounds()
+        .model()
+        .initiative(creature.id())
+        .is_some_and(|initiative| initiative.surprised)
+}
+
+/// Returns the movement mode and the successive positions of a movement action.
+fn movement_path(
+    state: &BattleState<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+) -> WeaselResult<(MovementMode, Vec<Footprint>), SRDRules> {
+    let (path, mode) = match action.activation {
+        Some(Activation::Movement { path, mode }) => (path, *mode),
+        _ => return Err(WeaselError::UserError("moving requires a path".to_string())),
+    };
+    let id = action.actor.entity_id().creature()?;
+    let actor = creature(state, &id)?;
+    let conditions = Conditions::of(actor);
+    // A prone creature can only crawl, unless it stands up first.
+    if conditions.has(ConditionId::Prone) && mode != MovementMode::Crawl {
+        return Err(WeaselError::UserError(format!(
+            "{:?} is prone and can only crawl",
+            id
+        )));
+    }
+    let budget = conditioned_turn_budget(actor);
+    if budget.speeds().speed(mode) == 0 {
+        return Err(WeaselError::UserError(format!(
+            "{:?} can't move with {:?}",
+            id, mode
+        )));
+    }
+    let cost = state
+        .space()
+        .model()
+        .path_cost(
+            &id,
+            path,
+            budget.speeds().extra_cost(mode),
+            &blocking_creatures(state, actor),
+        )
+        .map_err(|err| WeaselError::UserError(err.to_string()))?
+        .saturating_mul(drag_factor(state, actor));
+    if cost > budget.movement_sq(mode) {
+        return Err(WeaselError::UserError(
+            SRDError::CostNotAffordable(ActionCost::Movement).to_string(),
+        ));
+    }
+    let steps = path
+        .iter()
+        .map(|origin| Footprint {
+            origin: *origin,
+            ..*actor.position()
+        })
+        .collect::<Vec<_>>();
+    // Grappled creatures must be able to follow the mover.
+    if let Some(end) = steps.last() {
+        for (dragged, position) in dragged_positions(state, actor, end) {
+            if position.is_none() {
+                return Err(WeaselError::UserError(format!(
+                    "{:?} can't drag {:?} there",
+                    id,
+                    dragged.id()
+                )));
+            }
+        }
+    }
+    // A frightened creature can't willingly move closer to the source of its fear.
+    let fear = conditions
+        .get(ConditionId::Frightened)
+        .and_then(|condition| condition.source())
+        .and_then(|source| state.entities().creature(source));
+    if let Some(fear) = fear {
+        let mut distance = fear.position().distance_sq(actor.position());
+        for step in &steps {
+            let next = fear.position().distance_sq(step);
+            if next < distance {
+                return Err(WeaselError::UserError(format!(
+                    "{:?} can't move closer to {:?}",
+                    id,
+                    fear.id()
+                )));
+            }
+            distance = next;
+        }
+    }
+    Ok((mode, steps))
+}
+
+/// Returns the attack profile of a creature, if it can attack.
+fn attack_profile(creature: &Creature<SRDRules>) -> Option<&AttackProfile> {
+    creature
+        .ability(&ActionId::Attack)
+        .and_then(|action| action.attack().ok())
+}
+
+/// Verifies that `creature` can make an off-hand attack: it took the Attack action this turn
+/// and holds a light melee weapon in each hand.
+///
+/// # Reference
+///
+/// When you take the Attack action and attack with a light melee weapon that you're holding in
+/// one hand, you can use a bonus action to attack with a different light melee weapon that
+/// you're holding in the other hand.
+fn two_weapon_fighting(creature: &Creature<SRDRules>) -> WeaselResult<(), SRDRules> {
+    if !turn_budget(creature).is_some_and(|budget| budget.took_attack_action()) {
+        return Err(WeaselError::UserError(format!(
+            "{:?} didn't take the Attack action this turn",
+            creature.id()
+        )));
+    }
+    let slots = creature
+        .statistic(&StatisticId::WeaponSlots)
+        .and_then(|statistic| statistic.weapon_slots().ok().copied())
+        .unwrap_or_default();
+    let light_melee = |weapon: Option<WeaponId>| {
+        weapon
+            .and_then(|id| compendium().weapon(&id))
+            .is_some_and(|weapon| weapon.light_melee())
+    };
+    if !light_melee(slots.main_hand) || !light_melee(slots.off_hand) {
+        return Err(WeaselError::UserError(format!(
+            "{:?} isn't holding a light melee weapon in each hand",
+            creature.id()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns how many attacks the Attack action grants to the actor of `action`.
+fn attacks_per_action(state: &BattleState<SRDRules>, action: &weasel::Action<SRDRules>) -> u8 {
+    action
+        .ability
+        .attack()
+        .ok()
+        .or_else(|| {
+            let id = action.actor.entity_id().creature().ok()?;
+            attack_profile(state.entities().creature(&id)?)
+        })
+        .map_or(1, |profile| profile.attacks)
+}
+
+/// Returns the reach, in feet, of a creature's melee attacks.
+fn melee_reach(creature: &Creature<SRDRules>) -> u16 {
+    attack_profile(creature)
+        .filter(|profile| profile.kind == WeaponKind::Melee)
+        .map_or(DEFAULT_REACH_FT, |profile| profile.reach_ft)
+}
+
+/// Returns the size of a creature. Creatures without a size are considered Medium.
+fn creature_size(creature: &Creature<SRDRules>) -> CreatureSize {
+    creature
+        .statistic(&StatisticId::Size)
+        .and_then(|statistic| statistic.size().ok().copied())
+        .unwrap_or(CreatureSize::Medium)
+}
+
+/// Returns true if the teams of two creatures are enemies.
+fn are_enemies(
+    entities: &Entities<SRDRules>,
+    first: &Creature<SRDRules>,
+    second: &Creature<SRDRules>,
+) -> bool {
+    entities.relation(first.team_id(), second.team_id()) == Some(Relation::Enemy)
+}
+
+/// Returns the hostile creatures whose space `mover` can't move through.
+fn blocking_creatures<'a>(
+    state: &'a BattleState<SRDRules>,
+    mover: &Creature<SRDRules>,
+) -> Vec<&'a CharacterId> {
+    let size = creature_size(mover);
+    state
+        .entities()
+        .creatures()
+        .filter(|creature| are_enemies(state.entities(), mover, creature))
+        .filter(|creature| !can_move_through_hostile(size, creature_size(creature)))
+        .map(|creature| creature.id())
+        .collect()
+}
+
+/// Returns the creature grappling `grappled`.
+fn grappler<'a>(
+    state: &'a BattleState<SRDRules>,
+    grappled: &Creature<SRDRules>,
+) -> WeaselResult<&'a Creature<SRDRules>, SRDRules> {
+    Conditions::of(grappled)
+        .get(ConditionId::Grappled)
+        .and_then(|condition| condition.source())
+        .and_then(|source| state.entities().creature(source))
+        .ok_or_else(|| {
+            WeaselError::UserError(format!(
+                "{:?} is not grappled by any creature",
+                grappled.id()
+            ))
+        })
+}
+
+/// Returns the creatures grappled by `grappler`.
+fn grappled_by<'a>(
+    state: &'a BattleState<SRDRules>,
+    grappler: &CharacterId,
+) -> Vec<&'a Creature<SRDRules>> {
+    state
+        .entities()
+        .creatures()
+        .filter(|creature| {
+            Conditions::of(*creature)
+                .get(ConditionId::Grappled)
+                .is_some_and(|condition| condition.source() == Some(grappler))
+        })
+        .collect()
+}
+
+/// Returns how many squares of movement `mover` spends for each square it moves: two while it
+/// drags a grappled creature that halves its speed, one otherwise.
+fn drag_factor(state: &BattleState<SRDRules>, mover: &Creature<SRDRules>) -> u16 {
+    let size = creature_size(mover);
+    let halved = grappled_by(state, mover.id())
+        .into_iter()
+        .any(|grappled| drags_at_half_speed(size, creature_size(grappled)));
+    if halved {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the creatures grappled by `mover` and where they end up when `mover` moves to
+/// `position`, keeping their place relative to it. The position is `None` for creatures that
+/// can't follow.
+fn dragged_positions<'a>(
+    state: &'a BattleState<SRDRules>,
+    mover: &Creature<SRDRules>,
+    position: &Footprint,
+) -> Vec<(&'a Creature<SRDRules>, Option<Footprint>)> {
+    let dragged = grappled_by(state, mover.id());
+    let mut leaving: Vec<_> = dragged.iter().map(|creature| creature.id()).collect();
+    leaving.push(mover.id());
+    let dx = i32::from(position.origin.x) - i32::from(mover.position().origin.x);
+    let dy = i32::from(position.origin.y) - i32::from(mover.position().origin.y);
+    let grid = state.space().model();
+    dragged
+        .iter()
+        .map(|creature| {
+            let position = creature
+                .position()
+                .shifted(dx, dy)
+                .filter(|footprint| grid.check_after(creature.id(), footprint, &leaving).is_ok());
+            (*creature, position)
+        })
+        .collect()
+}
+
+/// Returns the temporary hit points of a creature.
+fn temporary_hit_points(creature: &Creature<SRDRules>) -> Option<&TemporaryHitPoints> {
+    creature
+        .statistic(&StatisticId::TemporaryHitPoints)
+        .and_then(|statistic| statistic.temporary_hit_points().ok())
+}
+
+/// Returns the hit points and temporary hit points left to a creature.
+fn remaining_hit_points(creature: &Creature<SRDRules>) -> u16 {
+    let hit_points = creature
+        .statistic(&StatisticId::HitPoints)
+        .and_then(|statistic| statistic.hit_points().ok())
+        .map_or(0, |hit_points| hit_points.value());
+    let temporary = temporary_hit_points(creature).map_or(0, |temporary| temporary.value());
+    hit_points.saturating_add(temporary)
+}
+
+/// Returns the creatures that can react to `mover` moving along `steps`, together with the
+/// index of the step provoking them and the opportunity offered, in the order they are provoked:
+/// enemies whose reach is left can make an opportunity attack, unless the mover disengaged, and
+/// creatures who readied an attack for the mover can make it once it enters their reach.\
+/// Only conscious creatures that are not incapacitated nor surprised and have a reaction
+/// available are returned.
+fn provoked_reactors<'a>(
+    state: &'a BattleState<SRDRules>,
+    mover: &Creature<SRDRules>,
+    steps: &[Footprint],
+) -> Vec<(usize, &'a Creature<SRDRules>, ReactionOpportunity)> {
+    let disengaged = turn_budget(mover).is_some_and(|budget| budget.disengaged());
+    let mut reactors = Vec::new();
+    let candidates = state
+        .entities()
+        .creatures()
+        .filter(|reactor| reactor.id() != mover.id() && is_conscious(reactor))
+        .filter(|reactor| !Conditions::of(*reactor).is_incapacitated())
+        .filter(|reactor| !is_surprised(state, reactor))
+        .filter(|reactor| {
+            turn_budget(reactor).is_some_and(|budget| budget.can_afford(ActionCost::Reaction))
+        });
+    for reactor in candidates {
+        let profile = match attack_profile(reactor) {
+            Some(profile) => profile,
+            None => continue,
+        };
+        let reach = melee_reach(reactor);
+        let in_reach = |footprint: &Footprint| {
+            squares_to_ft(reactor.position().distance_sq(footprint)) <= reach
+        };
+        let mut leaving = None;
+        let mut entering = None;
+        let mut previous = mover.position();
+        for (index, step) in steps.iter().enumerate() {
+            if leaving.is_none() && in_reach(previous) && !in_reach(step) {
+                leaving = Some(index);
+            }
+            if entering.is_none() && !in_reach(previous) && in_reach(step) {
+                entering = Some(index);
+            }
+            previous = step;
+        }
+        // Opportunity attacks are melee attacks.
+        let provokes = !disengaged && are_enemies(state.entities(), reactor, mover);
+        if let Some(index) = leaving.filter(|_| provokes && profile.kind == WeaponKind::Melee) {
+            let opportunity = ReactionOpportunity::OpportunityAttack {
+                reactor: reactor.id().clone(),
+                target: mover.id().clone(),
+            };
+            reactors.push((index, reactor, opportunity));
+        }
+        let readied = tactics(reactor)
+            .and_then(Tactics::readied)
+            .is_some_and(|trigger| trigger.matches(mover.id()));
+        if let Some(index) = entering.filter(|_| readied) {
+            let opportunity = ReactionOpportunity::ReadiedAttack {
+                reactor: reactor.id().clone(),
+                target: mover.id().clone(),
+            };
+            reactors.push((index, reactor, opportunity));
+        }
+    }
+    reactors.sort_by_key(|(index, _, _)| *index);
+    reactors
+}
+
+/// Returns the creature targeted by a stabilize action.
+fn stabilize_target<'a>(
+    state: &'a BattleState<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+) -> WeaselResult<&'a Creature<SRDRules>, SRDRules> {
+    let target = match action.activation {
+        Some(Activation::Stabilize { target }) => creature(state, target)?,
+        _ => {
+            return Err(WeaselError::UserError(
+                "stabilizing requires a target".to_string(),
+            ))
+        }
+    };
+    if life_state(target).is_some_and(|state| state.is_dying()) {
+        Ok(target)
+    } else {
+        Err(WeaselError::UserError(format!(
+            "{:?} is not dying",
+            target.id()
+        )))
+    }
+}
+
+/// Returns the creature targeted by a grapple or shove action, together with what the shove
+/// attempts. Grapples have no shove effect.
+///
+/// # Reference
+///
+/// The target must be no more than one size larger than you and must be within your reach.
+fn grapple_target<'a>(
+    state: &'a BattleState<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+) -> WeaselResult<(&'a Creature<SRDRules>, Option<ShoveEffect>), SRDRules> {
+    let (target, effect) = match (action.ability.id(), action.activation) {
+        (ActionId::Grapple, Some(Activation::Grapple { target })) => (target, None),
+        (ActionId::Shove, Some(Activation::Shove { target, effect })) => (target, Some(*effect)),
+        _ => {
+            return Err(WeaselError::UserError(
+                "grappling and shoving require a target".to_string(),
+            ))
+        }
+    };
+    if action.actor.entity_id().creature().ok().as_ref() == Some(target) {
+        return Err(WeaselError::UserError(
+            "a creature can't grapple or shove itself".to_string(),
+        ));
+    }
+    let target = creature(state, target)?;
+    let attacker = creature(state, &action.actor.entity_id().creature()?)?;
+    // Grapples and shoves are special melee attacks.
+    if Conditions::of(attacker).forbids_attack(target.id()) {
+        return Err(WeaselError::UserError(format!(
+            "{:?} is charmed by {:?}",
+            attacker.id(),
+            target.id()
+        )));
+    }
+    if !within_size_limit(creature_size(attacker), creature_size(target)) {
+        return Err(WeaselError::UserError(format!(
+            "{:?} is too large for {:?}",
+            target.id(),
+            attacker.id()
+        )));
+    }
+    let distance = squares_to_ft(attacker.position().distance_sq(target.position()));
+    if distance > melee_reach(attacker) {
+        return Err(WeaselError::UserError(
+            SRDError::TargetOutOfRange(distance).to_string(),
+        ));
+    }
+    if effect.is_none()
+        && grappler(state, target).is_ok_and(|grappler| grappler.id() == attacker.id())
+    {
+        return Err(WeaselError::UserError(format!(
+            "{:?} is already grappled by {:?}",
+            target.id(),
+            attacker.id()
+        )));
+    }
+    Ok((target, effect))
+}
+
+/// Returns the creature targeted by an attack action.
+fn attack_target<'a>(
+    state: &'a BattleState<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+) -> WeaselResult<(&'a Creature<SRDRules>, AttackReach, Cover), SRDRules> {
+    let target: &CharacterId = match action.activation {
+        Some(Activation::Attack { target }) => target,
+        _ => {
+            return Err(WeaselError::UserError(
+                "an attack requires a target".to_string(),
+            ))
+        }
+    };
+    if action.actor.entity_id().creature().ok().as_ref() == Some(target) {
+        return Err(WeaselError::UserError(
+            "a creature can't attack itself".to_string(),
+        ));
+    }
+    let target = creature(state, target)?;
+    let attacker = creature(state, &action.actor.entity_id().creature()?)?;
+    // A charmed creature can't attack the charmer.
+    if Conditions::of(attacker).forbids_attack(target.id()) {
+        return Err(WeaselError::UserError(format!(
+            "{:?} is charmed by {:?}",
+            attacker.id(),
+            target.id()
+        )));
+    }
+    let profile = action
+        .ability
+        .attack()
+        .map_err(|err| WeaselError::UserError(format!("invalid attack action: {}", err)))?;
+    // The target must be within reach or range.
+    let distance = squares_to_ft(attacker.position().distance_sq(target.position()));
+    let reach = profile
+        .reach(distance)
+        .ok_or_else(|| WeaselError::UserError(SRDError::TargetOutOfRange(distance).to_string()))?;
+    // Weapons with the ammunition property need ammunition to fire.
+    if profile.ammunition && !has_ammunition(attacker) {
+        return Err(WeaselError::UserError(
+            SRDError::OutOfAmmunition.to_string(),
+        ));
+    }
+    // Targets behind total cover can't be attacked.
+    let cover = state
+        .space()
+        .model()
+        .cover(attacker.position(), target.position());
+    if !cover.allows_targeting() {
+        return Err(WeaselError::UserError(format!(
+            "{:?} is behind total cover",
+            target.id()
+        )));
+    }
+    Ok((target, reach, cover))
+}
+
+/// Returns true if a creature has ammunition left to fire its weapon.
+fn has_ammunition(creature: &Creature<SRDRules>) -> bool {
+    creature
+        .statistic(&StatisticId::Ammunition)
+        .and_then(|statistic| statistic.ammunition().ok())
+        .is_some_and(|ammunition| ammunition.left() > 0)
+}
+
+/// Fires the events following an attack: the attacker expends its ammunition and, if hidden,
+/// gives away its location, the help it received is used up and the impact hits the target.
+///
+/// # Reference
+///
+/// If you are hidden—both unseen and unheard—when you make an attack, you give away your
+/// location when the attack hits or misses.
+fn attack_aftermath(
+    attacker: &Creature<SRDRules>,
+    profile: &AttackProfile,
+    helper: Option<&Creature<SRDRules>>,
+    outcome: AttackOutcome,
+    event_queue: &mut Option<EventQueue<SRDRules>>,
+) {
+    let id = EntityId::Creature(attacker.id().clone());
+    if profile.ammunition {
+        AlterStatistics::trigger(event_queue, id.clone(), StatisticChange::SpendAmmunition).fire();
+    }
+    if is_hidden(attacker) {
+        AlterStatistics::trigger(event_queue, id, StatisticChange::Reveal).fire();
+    }
+    end_help(helper, event_queue);
+    if let Some(impact) = outcome.impact {
+        ApplyImpact::trigger(event_queue, impact).fire();
+    }
+}
+
+/// Returns the conscious creature helping `ally` to attack `target`, or with its next ability
+/// check if `target` is `None`.
+fn helper<'a>(
+    state: &'a BattleState<SRDRules>,
+    ally: &CharacterId,
+    target: Option<&CharacterId>,
+) -> Option<&'a Creature<SRDRules>> {
+    state.entities().creatures().find(|creature| {
+        is_conscious(creature)
+            && tactics(*creature)
+                .and_then(Tactics::help)
+                .is_some_and(|help| &help.ally == ally && help.target.as_ref() == target)
+    })
+}
+
+/// Gives advantage to `check` if `subject` is helped with its next ability check.\
+/// Returns the check and the creature lending the help.
+fn helped_check<'a>(
+    state: &'a BattleState<SRDRules>,
+    subject: &Creature<SRDRules>,
+    mut check: Check,
+) -> (Check, Option<&'a Creature<SRDRules>>) {
+    let helper = helper(state, subject.id(), None);
+    if helper.is_some() {
+        check.add_advantage();
+    }
+    (check, helper)
+}
+
+/// Ends the help lent by `helper`, once its ally benefited from it.
+fn end_help(helper: Option<&Creature<SRDRules>>, event_queue: &mut Option<EventQueue<SRDRules>>) {
+    if let Some(helper) = helper {
+        AlterStatistics::trigger(
+            event_queue,
+            EntityId::Creature(helper.id().clone()),
+            StatisticChange::EndHelp,
+        )
+        .fire();
+    }
+}
+
+/// Returns the help lent by a help action, after verifying that the ally is another creature
+/// of an allied team and that the target of the attack is within 5 feet of the helper.
+fn helped_ally(
+    state: &BattleState<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+) -> WeaselResult<Help, SRDRules> {
+    let help = match action.activation {
+        Some(Activation::Help(help)) => help,
+        _ => {
+            return Err(WeaselError::UserError(
+                "helping requires an ally".to_string(),
+            ))
+        }
+    };
+    let helper = creature(state, &action.actor.entity_id().creature()?)?;
+    if helper.id() == &help.ally {
+        return Err(WeaselError::UserError(
+            "a creature can't help itself".to_string(),
+        ));
+    }
+    let ally = creature(state, &help.ally)?;
+    if !matches!(
+        state.entities().relation(helper.team_id(), ally.team_id()),
+        Some(Relation::Ally) | Some(Relation::Kin)
+    ) {
+        return Err(WeaselError::UserError(format!(
+            "{:?} is not an ally of {:?}",
+            help.ally,
+            helper.id()
+        )));
+    }
+    if let Some(target) = &help.target {
+        let target = creature(state, target)?;
+        let distance = squares_to_ft(helper.position().distance_sq(target.position()));
+        if distance > HELP_DISTANCE_FT {
+            return Err(WeaselError::UserError(
+                SRDError::TargetOutOfRange(distance).to_string(),
+            ));
+        }
+    }
+    Ok(help.clone())
+}
+
+/// Returns the conscious enemies that could notice `creature`.
+fn observers<'a>(
+    state: &'a BattleState<SRDRules>,
+    creature: &'a Creature<SRDRules>,
+) -> impl Iterator<Item = &'a Creature<SRDRules>> {
+    state.entities().creatures().filter(move |observer| {
+        are_enemies(state.entities(), creature, observer) && is_conscious(observer)
+    })
+}
+
+/// Verifies that `hider` can try to hide: it must be invisible or have cover from every
+/// observer that isn't blinded.
+///
+/// # Reference
+///
+/// You can't hide from a creature that can see you clearly.
+fn hiding_spot(
+    state: &BattleState<SRDRules>,
+    hider: &Creature<SRDRules>,
+) -> WeaselResult<(), SRDRules> {
+    if Conditions::of(hider).has(ConditionId::Invisible) {
+        return Ok(());
+    }
+    let grid = state.space().model();
+    let seen_by = observers(state, hider).find(|observer| {
+        !Conditions::of(*observer).has(ConditionId::Blinded)
+            && grid.cover(observer.position(), hider.position()) == Cover::None
+    });
+    match seen_by {
+        Some(observer) => Err(WeaselError::UserError(format!(
+            "{:?} is in plain sight of {:?}",
+            hider.id(),
+            observer.id()
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns the trigger of a ready action, after verifying that the creature can attack and
+/// that the creature setting off the trigger, if any, exists.
+fn ready_trigger(
+    state: &BattleState<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+) -> WeaselResult<ReadyTrigger, SRDRules> {
+    let trigger = match action.activation {
+        Some(Activation::Ready { trigger }) => trigger,
+        _ => {
+            return Err(WeaselError::UserError(
+                "readying requires a trigger".to_string(),
+            ))
+        }
+    };
+    let actor = creature(state, &action.actor.entity_id().creature()?)?;
+    if attack_profile(actor).is_none() {
+        return Err(WeaselError::UserError(format!(
+            "{:?} has no attack to ready",
+            actor.id()
+        )));
+    }
+    match trigger {
+        ReadyTrigger::EntersReach {
+            creature: Some(other),
+        } => creature(state, other).map(|_| trigger.clone()),
+        ReadyTrigger::EntersReach { creature: None } => Ok(trigger.clone()),
+    }
+}
+
+/// Returns the skill used by a search action.
+fn search_skill(action: &weasel::Action<SRDRules>) -> WeaselResult<SkillId, SRDRules> {
+    match action.activation {
+        Some(Activation::Search { skill }) if is_search_skill(*skill) => Ok(*skill),
+        Some(Activation::Search { skill }) => Err(WeaselError::UserError(format!(
+            "{:?} can't be used to search",
+            skill
+        ))),
+        _ => Err(WeaselError::UserError(
+            "searching requires a skill".to_string(),
+        )),
+    }
+}
+
+/// Returns the weapons a creature wields after an object interaction, together with its new
+/// attack and off-hand attack.
+fn wielded_weapons(
+    actor: &Creature<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+) -> WeaselResult<(WeaponSlots, AttackProfile, Option<AttackProfile>), SRDRules> {
+    let (main_hand, off_hand) = match action.activation {
+        Some(Activation::UseObject(ObjectInteraction::Wield {
+            main_hand,
+            off_hand,
+        })) => (*main_hand, *off_hand),
+        _ => {
+            return Err(WeaselError::UserError(
+                "using an object requires an interaction".to_string(),
+            ))
+        }
+    };
+    let slots = WeaponSlots {
+        main_hand,
+        off_hand,
+        shield: actor
+            .statistic(&StatisticId::WeaponSlots)
+            .and_then(|statistic| statistic.weapon_slots().ok())
+            .is_some_and(|slots| slots.shield),
+    };
+    let proficient = |id: WeaponId, weapon: &Weapon| {
+        [
+            WeaponProficiency::Weapon(id),
+            WeaponProficiency::Category(weapon.category),
+        ]
+        .iter()
+        .any(|proficiency| {
+            actor
+                .statistic(&StatisticId::WeaponProficiency(*proficiency))
+                .is_some()
+        })
+    };
+    let attacks = actor
+        .statistic(&StatisticId::Class)
+        .and_then(|statistic| statistic.class().ok())
+        .and_then(|class| compendium().class_model(class))
+        .zip(
+            actor
+                .statistic(&StatisticId::Level)
+                .and_then(|statistic| statistic.level().ok()),
+        )
+        .map_or(1, |(model, level)| model.attacks_per_action(level));
+    let style = actor
+        .statistic(&StatisticId::FightingStyle(
+            FightingStyle::TwoWeaponFighting,
+        ))
+        .is_some();
+    let main_hand = AttackProfile::main_hand(&slots, proficient, attacks)
+        .map_err(|err| WeaselError::UserError(err.to_string()))?;
+    let off_hand = AttackProfile::off_hand_weapon(&slots, proficient, style)
+        .map_err(|err| WeaselError::UserError(err.to_string()))?;
+    Ok((slots, main_hand, off_hand))
+}
+
+/// Draws or stows weapons, replacing the attacks of the creature with those of its new
+/// weapons.
+///
+/// # Reference
+///
+/// You can also interact with one object or feature of the environment for free, during either
+/// your move or your action. For example, you could open a door during your move as you stride
+/// toward a foe, or you could draw your weapon as part of the same action you use to attack.\
+/// If you want to interact with a second object, you need to use your action.
+fn use_object(
+    state: &BattleState<SRDRules>,
+    action: &weasel::Action<SRDRules>,
+    event_queue: &mut Option<EventQueue<SRDRules>>,
+) {
+    let result = action
+        .actor
+        .entity_id()
+        .creature()
+        .and_then(|id| wielded_weapons(creature(state, &id)?, action));
+    match result {
+        Ok((slots, main_hand, off_hand)) => {
+            let id = action.actor.entity_id().clone();
+            AlterStatistics::trigger(event_queue, id.clone(), StatisticChange::Wield(slots)).fire();
+            AlterAbilities::trigger(
+                event_queue,
+                id.clone(),
+                ActionsAlteration::Add(ActionInitializer::Attack(main_hand)),
+            )
+            .fire();
+            let alteration = match off_hand {
+                Some(profile) => ActionsAlteration::Add(ActionInitializer::OffHandAttack(profile)),
+                None => ActionsAlteration::Remove(ActionId::OffHandAttack),
+            };
+            AlterAbilities::trigger(event_queue, id, alteration).fire();
+        }
+        Err(err) => log::error!("failed to use an object: {}", err),
+    }
+}
+
+/// Returns true if a conscious enemy that isn't incapacitated is within 5 feet of `attacker`,
+/// hindering its ranged attacks.
+fn is_threatened(entities: &Entities<SRDRules>, attacker: &Creature<SRDRules>) -> bool {
+    entities.creatures().any(|creature| {
+        are_enemies(entities, attacker, creature)
+            && is_conscious(creature)
+            && !Conditions::of(creature).is_incapacitated()
+            && squares_to_ft(attacker.position().distance_sq(creature.position())) <= 5
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attack_profile, damage_against, is_threatened};
+    use crate::ability::{AbilityScore, CONSTITUTION, DEXTERITY, STRENGTH, WISDOM};
+    use crate::character::class::FIGHTER;
+    use crate::character::race::HILL_DWARF;
+    use crate::character::CharacterId;
+    use crate::compendium::init_srd_compendium;
+    use crate::damage::{Damage, DamageDefense, DamageType};
+    use crate::dice::roll::SequenceRoller;
+    use crate::dice::DiceRolls;
+    use crate::equipment::weapon::{DAGGER, DART, GREATAXE, LONGSWORD, SHORTSWORD, SLING};
+    use crate::error::SRDError;
+    use crate::handle::creature_handle::{CreatureHandle, CreatureHandleMut};
+    use crate::hit_points::TemporaryHitPoints;
+    use crate::rules::core::action::{ActionId, Activation, ObjectInteraction};
+    use crate::rules::core::statistic::StatisticChange;
+    use crate::rules::core::{
+        ActionCost, ConditionId, ConditionPotency, Cover, CreatureSize, DeathSavingThrows,
+        Footprint, GridSeed, Help, Impact, Initiative, InitiativeOrder, LifeState, MovementMode,
+        ReadyTrigger, ShoveEffect, Square, Tactics,
+    };
+    use crate::rules::narrator::NopNarrator;
+    use crate::rules::reaction::PassiveReactor;
+    use crate::rules::SRDRules;
+    use crate::skill::{ATHLETICS, PERCEPTION, STEALTH};
+    use crate::util::{end_turn, simple_server, start_turn_of};
+    use std::sync::Arc;
+    use weasel::error::WeaselErrorType;
+    use weasel::{
+        ActivateAbility, Actor, AlterStatistics, Battle, BattleController, ClearStatus, EntityId,
+        EventTrigger, InflictStatus, MoveEntity, ResetEntropy, ResetRounds, ResetSpace, Server,
+        WeaselError,
+    };
+
+    fn activate(
+        server: &mut Server<SRDRules>,
+        action: ActionId,
+        activation: Option<Activation>,
+    ) -> Option<WeaselErrorType<SRDRules>> {
+        let id = EntityId::Creature("attacker".into());
+        let result = match activation {
+            Some(activation) => ActivateAbility::trigger(server, id, action)
+                .activation(activation)
+                .fire(),
+            None => ActivateAbility::trigger(server, id, action).fire(),
+        };
+        match result.err().map(|err| err.unfold()) {
+            Some(WeaselError::AbilityNotActivable(_, _, err)) => Some(*err),
+            other => other,
+        }
+    }
+
+    #[test]
+    fn attack_until_removal() {
+        let mut server = simple_server();
+        for id in &["attacker", "target"] {
+            crate::Character::new(*id, HILL_DWARF, FIGHTER)
+                .unwrap()
+                .add_ability(STRENGTH, AbilityScore::new(20).unwrap())
+                .add_ability(CONSTITUTION, AbilityScore::new(3).unwrap())
+                .spawn(&mut server)
+                .unwrap();
+        }
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        let mut attacks = 0;
+        while server.battle().entities().creatures().count() > 1 {
+            start_turn_of(&mut server, &attacker);
+            // The target might have died after failing its death saving throws.
+            if server.battle().entities().creature(&target).is_some() {
+                CreatureHandleMut::new(&attacker, &mut server)
+                    .attack(&target)
+                    .unwrap();
+            }
+            end_turn(&mut server);
+            attacks += 1;
+            assert!(attacks < 1000, "the target should have been defeated");
+        }
+        assert!(server.battle().entities().creature(&target).is_none());
+        assert!(server.battle().entities().creature(&attacker).is_some());
+    }
+
+    #[test]
+    fn damage_defenses_reduce_damage() {
+        let mut server = simple_server();
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(STRENGTH, AbilityScore::new(20).unwrap())
+            .spawn(&mut server)
+            .unwrap();
+        crate::Character::new("target", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_damage_defense(DamageType::Bludgeoning, DamageDefense::Immunity)
+            .spawn(&mut server)
+            .unwrap();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        let hit_points = |server: &Server<SRDRules>| {
+            CreatureHandle::new(&target, server)
+                .hit_points()
+                .unwrap()
+                .value()
+        };
+        let initial_hit_points = hit_points(&server);
+        for _ in 0..20 {
+            start_turn_of(&mut server, &attacker);
+            CreatureHandleMut::new(&attacker, &mut server)
+                .attack(&target)
+                .unwrap();
+            end_turn(&mut server);
+        }
+        assert_eq!(hit_points(&server), initial_hit_points);
+    }
+
+    #[test]
+    fn petrified_creatures_resist_all_damage() {
+        let mut server = simple_server();
+        let target: CharacterId = "target".into();
+        crate::Character::new(target.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_damage_defense(DamageType::Fire, DamageDefense::Immunity)
+            .add_damage_defense(DamageType::Slashing, DamageDefense::Vulnerability)
+            .spawn(&mut server)
+            .unwrap();
+        InflictStatus::trigger(
+            &mut server,
+            EntityId::Creature(target.clone()),
+            ConditionId::Petrified,
+        )
+        .fire()
+        .unwrap();
+        let impact = |damage_type| Impact {
+            source: "attacker".into(),
+            target: target.clone(),
+            damage: vec![Damage::new(
+                damage_type,
+                DiceRolls {
+                    modifier: 7,
+                    ..DiceRolls::default()
+                },
+            )
+            .roll(&mut SequenceRoller::new(vec![]))],
+            critical: false,
+        };
+        let creature = server.battle().entities().creature(&target).unwrap();
+        // Resistance applies on top of unrelated defenses, but not to immunities.
+        assert_eq!(
+            damage_against(creature, &impact(DamageType::Bludgeoning)),
+            3
+        );
+        assert_eq!(damage_against(creature, &impact(DamageType::Slashing)), 6);
+        assert_eq!(damage_against(creature, &impact(DamageType::Fire)), 0);
+    }
+
+    #[test]
+    fn attack_with_weapon() {
+        let mut server = simple_server();
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(STRENGTH, AbilityScore::new(20).unwrap())
+            .wield(Some(GREATAXE))
+            .unwrap()
+            .spawn(&mut server)
+            .unwrap();
+        crate::Character::new("target", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_damage_defense(DamageType::Slashing, DamageDefense::Immunity)
+            .spawn(&mut server)
+            .unwrap();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        let profile = attack_profile(server.battle().entities().creature(&attacker).unwrap())
+            .unwrap()
+            .clone();
+        assert_eq!(profile.weapon, Some(GREATAXE));
+        assert!(profile.heavy);
+        let initial_hit_points = CreatureHandle::new(&target, &server)
+            .hit_points()
+            .unwrap()
+            .value();
+        // Greataxes deal slashing damage, to which the target is immune.
+        for _ in 0..20 {
+            start_turn_of(&mut server, &attacker);
+            CreatureHandleMut::new(&attacker, &mut server)
+                .attack(&target)
+                .unwrap();
+            end_turn(&mut server);
+        }
+        assert_eq!(
+            CreatureHandle::new(&target, &server)
+                .hit_points()
+                .unwrap()
+                .value(),
+            initial_hit_points
+        );
+    }
+
+    /// Spawns an attacker and a dying target. Rolls are made deterministic by `seed`.
+    fn spawn_dying_target(server: &mut Server<SRDRules>, seed: u64) {
+        ResetEntropy::trigger(server).seed(seed).fire().unwrap();
+        for id in &["attacker", "target"] {
+            crate::Character::new(*id, HILL_DWARF, FIGHTER)
+                .unwrap()
+                .spawn(server)
+                .unwrap();
+        }
+        AlterStatistics::trigger(
+            server,
+            EntityId::Creature("target".into()),
+            StatisticChange::Damage {
+                amount: 11,
+                critical: false,
+            },
+        )
+        .fire()
+        .unwrap();
+    }
+
+    fn life_state(server: &Server<SRDRules>, id: &CharacterId) -> Option<LifeState> {
+        CreatureHandle::new(id, server).life_state().ok().copied()
+    }
+
+    #[test]
+    fn stabilize_dying_creature() {
+        let mut server = simple_server();
+        spawn_dying_target(&mut server, 2);
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        assert!(life_state(&server, &target).unwrap().is_dying());
+        // Unconscious creatures can't act.
+        start_turn_of(&mut server, &target);
+        let activation = Activation::Attack {
+            target: attacker.clone(),
+        };
+        let result = ActivateAbility::trigger(
+            &mut server,
+            EntityId::Creature(target.clone()),
+            ActionId::Attack,
+        )
+        .activation(activation)
+        .fire();
+        assert!(result.is_err());
+        end_turn(&mut server);
+        // First aid eventually stabilizes the target.
+        let mut attempts = 0;
+        while life_state(&server, &target).is_some_and(|state| state.is_dying()) {
+            start_turn_of(&mut server, &attacker);
+            CreatureHandleMut::new(&attacker, &mut server)
+                .stabilize(&target)
+                .unwrap();
+            end_turn(&mut server);
+            attempts += 1;
+        }
+        // The first Medicine check fails, the second succeeds.
+        assert_eq!(attempts, 2);
+        assert_eq!(life_state(&server, &target), Some(LifeState::Stable));
+        // Only dying creatures can be stabilized.
+        start_turn_of(&mut server, &attacker);
+        let activation = Activation::Stabilize {
+            target: attacker.clone(),
+        };
+        assert!(matches!(
+            activate(&mut server, ActionId::Stabilize, Some(activation)),
+            Some(WeaselError::UserError(_))
+        ));
+    }
+
+    #[test]
+    fn temporary_hit_points_duration() {
+        let mut server = simple_server();
+        let id: CharacterId = "one".into();
+        crate::Character::new(id.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn(&mut server)
+            .unwrap();
+        AlterStatistics::trigger(
+            &mut server,
+            EntityId::Creature(id.clone()),
+            StatisticChange::TemporaryHitPoints(TemporaryHitPoints::with_duration(5, 2)),
+        )
+        .fire()
+        .unwrap();
+        let temporary = |server: &Server<SRDRules>| {
+            *CreatureHandle::new(&id, server)
+                .temporary_hit_points()
+                .unwrap()
+        };
+        // The temporary hit points last until the start of the creature's second turn.
+        start_turn_of(&mut server, &id);
+        assert_eq!(temporary(&server), TemporaryHitPoints::with_duration(5, 1));
+        end_turn(&mut server);
+        start_turn_of(&mut server, &id);
+        assert_eq!(temporary(&server).value(), 0);
+    }
+
+    #[test]
+    fn death_saving_throws_on_turn_start() {
+        let target: CharacterId = "target".into();
+        // Seeds leading to death, to a natural 20 and to three successes.
+        for (seed, expected_turns, expected_state, expected_hit_points) in [
+            (0, 3, None, None),
+            (1, 1, Some(LifeState::Conscious), Some(1)),
+            (2, 5, Some(LifeState::Stable), Some(0)),
+        ] {
+            let mut server = simple_server();
+            spawn_dying_target(&mut server, seed);
+            let mut turns = 0;
+            while life_state(&server, &target).is_some_and(|state| state.is_dying()) {
+                start_turn_of(&mut server, &target);
+                end_turn(&mut server);
+                turns += 1;
+            }
+            assert_eq!(turns, expected_turns);
+            assert_eq!(life_state(&server, &target), expected_state);
+            assert_eq!(
+                CreatureHandle::new(&target, &server)
+                    .current_hit_points()
+                    .ok(),
+                expected_hit_points
+            );
+        }
+    }
+
+    #[test]
+    fn melee_hit_on_dying_creature() {
+        let mut server = simple_server();
+        spawn_dying_target(&mut server, 0);
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        start_turn_of(&mut server, &attacker);
+        assert_eq!(
+            life_state(&server, &target),
+            Some(LifeState::Dying(DeathSavingThrows::default()))
+        );
+        // Hits from within 5 feet against an unconscious creature are critical hits.
+        CreatureHandleMut::new(&attacker, &mut server)
+            .attack(&target)
+            .unwrap();
+        assert_eq!(
+            life_state(&server, &target),
+            Some(LifeState::Dying(DeathSavingThrows {
+                successes: 0,
+                failures: 2
+            }))
+        );
+    }
+
+    #[test]
+    fn one_action_per_turn() {
+        let mut server = simple_server();
+        for id in &["attacker", "target"] {
+            crate::Character::new(*id, HILL_DWARF, FIGHTER)
+                .unwrap()
+                .add_ability(CONSTITUTION, AbilityScore::new(20).unwrap())
+                .spawn(&mut server)
+                .unwrap();
+        }
+        let attacker: CharacterId = "attacker".into();
+        let activation = Activation::Attack {
+            target: "target".into(),
+        };
+        start_turn_of(&mut server, &attacker);
+        assert_eq!(
+            activate(&mut server, ActionId::Attack, Some(activation.clone())),
+            None
+        );
+        let budget = *CreatureHandle::new(&attacker, &server)
+            .turn_budget()
+            .unwrap();
+        assert_eq!(budget.actions(), 0);
+        assert_eq!(budget.bonus_actions(), 1);
+        assert!(matches!(
+            activate(&mut server, ActionId::Attack, Some(activation.clone())),
+            Some(WeaselError::UserError(_))
+        ));
+        // Action Surge.
+        AlterStatistics::trigger(
+            &mut server,
+            EntityId::Creature(attacker.clone()),
+            StatisticChange::GrantBudget(ActionCost::Action),
+        )
+        .fire()
+        .unwrap();
+        assert_eq!(
+            activate(&mut server, ActionId::Attack, Some(activation.clone())),
+            None
+        );
+        end_turn(&mut server);
+        // The budget is restored on the next turn.
+        start_turn_of(&mut server, &attacker);
+        assert_eq!(
+            activate(&mut server, ActionId::Attack, Some(activation)),
+            None
+        );
+    }
+
+    #[test]
+    fn attack_not_activable() {
+        let mut server = simple_server();
+        for id in &["attacker", "target"] {
+            crate::Character::new(*id, HILL_DWARF, FIGHTER)
+                .unwrap()
+                .spawn(&mut server)
+                .unwrap();
+        }
+        let attacker: CharacterId = "attacker".into();
+        start_turn_of(&mut server, &attacker);
+        // Missing target.
+        assert!(matches!(
+            activate(&mut server, ActionId::Attack, None),
+            Some(WeaselError::UserError(_))
+        ));
+        // Self attack.
+        let activation = Activation::Attack {
+            target: attacker.clone(),
+        };
+        assert!(matches!(
+            activate(&mut server, ActionId::Attack, Some(activation)),
+            Some(WeaselError::UserError(_))
+        ));
+        // Non existing target.
+        let activation = Activation::Attack {
+            target: "nobody".into(),
+        };
+        assert!(matches!(
+            activate(&mut server, ActionId::Attack, Some(activation)),
+            Some(WeaselError::CreatureNotFound(_))
+        ));
+        // Actions other than attacks.
+        let activation = Activation::Attack {
+            target: "target".into(),
+        };
+        assert!(matches!(
+            activate(&mut server, ActionId::Movement, Some(activation.clone())),
+            Some(WeaselError::UserError(_))
+        ));
+        assert_eq!(
+            activate(&mut server, ActionId::Attack, Some(activation)),
+            None
+        );
+    }
+
+    #[test]
+    fn ranged_attacks() {
+        let mut server = simple_server();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        let mut character = crate::Character::new(attacker.clone(), HILL_DWARF, FIGHTER).unwrap();
+        character
+            .wield(Some(SLING))
+            .unwrap()
+            .set_ammunition(3)
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new(target.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(CONSTITUTION, AbilityScore::new(20).unwrap())
+            .spawn_at(&mut server, Square::new(30, 0))
+            .unwrap();
+        // The target is 72 ft away, within the sling's long range.
+        for _ in 0..3 {
+            start_turn_of(&mut server, &attacker);
+            CreatureHandleMut::new(&attacker, &mut server)
+                .attack(&target)
+                .unwrap();
+            end_turn(&mut server);
+        }
+        let ammunition = *CreatureHandle::new(&attacker, &server)
+            .ammunition()
+            .unwrap();
+        assert_eq!((ammunition.left(), ammunition.spent()), (0, 3));
+        start_turn_of(&mut server, &attacker);
+        assert_eq!(
+            activate(
+                &mut server,
+                ActionId::Attack,
+                Some(Activation::Attack {
+                    target: target.clone()
+                })
+            ),
+            Some(WeaselError::UserError(
+                SRDError::OutOfAmmunition.to_string()
+            ))
+        );
+        end_turn(&mut server);
+        character.recover_ammunition(&server).unwrap();
+        assert_eq!(character.ammunition(), 1);
+        // Darts can't be thrown that far.
+        let mut server = simple_server();
+        crate::Character::new(attacker.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .wield(Some(DART))
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new(target.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(30, 0))
+            .unwrap();
+        start_turn_of(&mut server, &attacker);
+        assert!(CreatureHandleMut::new(&attacker, &mut server)
+            .attack(&target)
+            .is_err());
+    }
+
+    #[test]
+    fn threatened_by_adjacent_enemies() {
+        let mut server = simple_server();
+        let attacker: CharacterId = "attacker".into();
+        crate::Character::new(attacker.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new("ally", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(2, 0))
+            .unwrap();
+        let threatened = |server: &Server<SRDRules>| {
+            let entities = server.battle().entities();
+            is_threatened(entities, entities.creature(&attacker).unwrap())
+        };
+        assert!(!threatened(&server));
+        let enemy: CharacterId = "enemy".into();
+        crate::Character::new(enemy.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .set_team(1)
+            .spawn_at(&mut server, Square::new(0, 2))
+            .unwrap();
+        assert!(threatened(&server));
+        // Incapacitated enemies are no threat.
+        inflict(&mut server, &enemy, ConditionId::Stunned);
+        assert!(!threatened(&server));
+    }
+
+    #[test]
+    fn attacks_behind_cover() {
+        let mut server = simple_server();
+        ResetSpace::trigger(&mut server)
+            .seed(GridSeed {
+                width: 20,
+                height: 4,
+                walls: (0..4).map(|y| Square::new(6, y)).collect(),
+                ..GridSeed::default()
+            })
+            .fire()
+            .unwrap();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        crate::Character::new(attacker.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .wield(Some(SLING))
+            .unwrap()
+            .set_ammunition(1)
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new(target.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(10, 0))
+            .unwrap();
+        let handle = CreatureHandle::new(&attacker, &server);
+        assert_eq!(handle.cover_of(&target), Ok(Cover::Total));
+        assert_eq!(handle.cover_at(Square::new(3, 3)), Ok(Cover::None));
+        assert!(handle.cover_at(Square::new(20, 0)).is_err());
+        start_turn_of(&mut server, &attacker);
+        assert_eq!(
+            activate(
+                &mut server,
+                ActionId::Attack,
+                Some(Activation::Attack {
+                    target: target.clone()
+                })
+            ),
+            Some(WeaselError::UserError(format!(
+                "{:?} is behind total cover",
+                target
+            )))
+        );
+        // A creature in the way only gives partial cover.
+        let mut server = simple_server();
+        crate::Character::new(attacker.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .wield(Some(SLING))
+            .unwrap()
+            .set_ammunition(1)
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new("bystander", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(5, 0))
+            .unwrap();
+        crate::Character::new(target.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(10, 0))
+            .unwrap();
+        let cover = CreatureHandle::new(&attacker, &server)
+            .cover_of(&target)
+            .unwrap();
+        assert!(cover > Cover::None && cover.allows_targeting());
+        start_turn_of(&mut server, &attacker);
+        CreatureHandleMut::new(&attacker, &mut server)
+            .attack(&target)
+            .unwrap();
+    }
+
+    #[test]
+    fn move_around_attacks() {
+        let mut server = simple_server();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        crate::Character::new(attacker.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new(target.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(CONSTITUTION, AbilityScore::new(20).unwrap())
+            .spawn_at(&mut server, Square::new(10, 0))
+            .unwrap();
+        let row = |xs: Vec<u16>| xs.into_iter().map(|x| Square::new(x, 0)).collect();
+        start_turn_of(&mut server, &attacker);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        // Dwarves walk 25 ft (10 squares) per turn.
+        handle
+            .move_along(row(vec![1, 2]), MovementMode::Walk)
+            .unwrap();
+        // The target is out of reach.
+        assert!(handle.attack(&target).is_err());
+        handle
+            .move_along(row((3..=7).collect()), MovementMode::Walk)
+            .unwrap();
+        handle.attack(&target).unwrap();
+        handle.move_along(row(vec![8]), MovementMode::Walk).unwrap();
+        assert_eq!(
+            handle.position(),
+            Ok(&Footprint::new(Square::new(8, 0), CreatureSize::Medium))
+        );
+        assert_eq!(handle.turn_budget().unwrap().movement(), 5);
+        // Not enough movement left.
+        let path = vec![Square::new(8, 1), Square::new(8, 2), Square::new(8, 3)];
+        assert!(handle.move_along(path, MovementMode::Walk).is_err());
+        // Dwarves can't fly.
+        let path = vec![Square::new(8, 1)];
+        assert!(handle.move_along(path, MovementMode::Fly).is_err());
+        end_turn(&mut server);
+        // Dashing doubles the movement.
+        start_turn_of(&mut server, &attacker);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        handle.dash().unwrap();
+        assert_eq!(handle.turn_budget().unwrap().movement(), 50);
+        assert!(handle.dash().is_err());
+        handle
+            .move_along(row((0..8).rev().collect()), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(handle.turn_budget().unwrap().movement(), 30);
+    }
+
+    #[test]
+    fn hostile_creatures_block_movement() {
+        let mut server = simple_server();
+        let mover: CharacterId = "mover".into();
+        crate::Character::new(mover.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new("ally", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(2, 0))
+            .unwrap();
+        crate::Character::new("enemy", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .set_team(1)
+            .spawn_at(&mut server, Square::new(2, 2))
+            .unwrap();
+        start_turn_of(&mut server, &mover);
+        let mut handle = CreatureHandleMut::new(&mover, &mut server);
+        // The enemy is the same size as the mover.
+        assert!(handle
+            .move_along(vec![Square::new(1, 1)], MovementMode::Walk)
+            .is_err());
+        // The ally's space is difficult terrain: crossing it costs 7 squares out of 10.
+        handle
+            .move_along(row(vec![1, 2, 3, 4]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(
+            handle
+                .turn_budget()
+                .unwrap()
+                .movement_sq(MovementMode::Walk),
+            3
+        );
+    }
+
+    fn spawn_side_by_side(server: &mut Server<SRDRules>) {
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new("target", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(CONSTITUTION, AbilityScore::new(20).unwrap())
+            .set_team(1)
+            .spawn_at(server, Square::new(2, 0))
+            .unwrap();
+    }
+
+    fn reactions(server: &Server<SRDRules>, id: &CharacterId) -> u8 {
+        CreatureHandle::new(id, server)
+            .turn_budget()
+            .unwrap()
+            .reactions()
+    }
+
+    fn row(xs: Vec<u16>) -> Vec<Square> {
+        xs.into_iter().map(|x| Square::new(x, 0)).collect()
+    }
+
+    #[test]
+    fn opportunity_attacks() {
+        let mut server = simple_server();
+        spawn_side_by_side(&mut server);
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        start_turn_of(&mut server, &target);
+        // Moving within reach doesn't provoke.
+        CreatureHandleMut::new(&target, &mut server)
+            .move_along(row(vec![3]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 1);
+        // Leaving the reach does.
+        CreatureHandleMut::new(&target, &mut server)
+            .move_along(row(vec![4, 5, 6]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 0);
+        assert_eq!(
+            CreatureHandle::new(&target, &server).position(),
+            Ok(&Footprint::new(Square::new(6, 0), CreatureSize::Medium))
+        );
+        end_turn(&mut server);
+        // Reactions are restored at the start of the creature's turn.
+        start_turn_of(&mut server, &attacker);
+        assert_eq!(reactions(&server, &attacker), 1);
+        CreatureHandleMut::new(&attacker, &mut server)
+            .move_along(row(vec![1, 2, 3]), MovementMode::Walk)
+            .unwrap();
+        end_turn(&mut server);
+        // Disengaging creatures don't provoke.
+        start_turn_of(&mut server, &target);
+        let mut handle = CreatureHandleMut::new(&target, &mut server);
+        handle.disengage().unwrap();
+        handle
+            .move_along(row(vec![7, 8, 9]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 1);
+        end_turn(&mut server);
+        // Allies don't make opportunity attacks.
+        crate::Character::new("ally", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 2))
+            .unwrap();
+        let ally: CharacterId = "ally".into();
+        start_turn_of(&mut server, &ally);
+        CreatureHandleMut::new(&ally, &mut server)
+            .move_along(
+                vec![Square::new(0, 3), Square::new(0, 4), Square::new(0, 5)],
+                MovementMode::Walk,
+            )
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 1);
+    }
+
+    #[test]
+    fn opportunity_attacks_from_last_position_in_reach() {
+        let mut server = simple_server();
+        ResetSpace::trigger(&mut server)
+            .seed(GridSeed {
+                width: 10,
+                height: 10,
+                walls: (0..3).map(|y| Square::new(2, y)).collect(),
+                ..GridSeed::default()
+            })
+            .fire()
+            .unwrap();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        crate::Character::new(attacker.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new(target.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .set_team(1)
+            .spawn_at(&mut server, Square::new(3, 0))
+            .unwrap();
+        // The target starts behind total cover, but leaves the reach from an exposed position.
+        assert_eq!(
+            CreatureHandle::new(&attacker, &server)
+                .cover_of(&target)
+                .unwrap(),
+            Cover::Total
+        );
+        start_turn_of(&mut server, &target);
+        CreatureHandleMut::new(&target, &mut server)
+            .move_along(
+                (1..=4).map(|y| Square::new(3, y)).collect(),
+                MovementMode::Walk,
+            )
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 0);
+    }
+
+    #[test]
+    fn surprised_creatures_cant_react() {
+        let mut server = simple_server();
+        spawn_side_by_side(&mut server);
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        // The target acts first, while the attacker is surprised.
+        let dexterity = AbilityScore::default();
+        let mut order = InitiativeOrder::default();
+        for (id, d20) in [(&target, 20), (&attacker, 1)] {
+            let mut roller = SequenceRoller::new(vec![d20, 1]);
+            order.add(Initiative::roll(id.clone(), dexterity, 0, &mut roller));
+        }
+        assert!(order.surprise(&attacker));
+        ResetRounds::trigger(&mut server)
+            .seed(order)
+            .fire()
+            .unwrap();
+        start_turn_of(&mut server, &target);
+        CreatureHandleMut::new(&target, &mut server)
+            .move_along(row(vec![3, 4, 5]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 1);
+        CreatureHandleMut::new(&target, &mut server)
+            .move_along(row(vec![4, 3]), MovementMode::Walk)
+            .unwrap();
+        end_turn(&mut server);
+        // Once its first turn is skipped, the attacker can react again.
+        start_turn_of(&mut server, &target);
+        CreatureHandleMut::new(&target, &mut server)
+            .move_along(row(vec![4, 5, 6]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 0);
+    }
+
+    #[test]
+    fn declined_reactions() {
+        let _ = init_srd_compendium();
+        let rules = SRDRules::new(Arc::new(NopNarrator::default()))
+            .with_reactor(Arc::new(PassiveReactor::default()));
+        let mut server = Server::builder(Battle::builder(rules).build()).build();
+        spawn_side_by_side(&mut server);
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        start_turn_of(&mut server, &target);
+        CreatureHandleMut::new(&target, &mut server)
+            .move_along(row(vec![3, 4, 5]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 1);
+    }
+
+    fn inflict(server: &mut Server<SRDRules>, id: &CharacterId, condition: ConditionId) {
+        inflict_with(server, id, condition, ConditionPotency::default());
+    }
+
+    fn inflict_with(
+        server: &mut Server<SRDRules>,
+        id: &CharacterId,
+        condition: ConditionId,
+        potency: ConditionPotency,
+    ) {
+        InflictStatus::trigger(server, EntityId::Creature(id.clone()), condition)
+            .potency(potency)
+            .fire()
+            .unwrap();
+    }
+
+    #[test]
+    fn conditions_restrict_creatures() {
+        let mut server = simple_server();
+        spawn_side_by_side(&mut server);
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        // Stunned creatures can't act until the condition ends.
+        let potency = ConditionPotency {
+            duration: Some(1),
+            ..ConditionPotency::default()
+        };
+        inflict_with(&mut server, &attacker, ConditionId::Stunned, potency);
+        let activation = Activation::Attack {
+            target: target.clone(),
+        };
+        start_turn_of(&mut server, &target);
+        end_turn(&mut server);
+        start_turn_of(&mut server, &attacker);
+        assert!(!CreatureHandle::new(&attacker, &server)
+            .conditions()
+            .unwrap()
+            .has(ConditionId::Stunned));
+        assert_eq!(
+            activate(&mut server, ActionId::Attack, Some(activation.clone())),
+            None
+        );
+        end_turn(&mut server);
+        inflict(&mut server, &attacker, ConditionId::Stunned);
+        start_turn_of(&mut server, &attacker);
+        assert!(matches!(
+            activate(&mut server, ActionId::Attack, Some(activation.clone())),
+            Some(WeaselError::UserError(_))
+        ));
+        ClearStatus::trigger(
+            &mut server,
+            EntityId::Creature(attacker.clone()),
+            ConditionId::Stunned,
+        )
+        .fire()
+        .unwrap();
+        // Prone creatures crawl, or spend half their speed to stand up.
+        inflict(&mut server, &attacker, ConditionId::Prone);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        assert!(handle.move_along(row(vec![1]), MovementMode::Walk).is_err());
+        handle
+            .move_along(vec![Square::new(0, 1)], MovementMode::Crawl)
+            .unwrap();
+        assert_eq!(handle.turn_budget().unwrap().movement(), 20);
+        handle.stand_up().unwrap();
+        assert!(!handle.conditions().unwrap().has(ConditionId::Prone));
+        assert_eq!(handle.turn_budget().unwrap().movement(), 7);
+        assert!(handle.stand_up().is_err());
+        end_turn(&mut server);
+        // Frightened creatures can't move closer to the source of their fear.
+        let potency = ConditionPotency {
+            source: Some(target.clone()),
+            ..ConditionPotency::default()
+        };
+        inflict_with(&mut server, &attacker, ConditionId::Frightened, potency);
+        start_turn_of(&mut server, &attacker);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        handle
+            .move_along(
+                vec![Square::new(0, 2), Square::new(0, 3)],
+                MovementMode::Walk,
+            )
+            .unwrap();
+        assert!(handle
+            .move_along(vec![Square::new(0, 2)], MovementMode::Walk)
+            .is_err());
+        // Grappled creatures can't move at all.
+        end_turn(&mut server);
+        inflict(&mut server, &attacker, ConditionId::Grappled);
+        start_turn_of(&mut server, &attacker);
+        assert!(CreatureHandleMut::new(&attacker, &mut server)
+            .move_along(vec![Square::new(0, 4)], MovementMode::Walk)
+            .is_err());
+    }
+
+    #[test]
+    fn exhaustion_kills() {
+        let mut server = simple_server();
+        spawn_side_by_side(&mut server);
+        let target: CharacterId = "target".into();
+        let potency = ConditionPotency {
+            levels: 5,
+            ..ConditionPotency::default()
+        };
+        inflict_with(&mut server, &target, ConditionId::Exhaustion, potency);
+        let conditions = CreatureHandle::new(&target, &server).conditions().unwrap();
+        assert_eq!(conditions.exhaustion(), 5);
+        inflict(&mut server, &target, ConditionId::Exhaustion);
+        assert!(server.battle().entities().creature(&target).is_none());
+    }
+
+    fn has_condition(server: &Server<SRDRules>, id: &CharacterId, condition: ConditionId) -> bool {
+        CreatureHandle::new(id, server)
+            .conditions()
+            .unwrap()
+            .has(condition)
+    }
+
+    #[test]
+    fn two_weapon_fighting() {
+        let mut server = simple_server();
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .wield(Some(SHORTSWORD))
+            .unwrap()
+            .wield_off_hand(Some(DAGGER))
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new("target", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(CONSTITUTION, AbilityScore::new(20).unwrap())
+            .spawn_at(&mut server, Square::new(2, 0))
+            .unwrap();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        start_turn_of(&mut server, &attacker);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        // The off-hand attack requires the Attack action first.
+        assert!(handle.off_hand_attack(&target).is_err());
+        handle.attack(&target).unwrap();
+        handle.off_hand_attack(&target).unwrap();
+        let bonus_actions = CreatureHandle::new(&attacker, &server)
+            .turn_budget()
+            .unwrap()
+            .bonus_actions();
+        assert_eq!(bonus_actions, 0);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        assert!(handle.off_hand_attack(&target).is_err());
+        end_turn(&mut server);
+        // Both weapons must be light.
+        let mut server = simple_server();
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .wield(Some(LONGSWORD))
+            .unwrap()
+            .wield_off_hand(Some(DAGGER))
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new("target", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(2, 0))
+            .unwrap();
+        start_turn_of(&mut server, &attacker);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        handle.attack(&target).unwrap();
+        assert!(handle.off_hand_attack(&target).is_err());
+    }
+
+    fn spawn_wrestlers(server: &mut Server<SRDRules>) {
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(STRENGTH, AbilityScore::new(20).unwrap())
+            .add_skill(ATHLETICS, true)
+            .spawn_at(server, Square::new(2, 0))
+            .unwrap();
+        crate::Character::new("target", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(STRENGTH, AbilityScore::new(3).unwrap())
+            .add_ability(DEXTERITY, AbilityScore::new(3).unwrap())
+            .spawn_at(server, Square::new(4, 0))
+            .unwrap();
+    }
+
+    #[test]
+    fn grapple_drag_and_shove() {
+        let mut server = simple_server();
+        spawn_wrestlers(&mut server);
+        crate::Character::new("far", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(10, 0))
+            .unwrap();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        let mut turns = 0;
+        while !has_condition(&server, &target, ConditionId::Grappled) {
+            turns += 1;
+            assert!(turns < 100, "the target should have been grappled");
+            start_turn_of(&mut server, &attacker);
+            let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+            assert!(handle.grapple(&"far".into()).is_err());
+            handle.grapple(&target).unwrap();
+            // The grapple replaced the only attack of a 1st level fighter.
+            assert!(handle.attack(&target).is_err());
+            end_turn(&mut server);
+        }
+        let conditions = CreatureHandle::new(&target, &server).conditions().unwrap();
+        assert_eq!(
+            conditions.get(ConditionId::Grappled).unwrap().source(),
+            Some(&attacker)
+        );
+        // Grappled creatures can't move.
+        start_turn_of(&mut server, &target);
+        assert!(CreatureHandleMut::new(&target, &mut server)
+            .move_along(vec![Square::new(5, 0)], MovementMode::Walk)
+            .is_err());
+        end_turn(&mut server);
+        // The grappler drags the target at half speed.
+        start_turn_of(&mut server, &attacker);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        assert!(handle.grapple(&target).is_err());
+        handle
+            .move_along(row(vec![1, 0]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(handle.turn_budget().unwrap().movement(), 15);
+        let footprint = |x| Footprint::new(Square::new(x, 0), CreatureSize::Medium);
+        let position =
+            |server: &Server<SRDRules>| *CreatureHandle::new(&target, server).position().unwrap();
+        assert_eq!(position(&server), footprint(2));
+        end_turn(&mut server);
+        // Shoving the target prone, then away from the grappler's reach.
+        let mut turns = 0;
+        while !has_condition(&server, &target, ConditionId::Prone) {
+            turns += 1;
+            assert!(turns < 100, "the target should have been knocked prone");
+            start_turn_of(&mut server, &attacker);
+            CreatureHandleMut::new(&attacker, &mut server)
+                .shove(&target, ShoveEffect::KnockProne)
+                .unwrap();
+            end_turn(&mut server);
+        }
+        let mut turns = 0;
+        while position(&server) == footprint(2) {
+            turns += 1;
+            assert!(turns < 100, "the target should have been pushed");
+            start_turn_of(&mut server, &attacker);
+            CreatureHandleMut::new(&attacker, &mut server)
+                .shove(&target, ShoveEffect::Push)
+                .unwrap();
+            end_turn(&mut server);
+        }
+        assert_eq!(position(&server), footprint(4));
+        assert!(!has_condition(&server, &target, ConditionId::Grappled));
+    }
+
+    #[test]
+    fn escape_grapple() {
+        let mut server = simple_server();
+        spawn_wrestlers(&mut server);
+        let attacker: CharacterId = "attacker".into();
+        start_turn_of(&mut server, &attacker);
+        assert!(matches!(
+            activate(&mut server, ActionId::Escape, None),
+            Some(WeaselError::UserError(_))
+        ));
+        end_turn(&mut server);
+        let potency = ConditionPotency {
+            source: Some("target".into()),
+            ..ConditionPotency::default()
+        };
+        inflict_with(&mut server, &attacker, ConditionId::Grappled, potency);
+        let mut turns = 0;
+        while has_condition(&server, &attacker, ConditionId::Grappled) {
+            turns += 1;
+            assert!(turns < 100, "the grapple should have been escaped");
+            start_turn_of(&mut server, &attacker);
+            assert_eq!(activate(&mut server, ActionId::Escape, None), None);
+            // Escaping takes the creature's action.
+            assert_eq!(
+                activate(&mut server, ActionId::Escape, None),
+                Some(WeaselError::UserError(
+                    SRDError::CostNotAffordable(ActionCost::Action).to_string()
+                ))
+            );
+            end_turn(&mut server);
+        }
+    }
+
+    fn tactics(server: &Server<SRDRules>, id: &CharacterId) -> Tactics {
+        CreatureHandle::new(id, server).tactics().unwrap().clone()
+    }
+
+    #[test]
+    fn dodge_and_help() {
+        let mut server = simple_server();
+        spawn_side_by_side(&mut server);
+        crate::Character::new("helper", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(4, 0))
+            .unwrap();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        let helper: CharacterId = "helper".into();
+        start_turn_of(&mut server, &target);
+        CreatureHandleMut::new(&target, &mut server)
+            .dodge()
+            .unwrap();
+        assert!(tactics(&server, &target).dodging());
+        end_turn(&mut server);
+        start_turn_of(&mut server, &helper);
+        let mut handle = CreatureHandleMut::new(&helper, &mut server);
+        assert!(handle
+            .help(Help {
+                ally: helper.clone(),
+                target: None
+            })
+            .is_err());
+        // Only allies can be helped.
+        assert!(handle
+            .help(Help {
+                ally: target.clone(),
+                target: None
+            })
+            .is_err());
+        // The target must be within 5 feet of the helper.
+        assert!(handle
+            .help(Help {
+                ally: target.clone(),
+                target: Some(attacker.clone())
+            })
+            .is_err());
+        handle
+            .help(Help {
+                ally: attacker.clone(),
+                target: Some(target.clone()),
+            })
+            .unwrap();
+        end_turn(&mut server);
+        start_turn_of(&mut server, &attacker);
+        CreatureHandleMut::new(&attacker, &mut server)
+            .attack(&target)
+            .unwrap();
+        assert_eq!(tactics(&server, &helper).help(), None);
+        end_turn(&mut server);
+        // Dodging lasts until the start of the creature's next turn.
+        assert!(tactics(&server, &target).dodging());
+        start_turn_of(&mut server, &target);
+        assert!(!tactics(&server, &target).dodging());
+        end_turn(&mut server);
+        // Help with an ability check, such as the one to grapple.
+        start_turn_of(&mut server, &helper);
+        CreatureHandleMut::new(&helper, &mut server)
+            .help(Help {
+                ally: attacker.clone(),
+                target: None,
+            })
+            .unwrap();
+        end_turn(&mut server);
+        start_turn_of(&mut server, &attacker);
+        CreatureHandleMut::new(&attacker, &mut server)
+            .grapple(&target)
+            .unwrap();
+        assert_eq!(tactics(&server, &helper).help(), None);
+    }
+
+    #[test]
+    fn hide_and_search() {
+        let mut server = simple_server();
+        ResetSpace::trigger(&mut server)
+            .seed(GridSeed {
+                width: 20,
+                height: 8,
+                walls: (0..4).map(|y| Square::new(6, y)).collect(),
+                ..GridSeed::default()
+            })
+            .fire()
+            .unwrap();
+        let hider: CharacterId = "attacker".into();
+        let seeker: CharacterId = "target".into();
+        crate::Character::new(hider.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(DEXTERITY, AbilityScore::new(20).unwrap())
+            .add_skill(STEALTH, true)
+            .spawn_at(&mut server, Square::new(10, 0))
+            .unwrap();
+        crate::Character::new(seeker.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(WISDOM, AbilityScore::new(3).unwrap())
+            .set_team(1)
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        // Allies in plain sight don't prevent hiding.
+        crate::Character::new("ally", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(14, 0))
+            .unwrap();
+        assert_eq!(
+            CreatureHandle::new(&hider, &server).cover_of(&seeker),
+            Ok(Cover::Total)
+        );
+        // Stealth +7 always beats a passive Perception of 6.
+        start_turn_of(&mut server, &hider);
+        CreatureHandleMut::new(&hider, &mut server).hide().unwrap();
+        assert!(tactics(&server, &hider).stealth().unwrap() >= 8);
+        end_turn(&mut server);
+        start_turn_of(&mut server, &seeker);
+        let mut handle = CreatureHandleMut::new(&seeker, &mut server);
+        assert!(handle.search(STEALTH).is_err());
+        // The wall blocks the view of the hidden creature.
+        handle.search(PERCEPTION).unwrap();
+        assert!(tactics(&server, &hider).hidden());
+        end_turn(&mut server);
+        AlterStatistics::trigger(
+            &mut server,
+            EntityId::Creature(hider.clone()),
+            StatisticChange::Hide(10),
+        )
+        .fire()
+        .unwrap();
+        MoveEntity::trigger(
+            &mut server,
+            EntityId::Creature(seeker.clone()),
+            Footprint::new(Square::new(10, 2), CreatureSize::Medium),
+        )
+        .fire()
+        .unwrap();
+        let mut turns = 0;
+        while tactics(&server, &hider).hidden() {
+            turns += 1;
+            assert!(turns < 100);
+            start_turn_of(&mut server, &seeker);
+            CreatureHandleMut::new(&seeker, &mut server)
+                .search(PERCEPTION)
+                .unwrap();
+            end_turn(&mut server);
+        }
+        // Creatures can't hide in plain sight and give away their location when attacking.
+        start_turn_of(&mut server, &hider);
+        let mut handle = CreatureHandleMut::new(&hider, &mut server);
+        assert!(handle.hide().is_err());
+        AlterStatistics::trigger(
+            &mut server,
+            EntityId::Creature(hider.clone()),
+            StatisticChange::Hide(30),
+        )
+        .fire()
+        .unwrap();
+        CreatureHandleMut::new(&hider, &mut server)
+            .attack(&seeker)
+            .unwrap();
+        assert!(!tactics(&server, &hider).hidden());
+    }
+
+    #[test]
+    fn readied_attacks() {
+        let mut server = simple_server();
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        crate::Character::new("target", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(CONSTITUTION, AbilityScore::new(20).unwrap())
+            .spawn_at(&mut server, Square::new(6, 0))
+            .unwrap();
+        let attacker: CharacterId = "attacker".into();
+        let target: CharacterId = "target".into();
+        start_turn_of(&mut server, &attacker);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        assert!(handle
+            .ready(ReadyTrigger::EntersReach {
+                creature: Some("nobody".into())
+            })
+            .is_err());
+        handle
+            .ready(ReadyTrigger::EntersReach {
+                creature: Some(target.clone()),
+            })
+            .unwrap();
+        end_turn(&mut server);
+        // The attack is made as soon as the target enters the reach.
+        start_turn_of(&mut server, &target);
+        CreatureHandleMut::new(&target, &mut server)
+            .move_along(row(vec![5, 4, 3]), MovementMode::Walk)
+            .unwrap();
+        assert_eq!(reactions(&server, &attacker), 0);
+        assert_eq!(tactics(&server, &attacker).readied(), None);
+        end_turn(&mut server);
+        // Readied attacks are lost at the start of the creature's next turn.
+        start_turn_of(&mut server, &attacker);
+        CreatureHandleMut::new(&attacker, &mut server)
+            .ready(ReadyTrigger::EntersReach { creature: None })
+            .unwrap();
+        assert!(tactics(&server, &attacker).readied().is_some());
+        end_turn(&mut server);
+        start_turn_of(&mut server, &attacker);
+        assert_eq!(tactics(&server, &attacker).readied(), None);
+    }
+
+    #[test]
+    fn use_object_swaps_weapons() {
+        let mut server = simple_server();
+        let attacker: CharacterId = "attacker".into();
+        crate::Character::new(attacker.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .wield(Some(SHORTSWORD))
+            .unwrap()
+            .spawn_at(&mut server, Square::new(0, 0))
+            .unwrap();
+        let has_off_hand_attack = |server: &Server<SRDRules>| {
+            server
+                .battle()
+                .entities()
+                .creature(&attacker)
+                .unwrap()
+                .ability(&ActionId::OffHandAttack)
+                .is_some()
+        };
+        assert!(!has_off_hand_attack(&server));
+        start_turn_of(&mut server, &attacker);
+        let mut handle = CreatureHandleMut::new(&attacker, &mut server);
+        // Two-handed weapons need both hands.
+        assert!(handle
+            .use_object(ObjectInteraction::Wield {
+                main_hand: Some(GREATAXE),
+                off_hand: Some(DAGGER),
+            })
+            .is_err());
+        handle
+            .use_object(ObjectInteraction::Wield {
+                main_hand: Some(SHORTSWORD),
+                off_hand: Some(DAGGER),
+            })
+            .unwrap();
+        assert!(has_off_hand_attack(&server));
+        // The second interaction takes an action.
+        CreatureHandleMut::new(&attacker, &mut server)
+            .use_object(ObjectInteraction::Wield {
+                main_hand: Some(GREATAXE),
+                off_hand: None,
+            })
+            .unwrap();
+        assert!(!has_off_hand_attack(&server));
+        let handle = CreatureHandle::new(&attacker, &server);
+        assert_eq!(handle.turn_budget().unwrap().actions(), 0);
+        assert_eq!(handle.weapon_slots().unwrap().main_hand, Some(GREATAXE));
+        let expected = crate::Character::new("other", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .wield(Some(GREATAXE))
+            .unwrap()
+            .attack_profile()
+            .unwrap();
+        let creature = server.battle().entities().creature(&attacker).unwrap();
+        assert_eq!(attack_profile(creature), Some(&expected));
+        assert!(CreatureHandleMut::new(&attacker, &mut server)
+            .use_object(ObjectInteraction::Wield {
+                main_hand: None,
+                off_hand: None,
+            })
+            .is_err());
     }
 }
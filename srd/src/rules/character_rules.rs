@@ -1,11 +1,19 @@
 //! Implementation of rules for characters.
 
 use crate::character::CharacterId;
-use crate::rules::core::statistic::{Statistic, StatisticChange, StatisticsSeed};
+use crate::equipment::WeaponSlots;
+use crate::hit_points::HitPoints;
+use crate::rules::core::condition::{
+    Condition, ConditionId, ConditionPotency, Conditions, ConditionsAlteration,
+};
+use crate::rules::core::death::{DeathSavingThrows, LifeState};
+use crate::rules::core::statistic::{Statistic, StatisticChange, StatisticId, StatisticsSeed};
 use crate::rules::narrator::Narrator;
 use crate::rules::SRDRules;
+use std::convert::TryFrom;
 use std::sync::Arc;
-use weasel::{Character, CharacterRules, Entropy, Transmutation, WriteMetrics};
+use weasel::status::AppliedStatus;
+use weasel::{Character, CharacterRules, Entropy, Id, Transmutation, WriteMetrics};
 
 /// Rules for representing and evolving characters.\
 /// Character in weasel has a broader definition since it includes objects as well.
@@ -27,8 +35,8 @@ impl CharacterRules<SRDRules> for SRDCharacterRules {
     type Statistic = Statistic;
     type StatisticsSeed = StatisticsSeed;
     type StatisticsAlteration = StatisticChange;
-    type Status = weasel::rules::empty::EmptyStatus; // TODO not sure if we need this
-    type StatusesAlteration = (); // TODO not sure if we need this
+    type Status = Condition;
+    type StatusesAlteration = ConditionsAlteration;
 
     fn generate_statistics(
         &self,
@@ -47,13 +55,468 @@ impl CharacterRules<SRDRules> for SRDCharacterRules {
         }
     }
 
+    fn generate_status(
+        &self,
+        character: &dyn Character<SRDRules>,
+        status_id: &ConditionId,
+        potency: &Option<ConditionPotency>,
+        _entropy: &mut Entropy<SRDRules>,
+        _metrics: &mut WriteMetrics<SRDRules>,
+    ) -> Option<Condition> {
+        let potency = potency.clone().unwrap_or_default();
+        let mut condition = Condition::new(*status_id, potency.clone());
+        // Levels of exhaustion add up.
+        if let Some(existing) = character.status(status_id) {
+            if *status_id == ConditionId::Exhaustion {
+                condition = existing.status().clone();
+                condition.change_level(i8::try_from(potency.levels).unwrap_or(i8::MAX));
+            }
+        }
+        Some(condition)
+    }
+
+    fn alter_statuses(
+        &self,
+        character: &mut dyn Character<SRDRules>,
+        alteration: &Self::StatusesAlteration,
+        _entropy: &mut Entropy<SRDRules>,
+        _metrics: &mut WriteMetrics<SRDRules>,
+    ) {
+        match alteration {
+            ConditionsAlteration::Apply(condition) => {
+                character.add_status(AppliedStatus::new(condition.clone()));
+            }
+            ConditionsAlteration::Remove(id) => {
+                character.remove_status(id);
+            }
+            ConditionsAlteration::RemoveFrom(source) => {
+                let ids: Vec<_> = character
+                    .statuses()
+                    .filter(|status| status.source() == Some(source))
+                    .map(|status| *status.id())
+                    .collect();
+                for id in ids {
+                    character.remove_status(&id);
+                }
+            }
+            ConditionsAlteration::Exhaustion(change) => {
+                if let Some(status) = character.status_mut(&ConditionId::Exhaustion) {
+                    status.change_level(*change);
+                    if status.level() == 0 {
+                        character.remove_status(&ConditionId::Exhaustion);
+                    }
+                } else if *change > 0 {
+                    let mut condition = Condition::new(
+                        ConditionId::Exhaustion,
+                        ConditionPotency {
+                            levels: 0,
+                            ..ConditionPotency::default()
+                        },
+                    );
+                    condition.change_level(*change);
+                    character.add_status(AppliedStatus::new(condition));
+                }
+            }
+        }
+        clamp_hit_points(character);
+    }
+
     fn alter_statistics(
         &self,
-        _character: &mut dyn Character<SRDRules>,
-        _alteration: &Self::StatisticsAlteration,
+        character: &mut dyn Character<SRDRules>,
+        alteration: &Self::StatisticsAlteration,
         _entropy: &mut Entropy<SRDRules>,
         _metrics: &mut WriteMetrics<SRDRules>,
     ) -> Option<Transmutation> {
-        unimplemented!()
+        let before = life_state(character);
+        match alteration {
+            StatisticChange::Damage { amount, critical } => {
+                take_damage(character, *amount, *critical)
+            }
+            alteration => {
+                apply_change(character, alteration);
+                // A natural 20 on a death saving throw brings the creature back with 1 hit point.
+                if let StatisticChange::DeathSavingThrow(_) = alteration {
+                    if life_state(character).is_some_and(|state| state.is_conscious()) {
+                        apply_change(character, &StatisticChange::HitPoints(1));
+                    }
+                }
+            }
+        }
+        clamp_hit_points(character);
+        update_life_state(character, before);
+        let dead = match life_state(character) {
+            Some(state) => state.is_dead(),
+            // Creatures without a life state die as soon as they drop to 0 hit points.
+            None => hit_points(character).is_some_and(|hp| hp.value() == 0),
+        };
+        if dead {
+            log::debug!("{:?} is dead", character.entity_id());
+            Some(Transmutation::REMOVAL)
+        } else {
+            None
+        }
+    }
+}
+
+/// Applies `change` to the character's statistic it targets.
+fn apply_change(character: &mut dyn Character<SRDRules>, change: &StatisticChange) {
+    if let Some(statistic) = character.statistic_mut(&change.statistic_id()) {
+        statistic.apply_change(change);
+    } else {
+        log::warn!(
+            "ignoring change ({}) because the character doesn't have the statistic",
+            change
+        );
+    }
+}
+
+/// Returns the character's hit points, with the hit point maximum lowered by its conditions.
+fn hit_points(character: &dyn Character<SRDRules>) -> Option<HitPoints> {
+    character
+        .statistic(&StatisticId::HitPoints)
+        .and_then(|statistic| statistic.hit_points().ok())
+        .map(|hit_points| Conditions::of(character).hit_points(hit_points))
+}
+
+/// Prevents the character's current hit points from exceeding its hit point maximum, once
+/// lowered by conditions.
+fn clamp_hit_points(character: &mut dyn Character<SRDRules>) {
+    if let Some(value) = hit_points(character).map(|hit_points| hit_points.value()) {
+        if let Some(hit_points) = character
+            .statistic_mut(&StatisticId::HitPoints)
+            .and_then(|statistic| statistic.hit_points_mut().ok())
+        {
+            hit_points.set_value(value);
+        }
+    }
+}
+
+fn life_state(character: &dyn Character<SRDRules>) -> Option<LifeState> {
+    character
+        .statistic(&StatisticId::LifeState)
+        .and_then(|statistic| statistic.life_state().ok().copied())
+}
+
+fn set_life_state<F: FnOnce(&mut LifeState)>(character: &mut dyn Character<SRDRules>, f: F) {
+    if let Some(state) = character
+        .statistic_mut(&StatisticId::LifeState)
+        .and_then(|statistic| statistic.life_state_mut().ok())
+    {
+        f(state);
+    }
+}
+
+/// Inflicts damage to a character.
+///
+/// # Reference
+///
+/// When damage reduces you to 0 hit points and there is damage remaining, you die if the
+/// remaining damage equals or exceeds your hit point maximum.\
+/// If you take any damage while you have 0 hit points, you suffer a death saving throw failure.
+/// If the damage is from a critical hit, you suffer two failures instead. If the damage equals
+/// or exceeds your hit point maximum, you suffer instant death.
+fn take_damage(character: &mut dyn Character<SRDRules>, amount: u16, critical: bool) {
+    let mut damage = amount;
+    // Temporary hit points absorb damage first.
+    if let Some(temporary) = character.statistic_mut(&StatisticId::TemporaryHitPoints) {
+        damage = temporary.absorb_damage(damage);
+    }
+    if damage == 0 {
+        return;
+    }
+    let hp = if let Some(hp) = hit_points(character) {
+        hp
+    } else {
+        log::warn!("ignoring damage because the character doesn't have hit points");
+        return;
+    };
+    if hp.value() > 0 {
+        apply_change(
+            character,
+            &StatisticChange::Damage {
+                amount: damage,
+                critical,
+            },
+        );
+        if damage >= hp.value() && damage - hp.value() >= hp.max() {
+            set_life_state(character, |state| *state = LifeState::Dead);
+        }
+    } else if damage >= hp.max() {
+        set_life_state(character, |state| *state = LifeState::Dead);
+    } else {
+        set_life_state(character, |state| state.damage_at_zero(critical));
+    }
+}
+
+/// Keeps the character's life state consistent with its hit points, given the state `before`
+/// the latest change.
+///
+/// # Reference
+///
+/// If damage reduces you to 0 hit points and fails to kill you, you fall unconscious. This
+/// unconsciousness ends if you regain any hit points.\
+/// Unconscious: the creature drops whatever it's holding and falls prone.\
+/// Shields are worn rather than held, so they stay donned.
+fn update_life_state(character: &mut dyn Character<SRDRules>, before: Option<LifeState>) {
+    let (hp, before) = match (hit_points(character), before) {
+        (Some(hp), Some(state)) => (hp, state),
+        _ => return,
+    };
+    set_life_state(character, |state| {
+        if hp.value() > 0 && !state.is_dead() {
+            *state = LifeState::Conscious;
+        } else if hp.value() == 0 && state.is_conscious() {
+            // The creature falls unconscious.
+            *state = LifeState::Dying(DeathSavingThrows::default());
+        }
+    });
+    let after = life_state(character).unwrap_or(before);
+    if before.is_conscious() && !after.is_conscious() && !after.is_dead() {
+        for id in [ConditionId::Unconscious, ConditionId::Prone] {
+            character.add_status(AppliedStatus::new(Condition::new(
+                id,
+                ConditionPotency::default(),
+            )));
+        }
+        let slots = character
+            .statistic(&StatisticId::WeaponSlots)
+            .and_then(|statistic| statistic.weapon_slots().ok().copied());
+        if let Some(slots) = slots {
+            let dropped = WeaponSlots {
+                main_hand: None,
+                off_hand: None,
+                ..slots
+            };
+            apply_change(character, &StatisticChange::Wield(dropped));
+        }
+    } else if !before.is_conscious() && after.is_conscious() {
+        character.remove_status(&ConditionId::Unconscious);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::character::class::FIGHTER;
+    use crate::character::race::HILL_DWARF;
+    use crate::character::CharacterId;
+    use crate::equipment::weapon::LONGSWORD;
+    use crate::equipment::WeaponSlots;
+    use crate::handle::creature_handle::{CreatureHandle, CreatureHandleMut};
+    use crate::hit_points::TemporaryHitPoints;
+    use crate::rules::core::condition::{ConditionId, ConditionsAlteration};
+    use crate::rules::core::statistic::StatisticChange;
+    use crate::rules::core::{DeathSavingThrows, LifeState};
+    use crate::rules::SRDRules;
+    use crate::util::simple_server;
+    use weasel::{
+        AlterStatistics, AlterStatuses, BattleController, EntityId, EventTrigger, Server,
+    };
+
+    #[test]
+    fn temporary_hit_points_absorb_damage() {
+        let mut server = simple_server();
+        let id: CharacterId = "one".into();
+        crate::Character::new(id.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn(&mut server)
+            .unwrap();
+        let mut alter = |change| {
+            AlterStatistics::trigger(&mut server, EntityId::Creature(id.clone()), change)
+                .fire()
+                .unwrap();
+        };
+        alter(StatisticChange::TemporaryHitPoints(
+            TemporaryHitPoints::new(5),
+        ));
+        alter(StatisticChange::TemporaryHitPoints(
+            TemporaryHitPoints::new(3),
+        ));
+        alter(StatisticChange::Damage {
+            amount: 7,
+            critical: false,
+        });
+        let handle = CreatureHandle::new(&id, &server);
+        assert_eq!(handle.temporary_hit_points().unwrap().value(), 0);
+        assert_eq!(handle.current_hit_points().unwrap(), 9);
+        assert_eq!(handle.max_hit_points().unwrap(), 11);
+    }
+
+    fn alter(server: &mut Server<SRDRules>, id: &str, change: StatisticChange) {
+        AlterStatistics::trigger(server, EntityId::Creature(id.into()), change)
+            .fire()
+            .unwrap();
+    }
+
+    fn damage(amount: u16, critical: bool) -> StatisticChange {
+        StatisticChange::Damage { amount, critical }
+    }
+
+    #[test]
+    fn dropping_to_zero_hit_points() {
+        let mut server = simple_server();
+        for id in &["one", "two"] {
+            crate::Character::new(*id, HILL_DWARF, FIGHTER)
+                .unwrap()
+                .wield(Some(LONGSWORD))
+                .unwrap()
+                .equip_shield(true)
+                .spawn(&mut server)
+                .unwrap();
+        }
+        // Falling unconscious.
+        alter(&mut server, "one", damage(15, false));
+        alter(&mut server, "one", StatisticChange::DeathSavingThrow(12));
+        alter(&mut server, "one", damage(3, true));
+        // Instant death.
+        alter(&mut server, "two", damage(22, false));
+        let one: CharacterId = "one".into();
+        let handle = CreatureHandle::new(&one, &server);
+        assert_eq!(handle.current_hit_points().unwrap(), 0);
+        assert_eq!(
+            handle.life_state().unwrap(),
+            &LifeState::Dying(DeathSavingThrows {
+                successes: 1,
+                failures: 2
+            })
+        );
+        assert!(server.battle().entities().creature(&"two".into()).is_none());
+        let conditions = handle.conditions().unwrap();
+        assert!(conditions.has(ConditionId::Unconscious));
+        assert!(conditions.has(ConditionId::Prone));
+        // Weapons are dropped, while the shield stays donned.
+        assert_eq!(
+            handle.weapon_slots().unwrap(),
+            &WeaponSlots {
+                main_hand: None,
+                off_hand: None,
+                shield: true
+            }
+        );
+        // Stabilized creatures remain unconscious until healed.
+        alter(&mut server, "one", StatisticChange::Stabilize);
+        assert_eq!(
+            CreatureHandle::new(&one, &server).life_state().unwrap(),
+            &LifeState::Stable
+        );
+        // Damage at 0 hit points puts a stable creature back to dying.
+        alter(&mut server, "one", damage(1, true));
+        assert!(CreatureHandle::new(&one, &server)
+            .life_state()
+            .unwrap()
+            .is_dying());
+        alter(&mut server, "one", damage(1, false));
+        assert!(server.battle().entities().creature(&one).is_none());
+    }
+
+    #[test]
+    fn exhaustion_halves_hit_point_maximum() {
+        let mut server = simple_server();
+        let id: CharacterId = "one".into();
+        crate::Character::new(id.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn(&mut server)
+            .unwrap();
+        let exhaustion = |server: &mut Server<SRDRules>, change| {
+            AlterStatuses::trigger(
+                server,
+                EntityId::Creature(id.clone()),
+                ConditionsAlteration::Exhaustion(change),
+            )
+            .fire()
+            .unwrap();
+        };
+        exhaustion(&mut server, 3);
+        assert_eq!(
+            CreatureHandle::new(&id, &server).max_hit_points().unwrap(),
+            11
+        );
+        // The current hit points drop to the new maximum, and healing can't exceed it.
+        exhaustion(&mut server, 1);
+        alter(&mut server, "one", StatisticChange::HitPoints(5));
+        let handle = CreatureHandle::new(&id, &server);
+        assert_eq!(handle.max_hit_points().unwrap(), 5);
+        assert_eq!(handle.current_hit_points().unwrap(), 5);
+        // Recovering from exhaustion restores the maximum, but not the hit points.
+        exhaustion(&mut server, -1);
+        let handle = CreatureHandle::new(&id, &server);
+        assert_eq!(handle.max_hit_points().unwrap(), 11);
+        assert_eq!(handle.current_hit_points().unwrap(), 5);
+    }
+
+    #[test]
+    fn long_rest() {
+        let mut server = simple_server();
+        let id: CharacterId = "one".into();
+        crate::Character::new(id.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn(&mut server)
+            .unwrap();
+        AlterStatuses::trigger(
+            &mut server,
+            EntityId::Creature(id.clone()),
+            ConditionsAlteration::Exhaustion(4),
+        )
+        .fire()
+        .unwrap();
+        alter(&mut server, "one", damage(3, false));
+        alter(
+            &mut server,
+            "one",
+            StatisticChange::TemporaryHitPoints(TemporaryHitPoints::new(4)),
+        );
+        // Temporary hit points vanish, then hit points are regained up to the restored maximum.
+        CreatureHandleMut::new(&id, &mut server)
+            .long_rest()
+            .unwrap();
+        let handle = CreatureHandle::new(&id, &server);
+        assert_eq!(handle.temporary_hit_points().unwrap().value(), 0);
+        assert_eq!(
+            handle
+                .conditions()
+                .unwrap()
+                .get(ConditionId::Exhaustion)
+                .unwrap()
+                .level(),
+            3
+        );
+        assert_eq!(handle.current_hit_points().unwrap(), 11);
+        // Creatures with 0 hit points don't benefit from a long rest.
+        alter(&mut server, "one", damage(11, false));
+        assert!(CreatureHandleMut::new(&id, &mut server)
+            .long_rest()
+            .is_err());
+        assert_eq!(
+            CreatureHandle::new(&id, &server)
+                .current_hit_points()
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn regaining_hit_points_ends_unconsciousness() {
+        let mut server = simple_server();
+        for id in &["one", "two"] {
+            crate::Character::new(*id, HILL_DWARF, FIGHTER)
+                .unwrap()
+                .spawn(&mut server)
+                .unwrap();
+            alter(&mut server, id, damage(15, false));
+        }
+        // Healing wakes the creature up, but it remains prone.
+        alter(&mut server, "one", StatisticChange::HitPoints(2));
+        let one: CharacterId = "one".into();
+        let handle = CreatureHandle::new(&one, &server);
+        assert!(handle.life_state().unwrap().is_conscious());
+        let conditions = handle.conditions().unwrap();
+        assert!(!conditions.has(ConditionId::Unconscious));
+        assert!(conditions.has(ConditionId::Prone));
+        // So does a natural 20 on a death saving throw.
+        alter(&mut server, "two", StatisticChange::DeathSavingThrow(20));
+        let two: CharacterId = "two".into();
+        let handle = CreatureHandle::new(&two, &server);
+        assert_eq!(handle.current_hit_points().unwrap(), 1);
+        assert!(!handle.conditions().unwrap().has(ConditionId::Unconscious));
     }
 }
@@ -1,6 +1,15 @@
 //! Actions of creatures.
 
+use crate::character::CharacterId;
+use crate::equipment::WeaponId;
 use crate::error::{SRDError, SRDResult};
+use crate::rules::core::attack::AttackProfile;
+use crate::rules::core::battlegrid::Square;
+use crate::rules::core::economy::ActionCost;
+use crate::rules::core::grapple::ShoveEffect;
+use crate::rules::core::movement::MovementMode;
+use crate::rules::core::tactics::{Help, ReadyTrigger};
+use crate::skill::SkillId;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
@@ -10,12 +19,18 @@ use std::fmt::Display;
 #[derive(Debug, Clone)]
 pub struct Action {
     id: ActionId,
+    cost: ActionCost,
     value: ActionValue,
 }
 
 impl Action {
-    const fn new(id: ActionId, value: ActionValue) -> Self {
-        Self { id, value }
+    const fn new(id: ActionId, cost: ActionCost, value: ActionValue) -> Self {
+        Self { id, cost, value }
+    }
+
+    /// Returns what the creature spends to take this action.
+    pub const fn cost(&self) -> ActionCost {
+        self.cost
     }
 
     /// Returns a reference to `()` if this action is of the correct type, otherwise an error.
@@ -27,10 +42,10 @@ impl Action {
         }
     }
 
-    /// Returns a reference to `()` if this action is of the correct type, otherwise an error.
-    pub const fn attack(&self) -> SRDResult<()> {
-        if let ActionValue::Attack = &self.value {
-            Ok(())
+    /// Returns a reference to `AttackProfile` if this action is of the correct type, otherwise an error.
+    pub const fn attack(&self) -> SRDResult<&AttackProfile> {
+        if let ActionValue::Attack(profile) = &self.value {
+            Ok(profile)
         } else {
             Err(SRDError::IncorrectVariant)
         }
@@ -41,8 +56,50 @@ impl From<ActionInitializer> for Action {
     fn from(item: ActionInitializer) -> Self {
         use ActionInitializer::*;
         match item {
-            Movement => Self::new(ActionId::Movement, ActionValue::Movement),
-            Attack => Self::new(ActionId::Attack, ActionValue::Attack),
+            Movement => Self::new(
+                ActionId::Movement,
+                ActionCost::Movement,
+                ActionValue::Movement,
+            ),
+            Attack(profile) => Self::new(
+                ActionId::Attack,
+                ActionCost::Action,
+                ActionValue::Attack(profile),
+            ),
+            Stabilize => Self::new(
+                ActionId::Stabilize,
+                ActionCost::Action,
+                ActionValue::Stabilize,
+            ),
+            Dash => Self::new(ActionId::Dash, ActionCost::Action, ActionValue::Dash),
+            Disengage => Self::new(
+                ActionId::Disengage,
+                ActionCost::Action,
+                ActionValue::Disengage,
+            ),
+            StandUp => Self::new(
+                ActionId::StandUp,
+                ActionCost::Movement,
+                ActionValue::StandUp,
+            ),
+            Grapple => Self::new(ActionId::Grapple, ActionCost::Action, ActionValue::Grapple),
+            Shove => Self::new(ActionId::Shove, ActionCost::Action, ActionValue::Shove),
+            Escape => Self::new(ActionId::Escape, ActionCost::Action, ActionValue::Escape),
+            OffHandAttack(profile) => Self::new(
+                ActionId::OffHandAttack,
+                ActionCost::BonusAction,
+                ActionValue::Attack(profile),
+            ),
+            Dodge => Self::new(ActionId::Dodge, ActionCost::Action, ActionValue::Dodge),
+            Help => Self::new(ActionId::Help, ActionCost::Action, ActionValue::Help),
+            Hide => Self::new(ActionId::Hide, ActionCost::Action, ActionValue::Hide),
+            Ready => Self::new(ActionId::Ready, ActionCost::Action, ActionValue::Ready),
+            Search => Self::new(ActionId::Search, ActionCost::Action, ActionValue::Search),
+            UseObject => Self::new(
+                ActionId::UseObject,
+                ActionCost::ObjectInteraction,
+                ActionValue::UseObject,
+            ),
         }
     }
 }
@@ -61,21 +118,129 @@ impl weasel::Id for Action {
 pub enum ActionId {
     Movement,
     Attack,
+    Stabilize,
+    Dash,
+    Disengage,
+    StandUp,
+    Grapple,
+    Shove,
+    Escape,
+    OffHandAttack,
+    Dodge,
+    Help,
+    Hide,
+    Ready,
+    Search,
+    UseObject,
 }
 
 /// Encapsulates the actual value of an actions.
 #[derive(Debug, Clone, DisplayVariant)]
 enum ActionValue {
-    Movement, // TODO add a meaningful value (ft per turn)
-    Attack,   // TODO add a meaningful value (?)
+    Movement,
+    Attack(AttackProfile),
+    Stabilize,
+    Dash,
+    Disengage,
+    StandUp,
+    Grapple,
+    Shove,
+    Escape,
+    Dodge,
+    Help,
+    Hide,
+    Ready,
+    Search,
+    UseObject,
+}
+
+/// Parameters chosen by a creature when it activates an action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Activation {
+    /// Move across the battle grid.
+    Movement {
+        /// The successive positions of the creature, each adjacent to the previous one.
+        path: Vec<Square>,
+        /// How the creature moves.
+        mode: MovementMode,
+    },
+    /// Attack a creature, with the Attack action or with the weapon in the off hand.
+    Attack {
+        /// The creature to attack.
+        target: CharacterId,
+    },
+    /// Administer first aid to a dying creature.
+    Stabilize {
+        /// The creature to stabilize.
+        target: CharacterId,
+    },
+    /// Seize a creature, replacing one attack.
+    Grapple {
+        /// The creature to grapple.
+        target: CharacterId,
+    },
+    /// Shove a creature, replacing one attack.
+    Shove {
+        /// The creature to shove.
+        target: CharacterId,
+        /// Whether to knock the target prone or to push it away.
+        effect: ShoveEffect,
+    },
+    /// Lend aid to an ally.
+    Help(Help),
+    /// Wait for a circumstance to attack the creature causing it, using the reaction.
+    Ready {
+        /// The circumstance triggering the attack.
+        trigger: ReadyTrigger,
+    },
+    /// Look for hidden creatures or for clues.
+    Search {
+        /// The skill used to search, either Perception or Investigation.
+        skill: SkillId,
+    },
+    /// Interact with an object.
+    UseObject(ObjectInteraction),
+}
+
+/// An interaction of a creature with an object.
+///
+/// # Reference
+///
+/// You normally interact with an object while doing something else, such as when you draw your
+/// sword as part of an attack. When an object requires your action for its use, you take the Use
+/// an Object action. This action is also useful when you want to interact with more than one
+/// object on your turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectInteraction {
+    /// Draw or stow weapons, ending up with the given ones in hand.
+    Wield {
+        /// The weapon to hold in the main hand, if any.
+        main_hand: Option<WeaponId>,
+        /// The weapon to hold in the off hand, if any.
+        off_hand: Option<WeaponId>,
+    },
 }
 
 /// Initializer to create an action.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub enum ActionInitializer {
     Movement,
-    Attack,
+    Attack(AttackProfile),
+    Stabilize,
+    Dash,
+    Disengage,
+    StandUp,
+    Grapple,
+    Shove,
+    Escape,
+    OffHandAttack(AttackProfile),
+    Dodge,
+    Help,
+    Hide,
+    Ready,
+    Search,
+    UseObject,
 }
 
 /// `ActionsSeed` is used to generate all actions of an actor.
@@ -85,6 +250,15 @@ pub struct ActionsSeed {
     pub actions: Vec<ActionInitializer>,
 }
 
+/// Changes to the actions of a creature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ActionsAlteration {
+    /// Adds an action, replacing any existing action with the same id.
+    Add(ActionInitializer),
+    /// Removes an action.
+    Remove(ActionId),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,5 +268,6 @@ mod tests {
         let action: Action = ActionInitializer::Movement.into();
         assert!(action.movement().is_ok());
         assert!(action.attack().is_err());
+        assert_eq!(action.cost(), ActionCost::Movement);
     }
 }
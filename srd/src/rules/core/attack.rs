@@ -0,0 +1,896 @@
+//! Attacks and their resolution.
+
+use crate::ability::{AbilityId, DEXTERITY, STRENGTH};
+use crate::character::{CharacterId, Feat};
+use crate::compendium::compendium;
+use crate::damage::{Damage, DamageResult, DamageType};
+use crate::dice::{D20Test, D20TestResult, Dice, DicePool, DiceRolls, Die, Roller};
+use crate::equipment::{
+    ArmorClass, Weapon, WeaponId, WeaponKind, WeaponProperty, WeaponRange, WeaponSlots,
+};
+use crate::error::{SRDError, SRDResult};
+use crate::rules::core::condition::{ConditionId, Conditions};
+use crate::rules::core::constants::DEFAULT_REACH_FT;
+use crate::rules::core::cover::Cover;
+use crate::rules::core::movement::squares_to_ft;
+use crate::rules::core::size::CreatureSize;
+use crate::rules::core::statistic::StatisticId;
+use crate::rules::core::tactics::{is_dodging, is_hidden};
+use crate::rules::SRDRules;
+use serde::{Deserialize, Serialize};
+use weasel::Character;
+
+/// Describes how a creature attacks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttackProfile {
+    /// The ability whose modifier is added to the attack and damage rolls.
+    pub ability: AbilityId,
+    /// Whether or not the proficiency bonus is added to the attack roll.
+    pub proficient: bool,
+    /// The damage dealt on a hit, one entry per damage type.\
+    /// The ability modifier is added to the first entry.
+    pub damage: Vec<Damage>,
+    /// How far, in feet, the attack can reach.
+    pub reach_ft: u16,
+    /// Whether the better of the Strength and Dexterity modifiers is used instead of `ability`.
+    pub finesse: bool,
+    /// Whether the attack is made with a heavy weapon.
+    pub heavy: bool,
+    /// Whether the attack is made with a melee or a ranged weapon.
+    pub kind: WeaponKind,
+    /// The range of ranged attacks, if the weapon can be fired or thrown.
+    pub range: Option<WeaponRange>,
+    /// Whether each attack expends one piece of ammunition.
+    pub ammunition: bool,
+    /// How many attacks can be made with a single Attack action.
+    pub attacks: u8,
+    /// The weapon used to attack, if any.
+    pub weapon: Option<WeaponId>,
+    /// Whether a positive ability modifier is added to the damage. A negative one always is.
+    pub ability_damage: bool,
+}
+
+impl AttackProfile {
+    /// Returns the profile of an unarmed strike.
+    ///
+    /// # Reference
+    ///
+    /// Instead of using a weapon to make a melee weapon attack, you can use an unarmed strike:
+    /// a punch, kick, head-butt, or similar forceful blow. On a hit, an unarmed strike deals
+    /// bludgeoning damage equal to 1 + your Strength modifier. You are proficient with your
+    /// unarmed strikes.
+    pub fn unarmed_strike() -> Self {
+        Self {
+            ability: STRENGTH,
+            proficient: true,
+            damage: vec![Damage::new(
+                DamageType::Bludgeoning,
+                DiceRolls {
+                    modifier: 1,
+                    ..DiceRolls::default()
+                },
+            )],
+            reach_ft: DEFAULT_REACH_FT,
+            finesse: false,
+            heavy: false,
+            kind: WeaponKind::Melee,
+            range: None,
+            ammunition: false,
+            attacks: 1,
+            weapon: None,
+            ability_damage: true,
+        }
+    }
+
+    /// Returns the profile of an attack with `weapon`.\
+    /// `two_handed` tells whether the weapon is wielded with two hands, while `attacks_per_action`
+    /// is the number of attacks the creature makes when it takes the Attack action.
+    ///
+    /// # Reference
+    ///
+    /// The ability modifier used for a melee weapon attack is Strength, and the ability modifier
+    /// used for a ranged weapon attack is Dexterity.\
+    /// Because of the time required to load a weapon with the loading property, you can fire only
+    /// one piece of ammunition from it when you use an action, regardless of the number of
+    /// attacks you can normally make.
+    pub fn weapon(
+        id: WeaponId,
+        weapon: &Weapon,
+        proficient: bool,
+        two_handed: bool,
+        attacks_per_action: u8,
+    ) -> Self {
+        let mut damage = weapon.damage;
+        if let (true, Some(dice)) = (two_handed, weapon.versatile()) {
+            damage.dice_pool = DicePool::from_dice(dice);
+        }
+        // Thrown melee weapons use Strength for ranged attacks too.
+        let ability = match weapon.kind {
+            WeaponKind::Melee => STRENGTH,
+            WeaponKind::Ranged => DEXTERITY,
+        };
+        let attacks = if weapon.has(WeaponProperty::Loading) {
+            1
+        } else {
+            attacks_per_action
+        };
+        Self {
+            ability,
+            proficient,
+            damage: vec![Damage::new(weapon.damage_type, damage)],
+            reach_ft: weapon.reach_ft(),
+            finesse: weapon.has(WeaponProperty::Finesse),
+            heavy: weapon.has(WeaponProperty::Heavy),
+            kind: weapon.kind,
+            range: weapon.range(),
+            ammunition: weapon.uses_ammunition(),
+            attacks,
+            weapon: Some(id),
+            ability_damage: true,
+        }
+    }
+
+    /// Returns this profile as the bonus attack of two-weapon fighting, made with the weapon
+    /// held in the off hand.
+    ///
+    /// # Reference
+    ///
+    /// You don't add your ability modifier to the damage of the bonus attack, unless that
+    /// modifier is negative.\
+    /// When you engage in two-weapon fighting, you can add your ability modifier to the damage of
+    /// the second attack (Two-Weapon Fighting style).
+    pub fn off_hand(self, two_weapon_fighting_style: bool) -> Self {
+        Self {
+            attacks: 1,
+            ability_damage: two_weapon_fighting_style,
+            ..self
+        }
+    }
+
+    /// Returns the profile of the Attack action of a creature holding the weapons in `slots`:
+    /// an attack with the weapon in the main hand, or an unarmed strike if it's empty.\
+    /// `proficient` tells whether the creature is proficient with a weapon. Weapons are wielded
+    /// with two hands when the other hand is free.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the weapon doesn't exist or if it requires two hands while the
+    /// other hand holds a shield or a weapon.
+    pub fn main_hand<F>(
+        slots: &WeaponSlots,
+        proficient: F,
+        attacks_per_action: u8,
+    ) -> SRDResult<Self>
+    where
+        F: Fn(WeaponId, &Weapon) -> bool,
+    {
+        let id = match slots.main_hand {
+            Some(id) => id,
+            None => return Ok(Self::unarmed_strike()),
+        };
+        let weapon = compendium()
+            .weapon(&id)
+            .ok_or(SRDError::WeaponNotFound(id))?;
+        let free_hand = !slots.shield && slots.off_hand.is_none();
+        if weapon.two_handed() && !free_hand {
+            return Err(SRDError::InvalidArgument(
+                "two-handed weapons can't be used with a shield or an off-hand weapon".to_string(),
+            ));
+        }
+        Ok(Self::weapon(
+            id,
+            weapon,
+            proficient(id, weapon),
+            free_hand,
+            attacks_per_action,
+        ))
+    }
+
+    /// Returns the profile of the bonus attack of a creature holding a weapon in the off hand of
+    /// `slots`, if any.\
+    /// `proficient` tells whether the creature is proficient with a weapon.
+    ///
+    /// # Reference
+    ///
+    /// When you take the Attack action and attack with a light melee weapon that you're holding
+    /// in one hand, you can use a bonus action to attack with a different light melee weapon
+    /// that you're holding in the other hand.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the weapon doesn't exist or if a shield is also carried.
+    pub fn off_hand_weapon<F>(
+        slots: &WeaponSlots,
+        proficient: F,
+        two_weapon_fighting_style: bool,
+    ) -> SRDResult<Option<Self>>
+    where
+        F: Fn(WeaponId, &Weapon) -> bool,
+    {
+        let id = match slots.off_hand {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+        let weapon = compendium()
+            .weapon(&id)
+            .ok_or(SRDError::WeaponNotFound(id))?;
+        if slots.shield {
+            return Err(SRDError::InvalidArgument(
+                "an off-hand weapon can't be used with a shield".to_string(),
+            ));
+        }
+        Ok(Some(
+            Self::weapon(id, weapon, proficient(id, weapon), false, 1)
+                .off_hand(two_weapon_fighting_style),
+        ))
+    }
+
+    /// Returns how an attack reaches a target `distance_ft` feet away, or `None` if the target
+    /// is out of both reach and range.\
+    /// Melee weapons attack targets within their reach, and can be thrown farther if they have
+    /// the thrown property.
+    pub fn reach(&self, distance_ft: u16) -> Option<AttackReach> {
+        if self.kind == WeaponKind::Melee && distance_ft <= self.reach_ft {
+            return Some(AttackReach::Melee);
+        }
+        match self.range {
+            Some(range) if distance_ft <= range.normal => Some(AttackReach::NormalRange),
+            Some(range) if distance_ft <= range.long => Some(AttackReach::LongRange),
+            _ => None,
+        }
+    }
+}
+
+/// How an attack reaches its target.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AttackReach {
+    /// A melee attack against a target within reach.
+    #[default]
+    Melee,
+    /// A ranged attack against a target within normal range.
+    NormalRange,
+    /// A ranged attack against a target beyond normal range, but within long range.
+    LongRange,
+}
+
+impl AttackReach {
+    /// Returns true if this is a ranged attack.
+    pub fn is_ranged(&self) -> bool {
+        *self != Self::Melee
+    }
+}
+
+/// The circumstances of an attack that depend on the battlefield around the creatures.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttackCircumstances {
+    /// How the attack reaches its target.
+    pub reach: AttackReach,
+    /// Whether a hostile creature that isn't incapacitated is within 5 feet of the attacker.
+    pub threatened: bool,
+    /// The cover of the target against the attack.
+    pub cover: Cover,
+    /// Whether an ally of the attacker took the Help action to aid it against the target.
+    pub helped: bool,
+}
+
+/// Describes when a creature scores a critical hit and how much damage it deals.
+///
+/// # Reference
+///
+/// When you score a critical hit, you get to roll extra dice for the attack's damage against the
+/// target. Roll all of the attack's damage dice twice and add them together. Then add any relevant
+/// modifiers as normal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CriticalHit {
+    /// The lowest natural roll scoring a critical hit.
+    pub threshold: u8,
+    /// Number of additional weapon damage dice rolled on a critical hit with a melee attack.
+    pub extra_dice: u8,
+}
+
+impl CriticalHit {
+    /// Returns true if the natural roll `natural` of an attack scores a critical hit.
+    pub const fn is_critical(&self, natural: u8) -> bool {
+        natural >= self.threshold
+    }
+
+    /// Returns the damage of a critical hit, given the normal damage of an attack and whether
+    /// it is a melee attack.\
+    /// Damage dice are doubled while modifiers are not. Extra dice are added only to melee
+    /// attacks, and are of the same type as the largest die of the first damage entry.
+    ///
+    /// # Reference
+    ///
+    /// You can roll one additional weapon damage die when determining the extra damage for a
+    /// critical hit with a melee attack.
+    pub fn damage(&self, damage: &[Damage], melee: bool) -> Vec<Damage> {
+        damage
+            .iter()
+            .enumerate()
+            .map(|(i, damage)| {
+                let mut critical = *damage;
+                for dice in damage.roll.dice_pool.dice() {
+                    critical.roll.add_dice(dice);
+                }
+                if i == 0 && melee && self.extra_dice > 0 {
+                    let largest = damage
+                        .roll
+                        .dice_pool
+                        .dice()
+                        .max_by_key(|dice| dice.die.max());
+                    if let Some(dice) = largest {
+                        critical.roll.add_dice(Dice::new(self.extra_dice, dice.die));
+                    }
+                }
+                critical
+            })
+            .collect()
+    }
+}
+
+impl Default for CriticalHit {
+    fn default() -> Self {
+        Self {
+            threshold: Die::D20.max(),
+            extra_dice: 0,
+        }
+    }
+}
+
+/// The effect of a successful attack on its target.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Impact {
+    /// The attacker.
+    pub source: CharacterId,
+    /// The creature hit by the attack.
+    pub target: CharacterId,
+    /// The damage rolls, one for each damage type.
+    pub damage: Vec<DamageResult>,
+    /// Whether or not the attack was a critical hit.
+    pub critical: bool,
+}
+
+impl Impact {
+    /// Returns the total amount of damage, before applying the target's defenses.
+    pub fn amount(&self) -> u16 {
+        self.damage
+            .iter()
+            .fold(0, |total, damage| total.saturating_add(damage.amount()))
+    }
+}
+
+/// The outcome of an attack.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttackOutcome {
+    /// The attacker.
+    pub attacker: CharacterId,
+    /// The target of the attack.
+    pub target: CharacterId,
+    /// The attack roll.
+    pub roll: D20TestResult,
+    /// The target's armor class, including the bonus from cover.
+    pub armor_class: ArmorClass,
+    /// The impact of the attack, if it hit.
+    pub impact: Option<Impact>,
+}
+
+impl AttackOutcome {
+    /// Returns true if the attack hit the target.
+    pub fn hit(&self) -> bool {
+        self.impact.is_some()
+    }
+
+    /// Returns true if the attack was a critical hit.
+    pub fn critical(&self) -> bool {
+        self.impact.as_ref().is_some_and(|impact| impact.critical)
+    }
+}
+
+/// Returns the id of a creature.
+fn creature_id(creature: &dyn Character<SRDRules>) -> SRDResult<CharacterId> {
+    Ok(creature.entity_id().creature()?)
+}
+
+/// Returns the statistic with the given id or an error.
+fn statistic(
+    creature: &dyn Character<SRDRules>,
+    id: StatisticId,
+) -> SRDResult<&crate::rules::core::Statistic> {
+    creature
+        .statistic(&id)
+        .ok_or(SRDError::StatisticNotFound(id))
+}
+
+/// Resolves an attack of `attacker` against `target`.
+///
+/// # Reference
+///
+/// To make an attack roll, roll a d20 and add the appropriate modifiers. If the total of the
+/// roll plus modifiers equals or exceeds the target's Armor Class (AC), the attack hits.\
+/// If the d20 roll for an attack is a 20, the attack hits regardless of any modifiers or
+/// the target's AC.\
+/// If the d20 roll for an attack is a 1, the attack misses regardless of any modifiers or
+/// the target's AC.
+///
+/// Small creatures have disadvantage on attack rolls with heavy weapons.\
+/// When making an attack with a finesse weapon, you use your choice of your Strength or
+/// Dexterity modifier for the attack and damage rolls.
+///
+/// When you attack a target beyond normal range, you have disadvantage on the attack roll.\
+/// When you make a ranged attack with a weapon, you have disadvantage on the attack roll if you
+/// are within 5 feet of a hostile creature who can see you and who isn't incapacitated.\
+/// A target with half cover has a +2 bonus to AC, while a target with three-quarters cover
+/// has a +5 bonus.
+///
+/// When a creature can't see you, you have advantage on attack rolls against it. If you are
+/// hidden when you make an attack, you give away your location when the attack hits or misses.\
+/// When you attack a target that you can't see, you have disadvantage on the attack roll.\
+/// Attacks against a dodging creature have disadvantage, while an ally's help grants advantage.
+///
+/// A natural roll within the attacker's critical range is a critical hit, and always hits.\
+/// The conditions of both creatures may grant advantage or disadvantage on the roll, and turn
+/// hits against a paralyzed or unconscious target within 5 feet into critical hits.
+///
+/// # Errors
+///
+/// An error is returned if any creature lacks the statistics required to resolve the attack.
+pub(crate) fn resolve_attack<R: Roller + ?Sized>(
+    attacker: &dyn Character<SRDRules>,
+    target: &dyn Character<SRDRules>,
+    profile: &AttackProfile,
+    circumstances: AttackCircumstances,
+    roller: &mut R,
+) -> SRDResult<AttackOutcome> {
+    let modifier = |ability| -> SRDResult<i16> {
+        Ok(i16::from(
+            statistic(attacker, StatisticId::Ability(ability))?
+                .ability()?
+                .modifier(),
+        ))
+    };
+    let ability_modifier = if profile.finesse {
+        modifier(STRENGTH)?.max(modifier(DEXTERITY)?)
+    } else {
+        modifier(profile.ability)?
+    };
+    let proficiency = if profile.proficient {
+        i16::from(
+            statistic(attacker, StatisticId::ProficiencyBonus)?
+                .proficiency_bonus()?
+                .0,
+        )
+    } else {
+        0
+    };
+    let ArmorClass(armor_class) = *statistic(target, StatisticId::ArmorClass)?.armor_class()?;
+    let armor_class =
+        ArmorClass(armor_class.saturating_add(circumstances.cover.armor_class_bonus()));
+    let critical_hit = attacker
+        .statistic(&StatisticId::CriticalHit)
+        .and_then(|statistic| statistic.critical_hit().ok().copied())
+        .unwrap_or_default();
+    let within_5_ft = squares_to_ft(attacker.position().distance_sq(target.position())) <= 5;
+    let source = creature_id(attacker)?;
+    let target_id = creature_id(target)?;
+    let target_conditions = Conditions::of(target);
+    let mut test = D20Test::new(ability_modifier + proficiency);
+    Conditions::of(attacker).attacking().apply(&mut test);
+    if profile.heavy
+        && matches!(
+            statistic(attacker, StatisticId::Size)?.size()?,
+            CreatureSize::Tiny | CreatureSize::Small
+        )
+    {
+        test.add_disadvantage();
+    }
+    // The Grappler feat grants advantage against the creature being grappled.
+    if attacker
+        .statistic(&StatisticId::Feat(Feat::Grappler))
+        .is_some()
+        && target_conditions
+            .get(ConditionId::Grappled)
+            .is_some_and(|condition| condition.source() == Some(&source))
+    {
+        test.add_advantage();
+    }
+    let reach = circumstances.reach;
+    if reach == AttackReach::LongRange || (reach.is_ranged() && circumstances.threatened) {
+        test.add_disadvantage();
+    }
+    if circumstances.helped || is_hidden(attacker) {
+        test.add_advantage();
+    }
+    // A dodging creature must see the attacker to avoid its blows.
+    if (is_dodging(target) && !is_hidden(attacker)) || is_hidden(target) {
+        test.add_disadvantage();
+    }
+    target_conditions.defending(within_5_ft).apply(&mut test);
+    let roll = test.roll(roller);
+    let critical = roll.natural() != 1 && critical_hit.is_critical(roll.natural());
+    let hit = match roll.natural() {
+        1 => false,
+        20 => true,
+        _ => critical || roll.total() >= i32::from(armor_class.0),
+    };
+    let critical = critical || (hit && target_conditions.suffers_critical_hits(within_5_ft));
+    let impact = if hit {
+        let damage = if critical {
+            critical_hit.damage(&profile.damage, !reach.is_ranged())
+        } else {
+            profile.damage.clone()
+        };
+        let damage = damage
+            .iter()
+            .enumerate()
+            .map(|(i, damage)| {
+                let mut damage = *damage;
+                if i == 0 && (profile.ability_damage || ability_modifier < 0) {
+                    damage.roll.modifier += ability_modifier;
+                }
+                damage.roll(roller)
+            })
+            .collect();
+        Some(Impact {
+            source: source.clone(),
+            target: target_id.clone(),
+            damage,
+            critical,
+        })
+    } else {
+        None
+    };
+    Ok(AttackOutcome {
+        attacker: source,
+        target: target_id,
+        roll,
+        armor_class,
+        impact,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability::{AbilityScore, DEXTERITY};
+    use crate::character::class::FIGHTER;
+    use crate::character::race::HILL_DWARF;
+    use crate::dice::roll::SequenceRoller;
+    use crate::dice::DiceRoll;
+    use crate::rules::core::statistic::StatisticChange;
+    use crate::rules::core::ConditionPotency;
+    use crate::util::simple_server;
+    use weasel::{
+        AlterStatistics, BattleController, EntityId, EventTrigger, InflictStatus, Server,
+    };
+
+    fn spawn(server: &mut Server<SRDRules>, id: &str, strength: u8, dexterity: u8) {
+        crate::Character::new(id, HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(STRENGTH, AbilityScore::new(strength).unwrap())
+            .add_ability(DEXTERITY, AbilityScore::new(dexterity).unwrap())
+            .spawn(server)
+            .unwrap();
+    }
+
+    fn attack(server: &Server<SRDRules>, faces: Vec<u8>) -> AttackOutcome {
+        let entities = server.battle().entities();
+        resolve_attack(
+            entities.creature(&"attacker".into()).unwrap(),
+            entities.creature(&"target".into()).unwrap(),
+            &AttackProfile::unarmed_strike(),
+            AttackCircumstances::default(),
+            &mut SequenceRoller::new(faces),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn attack_roll_against_armor_class() {
+        let mut server = simple_server();
+        // Attack bonus is +3 (Strength) +2 (proficiency), target AC is 12.
+        spawn(&mut server, "attacker", 16, 10);
+        spawn(&mut server, "target", 10, 14);
+        let outcome = attack(&server, vec![7]);
+        assert_eq!(outcome.armor_class, ArmorClass(12));
+        assert_eq!(outcome.roll.total(), 12);
+        assert!(outcome.hit());
+        // Unarmed strike damage is 1 + Strength modifier.
+        assert_eq!(outcome.impact.unwrap().amount(), 4);
+        let outcome = attack(&server, vec![6]);
+        assert!(!outcome.hit());
+    }
+
+    #[test]
+    fn natural_one_and_twenty() {
+        let mut server = simple_server();
+        spawn(&mut server, "attacker", 30, 10);
+        spawn(&mut server, "target", 10, 30);
+        // The target has AC 20, the attacker +12.
+        assert!(!attack(&server, vec![1]).hit());
+        assert!(attack(&server, vec![8]).hit());
+        let mut server = simple_server();
+        spawn(&mut server, "attacker", 1, 10);
+        spawn(&mut server, "target", 10, 30);
+        assert!(attack(&server, vec![20]).hit());
+        assert!(!attack(&server, vec![19]).hit());
+    }
+
+    #[test]
+    fn critical_hit_doubles_dice() {
+        let mut server = simple_server();
+        spawn(&mut server, "attacker", 16, 10);
+        spawn(&mut server, "target", 10, 10);
+        let entities = server.battle().entities();
+        let profile = AttackProfile {
+            damage: vec![
+                Damage::new(DamageType::Slashing, DiceRoll::new(Dice::new(1, Die::D8))),
+                Damage::new(DamageType::Fire, DiceRoll::new(Dice::new(1, Die::D6))),
+            ],
+            ..AttackProfile::unarmed_strike()
+        };
+        let outcome = resolve_attack(
+            entities.creature(&"attacker".into()).unwrap(),
+            entities.creature(&"target".into()).unwrap(),
+            &profile,
+            AttackCircumstances::default(),
+            &mut SequenceRoller::new(vec![20, 1, 1, 1, 1]),
+        )
+        .unwrap();
+        assert!(outcome.critical());
+        let impact = outcome.impact.unwrap();
+        // Two d8 and two d6, plus the Strength modifier only once.
+        assert_eq!(impact.damage[0].roll.dice.len(), 2);
+        assert_eq!(impact.damage[1].roll.dice.len(), 2);
+        assert_eq!(impact.amount(), 4 + 3);
+    }
+
+    #[test]
+    fn grappler_advantage() {
+        let mut server = simple_server();
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(STRENGTH, AbilityScore::new(16).unwrap())
+            .add_feat(Feat::Grappler)
+            .unwrap()
+            .spawn(&mut server)
+            .unwrap();
+        spawn(&mut server, "target", 10, 14);
+        assert!(!attack(&server, vec![2, 15]).hit());
+        // Advantage against the grappled target.
+        InflictStatus::trigger(
+            &mut server,
+            EntityId::Creature("target".into()),
+            ConditionId::Grappled,
+        )
+        .potency(ConditionPotency {
+            source: Some("attacker".into()),
+            ..ConditionPotency::default()
+        })
+        .fire()
+        .unwrap();
+        assert!(attack(&server, vec![2, 15]).hit());
+    }
+
+    #[test]
+    fn critical_range_and_extra_dice() {
+        let critical_hit = CriticalHit {
+            threshold: 19,
+            extra_dice: 1,
+        };
+        assert!(!critical_hit.is_critical(18));
+        assert!(critical_hit.is_critical(19));
+        let damage = [Damage::new(
+            DamageType::Slashing,
+            DiceRoll::with_modifier(Dice::new(1, Die::D12), 2),
+        )];
+        let critical = critical_hit.damage(&damage, true);
+        assert_eq!(critical[0].roll.dice_pool.count(Die::D12), 3);
+        assert_eq!(critical[0].roll.modifier, 2);
+        // Ranged attacks don't get extra dice.
+        let critical = critical_hit.damage(&damage, false);
+        assert_eq!(critical[0].roll.dice_pool.count(Die::D12), 2);
+        let mut server = simple_server();
+        crate::Character::new("attacker", HILL_DWARF, FIGHTER)
+            .unwrap()
+            .add_ability(STRENGTH, AbilityScore::new(1).unwrap())
+            .set_critical_hit(critical_hit)
+            .spawn(&mut server)
+            .unwrap();
+        spawn(&mut server, "target", 10, 30);
+        // A 19 hits the AC 20 target even with -5 Strength.
+        let outcome = attack(&server, vec![19]);
+        assert!(outcome.hit());
+        assert!(outcome.critical());
+        assert!(!attack(&server, vec![18]).hit());
+        // The extra die is rolled only for melee attacks.
+        let profile = AttackProfile {
+            damage: vec![Damage::new(
+                DamageType::Piercing,
+                DiceRoll::new(Dice::new(1, Die::D8)),
+            )],
+            ..AttackProfile::unarmed_strike()
+        };
+        let dice_rolled = |reach| {
+            let entities = server.battle().entities();
+            resolve_attack(
+                entities.creature(&"attacker".into()).unwrap(),
+                entities.creature(&"target".into()).unwrap(),
+                &profile,
+                AttackCircumstances {
+                    reach,
+                    ..AttackCircumstances::default()
+                },
+                &mut SequenceRoller::new(vec![20, 1, 1, 1]),
+            )
+            .unwrap()
+            .impact
+            .unwrap()
+            .damage[0]
+                .roll
+                .dice
+                .len()
+        };
+        assert_eq!(dice_rolled(AttackReach::Melee), 3);
+        assert_eq!(dice_rolled(AttackReach::NormalRange), 2);
+    }
+
+    #[test]
+    fn weapon_profiles() {
+        let mut server = simple_server();
+        spawn(&mut server, "attacker", 10, 18);
+        spawn(&mut server, "target", 10, 10);
+        let rapier = Weapon::new(
+            crate::equipment::WeaponCategory::Martial,
+            WeaponKind::Melee,
+            DiceRoll::new(Dice::new(1, Die::D8)),
+            DamageType::Piercing,
+        )
+        .with(WeaponProperty::Finesse);
+        let profile = AttackProfile::weapon(WeaponId(0), &rapier, true, false, 2);
+        assert_eq!(profile.attacks, 2);
+        let entities = server.battle().entities();
+        // Finesse uses the better Dexterity modifier: 10 + 4 + 2 hits AC 10 with a 4.
+        let outcome = resolve_attack(
+            entities.creature(&"attacker".into()).unwrap(),
+            entities.creature(&"target".into()).unwrap(),
+            &profile,
+            AttackCircumstances::default(),
+            &mut SequenceRoller::new(vec![4, 1]),
+        )
+        .unwrap();
+        assert_eq!(outcome.roll.total(), 10);
+        assert_eq!(outcome.impact.unwrap().amount(), 5);
+        // Versatile weapons use the larger die with two hands.
+        let spear = Weapon::new(
+            crate::equipment::WeaponCategory::Simple,
+            WeaponKind::Melee,
+            DiceRoll::new(Dice::new(1, Die::D6)),
+            DamageType::Piercing,
+        )
+        .with(WeaponProperty::Versatile(Dice::new(1, Die::D8)))
+        .with(WeaponProperty::Loading);
+        let profile = AttackProfile::weapon(WeaponId(0), &spear, true, true, 2);
+        assert_eq!(profile.damage[0].roll.dice_pool.count(Die::D8), 1);
+        assert_eq!(profile.attacks, 1);
+        let profile = AttackProfile::weapon(WeaponId(0), &spear, true, false, 1);
+        assert_eq!(profile.damage[0].roll.dice_pool.count(Die::D6), 1);
+    }
+
+    #[test]
+    fn off_hand_damage() {
+        let mut server = simple_server();
+        spawn(&mut server, "attacker", 16, 10);
+        spawn(&mut server, "target", 10, 10);
+        let damage = |server: &Server<SRDRules>, profile: AttackProfile| {
+            let entities = server.battle().entities();
+            resolve_attack(
+                entities.creature(&"attacker".into()).unwrap(),
+                entities.creature(&"target".into()).unwrap(),
+                &profile,
+                AttackCircumstances::default(),
+                &mut SequenceRoller::new(vec![15]),
+            )
+            .unwrap()
+            .impact
+            .unwrap()
+            .amount()
+        };
+        let profile = AttackProfile::unarmed_strike();
+        assert_eq!(profile.clone().off_hand(false).attacks, 1);
+        // The positive Strength modifier is added only with the Two-Weapon Fighting style.
+        assert_eq!(damage(&server, profile.clone().off_hand(false)), 1);
+        assert_eq!(damage(&server, profile.clone().off_hand(true)), 4);
+        // A negative modifier always applies.
+        let mut server = simple_server();
+        spawn(&mut server, "attacker", 8, 10);
+        spawn(&mut server, "target", 10, 10);
+        assert_eq!(damage(&server, profile.off_hand(false)), 0);
+    }
+
+    #[test]
+    fn ranged_attack_disadvantage() {
+        let bow = Weapon::new(
+            crate::equipment::WeaponCategory::Simple,
+            WeaponKind::Ranged,
+            DiceRoll::new(Dice::new(1, Die::D6)),
+            DamageType::Piercing,
+        )
+        .with(WeaponProperty::Ammunition(WeaponRange {
+            normal: 80,
+            long: 320,
+        }));
+        let profile = AttackProfile::weapon(WeaponId(0), &bow, true, true, 1);
+        assert!(profile.ammunition);
+        assert_eq!(profile.reach(5), Some(AttackReach::NormalRange));
+        assert_eq!(profile.reach(80), Some(AttackReach::NormalRange));
+        assert_eq!(profile.reach(81), Some(AttackReach::LongRange));
+        assert_eq!(profile.reach(321), None);
+        let dagger = AttackProfile {
+            range: Some(WeaponRange {
+                normal: 20,
+                long: 60,
+            }),
+            ..AttackProfile::unarmed_strike()
+        };
+        assert_eq!(dagger.reach(5), Some(AttackReach::Melee));
+        assert_eq!(dagger.reach(10), Some(AttackReach::NormalRange));
+        assert_eq!(AttackProfile::unarmed_strike().reach(10), None);
+        let mut server = simple_server();
+        spawn(&mut server, "attacker", 10, 10);
+        spawn(&mut server, "target", 10, 10);
+        let entities = server.battle().entities();
+        let roll = |reach, threatened| {
+            resolve_attack(
+                entities.creature(&"attacker".into()).unwrap(),
+                entities.creature(&"target".into()).unwrap(),
+                &profile,
+                AttackCircumstances {
+                    reach,
+                    threatened,
+                    ..AttackCircumstances::default()
+                },
+                &mut SequenceRoller::new(vec![15, 2, 1]),
+            )
+            .unwrap()
+            .roll
+            .natural()
+        };
+        assert_eq!(roll(AttackReach::NormalRange, false), 15);
+        assert_eq!(roll(AttackReach::LongRange, false), 2);
+        assert_eq!(roll(AttackReach::NormalRange, true), 2);
+        // Close combat doesn't hinder melee attacks.
+        assert_eq!(roll(AttackReach::Melee, true), 15);
+    }
+
+    #[test]
+    fn tactical_advantage() {
+        let mut server = simple_server();
+        spawn(&mut server, "attacker", 16, 10);
+        spawn(&mut server, "target", 10, 14);
+        assert!(!attack(&server, vec![2, 15]).hit());
+        let entities = server.battle().entities();
+        let helped = resolve_attack(
+            entities.creature(&"attacker".into()).unwrap(),
+            entities.creature(&"target".into()).unwrap(),
+            &AttackProfile::unarmed_strike(),
+            AttackCircumstances {
+                helped: true,
+                ..AttackCircumstances::default()
+            },
+            &mut SequenceRoller::new(vec![2, 15]),
+        )
+        .unwrap();
+        assert!(helped.hit());
+        let alter = |server: &mut Server<SRDRules>, id: &str, change| {
+            AlterStatistics::trigger(server, EntityId::Creature(id.into()), change)
+                .fire()
+                .unwrap();
+        };
+        // Dodging doesn't help against unseen attackers.
+        alter(&mut server, "target", StatisticChange::Dodge);
+        alter(&mut server, "attacker", StatisticChange::Hide(20));
+        assert!(attack(&server, vec![2, 15]).hit());
+        alter(&mut server, "attacker", StatisticChange::Reveal);
+        assert!(!attack(&server, vec![15, 2]).hit());
+    }
+}
@@ -1 +1,668 @@
 //! Grid of squares.
+
+use crate::character::CharacterId;
+use crate::error::{SRDError, SRDResult};
+use crate::rules::core::cover::Cover;
+use crate::rules::core::size::CreatureSize;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+
+/// Default width and height, in squares, of a battle grid.
+pub const DEFAULT_GRID_SIDE_SQ: u16 = 40;
+
+/// A square of the battle grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Square {
+    /// Column of the square.
+    pub x: u16,
+    /// Row of the square.
+    pub y: u16,
+}
+
+impl Square {
+    /// Creates a new square.
+    pub const fn new(x: u16, y: u16) -> Self {
+        Self { x, y }
+    }
+}
+
+/// The squares occupied by a creature: a square area of `side` by `side` squares whose
+/// corner with the lowest coordinates is `origin`.
+///
+/// # Reference
+///
+/// A creature's space is the area in feet that it effectively controls in combat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Footprint {
+    /// The square with the lowest coordinates.
+    pub origin: Square,
+    /// Length of one side, in squares.
+    pub side: u8,
+}
+
+impl Footprint {
+    /// Returns the footprint of a creature of the given size, starting at `origin`.
+    pub const fn new(origin: Square, size: CreatureSize) -> Self {
+        Self {
+            origin,
+            side: size.space_sq(),
+        }
+    }
+
+    /// Returns an iterator over all squares in this footprint.
+    pub fn squares(&self) -> impl Iterator<Item = Square> {
+        let origin = self.origin;
+        let side = u16::from(self.side);
+        (0..side).flat_map(move |dy| {
+            (0..side).map(move |dx| {
+                Square::new(origin.x.saturating_add(dx), origin.y.saturating_add(dy))
+            })
+        })
+    }
+
+    /// Returns true if `square` is part of this footprint.
+    pub fn contains(&self, square: Square) -> bool {
+        let (x0, y0, x1, y1) = self.bounds();
+        let (x, y) = (u32::from(square.x), u32::from(square.y));
+        x0 <= x && x < x1 && y0 <= y && y < y1
+    }
+
+    /// Returns true if the two footprints share at least one square.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let (a, b) = (self.bounds(), other.bounds());
+        a.0 < b.2 && b.0 < a.2 && a.1 < b.3 && b.1 < a.3
+    }
+
+    /// Returns the distance, in squares, between the closest squares of two footprints.
+    /// Adjacent footprints are one square apart and overlapping ones zero.
+    pub fn distance_sq(&self, other: &Self) -> u16 {
+        let (a, b) = (self.bounds(), other.bounds());
+        let gap = |min_a: u32, max_a: u32, min_b: u32, max_b: u32| {
+            if max_a <= min_b {
+                min_b - max_a + 1
+            } else if max_b <= min_a {
+                min_a - max_b + 1
+            } else {
+                0
+            }
+        };
+        let distance = gap(a.0, a.2, b.0, b.2).max(gap(a.1, a.3, b.1, b.3));
+        u16::try_from(distance).unwrap_or(u16::MAX)
+    }
+
+    /// Returns this footprint moved by `dx` columns and `dy` rows, or `None` if it would
+    /// end up at negative coordinates.
+    pub fn shifted(&self, dx: i32, dy: i32) -> Option<Self> {
+        let shift = |value: u16, delta: i32| u16::try_from(i32::from(value) + delta).ok();
+        Some(Self {
+            origin: Square::new(shift(self.origin.x, dx)?, shift(self.origin.y, dy)?),
+            side: self.side,
+        })
+    }
+
+    /// Returns the direction, as the sign of the horizontal and vertical offset, in which
+    /// this footprint lies as seen from the center of `other`.
+    pub fn direction_from(&self, other: &Self) -> (i32, i32) {
+        let (a, b) = (self.bounds(), other.bounds());
+        let center = |min: u32, max: u32| i64::from(min + max);
+        let sign = |value: i64| value.signum() as i32;
+        (
+            sign(center(a.0, a.2) - center(b.0, b.2)),
+            sign(center(a.1, a.3) - center(b.1, b.3)),
+        )
+    }
+
+    /// Returns min x, min y, max x (exclusive) and max y (exclusive).
+    fn bounds(&self) -> (u32, u32, u32, u32) {
+        let (x, y) = (u32::from(self.origin.x), u32::from(self.origin.y));
+        let side = u32::from(self.side);
+        (x, y, x + side, y + side)
+    }
+}
+
+/// `GridSeed` describes the battle map.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GridSeed {
+    /// Number of columns.
+    pub width: u16,
+    /// Number of rows.
+    pub height: u16,
+    /// Squares that can't be entered.
+    pub walls: Vec<Square>,
+    /// Squares of difficult terrain.
+    pub difficult_terrain: Vec<Square>,
+}
+
+impl Default for GridSeed {
+    fn default() -> Self {
+        Self {
+            width: DEFAULT_GRID_SIDE_SQ,
+            height: DEFAULT_GRID_SIDE_SQ,
+            walls: Vec::new(),
+            difficult_terrain: Vec::new(),
+        }
+    }
+}
+
+/// The battle map, divided in squares.\
+/// It keeps track of walls, difficult terrain and of which creature occupies each square.
+#[derive(Debug, Clone, Default)]
+pub struct BattleGrid {
+    width: u16,
+    height: u16,
+    walls: HashSet<Square>,
+    difficult_terrain: HashSet<Square>,
+    occupants: HashMap<Square, CharacterId>,
+    footprints: HashMap<CharacterId, Footprint>,
+}
+
+impl BattleGrid {
+    /// Creates an empty grid from a seed.
+    pub fn new(seed: &GridSeed) -> Self {
+        Self {
+            width: seed.width,
+            height: seed.height,
+            walls: seed.walls.iter().copied().collect(),
+            difficult_terrain: seed.difficult_terrain.iter().copied().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the number of columns.
+    pub const fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub const fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns true if `square` is inside the grid.
+    pub fn contains(&self, square: Square) -> bool {
+        square.x < self.width && square.y < self.height
+    }
+
+    /// Returns true if `square` is a wall.
+    pub fn is_wall(&self, square: Square) -> bool {
+        self.walls.contains(&square)
+    }
+
+    /// Returns true if `square` is difficult terrain.
+    pub fn is_difficult_terrain(&self, square: Square) -> bool {
+        self.difficult_terrain.contains(&square)
+    }
+
+    /// Returns the creature occupying `square`, if any.
+    pub fn occupant(&self, square: Square) -> Option<&CharacterId> {
+        self.occupants.get(&square)
+    }
+
+    /// Returns the footprint of a creature on the grid.
+    pub fn footprint(&self, creature: &CharacterId) -> Option<&Footprint> {
+        self.footprints.get(creature)
+    }
+
+    /// Checks whether `creature` can occupy `footprint`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if any square of `footprint` is outside the grid, is a wall or
+    /// is occupied by another creature.
+    ///
+    /// # Reference
+    ///
+    /// Whether a creature is a friend or an enemy, you can't willingly end your move in its space.
+    pub fn check(&self, creature: &CharacterId, footprint: &Footprint) -> SRDResult<()> {
+        self.check_footprint(&[creature], footprint)
+    }
+
+    /// Checks whether `creature` can occupy `footprint` once all creatures in `leaving` have
+    /// left their space, for example because they are moving together.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if any square of `footprint` is outside the grid, is a wall or
+    /// is occupied by a creature that is not leaving.
+    pub fn check_after(
+        &self,
+        creature: &CharacterId,
+        footprint: &Footprint,
+        leaving: &[&CharacterId],
+    ) -> SRDResult<()> {
+        let mut ignored = leaving.to_vec();
+        ignored.push(creature);
+        self.check_footprint(&ignored, footprint)
+    }
+
+    /// Returns the cost, in squares of movement, for `creature` to follow `path`.\
+    /// `path` contains the successive origins of the creature's footprint, each adjacent to the
+    /// previous one. `extra_cost` is added to the cost of each square. The spaces of `blocking`
+    /// creatures, usually hostile ones, can't be entered.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `creature` is not on the grid, if the path is empty or
+    /// not contiguous, if it crosses walls, blocking creatures or leaves the grid, or if its end
+    /// is occupied.
+    ///
+    /// # Reference
+    ///
+    /// Every foot of movement in difficult terrain costs 1 extra foot.\
+    /// You can move through a nonhostile creature's space. Another creature's space is
+    /// difficult terrain for you.
+    pub fn path_cost(
+        &self,
+        creature: &CharacterId,
+        path: &[Square],
+        extra_cost: u16,
+        blocking: &[&CharacterId],
+    ) -> SRDResult<u16> {
+        let mut current = *self.footprint(creature).ok_or_else(|| {
+            SRDError::InvalidPosition(format!("{:?} is not on the grid", creature))
+        })?;
+        let end = path
+            .last()
+            .ok_or_else(|| SRDError::InvalidPosition("the path is empty".to_string()))?;
+        let mut cost: u16 = 0;
+        for origin in path {
+            let adjacent = current.origin.x.abs_diff(origin.x) <= 1
+                && current.origin.y.abs_diff(origin.y) <= 1
+                && current.origin != *origin;
+            if !adjacent {
+                return Err(SRDError::InvalidPosition(format!(
+                    "{:?} is not adjacent to {:?}",
+                    origin, current.origin
+                )));
+            }
+            current.origin = *origin;
+            let mut difficult = false;
+            for square in current.squares() {
+                if !self.contains(square) || self.is_wall(square) {
+                    return Err(SRDError::InvalidPosition(format!(
+                        "{:?} can't be entered",
+                        square
+                    )));
+                }
+                let occupant = self
+                    .occupant(square)
+                    .filter(|occupant| *occupant != creature);
+                if occupant.is_some_and(|occupant| blocking.contains(&occupant)) {
+                    return Err(SRDError::InvalidPosition(format!(
+                        "{:?} is occupied by a hostile creature",
+                        square
+                    )));
+                }
+                difficult |= self.is_difficult_terrain(square) || occupant.is_some();
+            }
+            cost = cost
+                .saturating_add(1 + extra_cost)
+                .saturating_add(u16::from(difficult));
+        }
+        self.check(
+            creature,
+            &Footprint {
+                origin: *end,
+                ..current
+            },
+        )?;
+        Ok(cost)
+    }
+
+    /// Checks whether `footprint` is free, ignoring the squares occupied by `ignored` creatures.
+    fn check_footprint(&self, ignored: &[&CharacterId], footprint: &Footprint) -> SRDResult<()> {
+        for square in footprint.squares() {
+            if !self.contains(square) {
+                return Err(SRDError::InvalidPosition(format!(
+                    "{:?} is out of the grid",
+                    square
+                )));
+            }
+            if self.is_wall(square) {
+                return Err(SRDError::InvalidPosition(format!(
+                    "{:?} is blocked",
+                    square
+                )));
+            }
+            match self.occupant(square) {
+                Some(occupant) if !ignored.contains(&occupant) => {
+                    return Err(SRDError::InvalidPosition(format!(
+                        "{:?} is occupied by {:?}",
+                        square, occupant
+                    )))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the first footprint, scanning rows from the top, where a creature of the given
+    /// size fits.
+    pub fn free_footprint(&self, size: CreatureSize) -> Option<Footprint> {
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| Square::new(x, y)))
+            .map(|origin| Footprint::new(origin, size))
+            .find(|footprint| self.check_footprint(&[], footprint).is_ok())
+    }
+
+    /// Returns the cover a creature occupying `target` has against attacks and effects coming
+    /// from a creature occupying `origin`.\
+    /// Walls and squares occupied by other creatures are obstacles. Creatures alone never grant
+    /// total cover.
+    ///
+    /// # Reference
+    ///
+    /// To determine whether a target has cover against an attack or other effect on a grid,
+    /// choose a corner of the attacker's space or the point of origin of an area of effect. Then
+    /// trace imaginary lines from that corner to every corner of any one square the target
+    /// occupies. If one or two of those lines are blocked by an obstacle (including a creature),
+    /// the target has half cover. If three or four of those lines are blocked but the attack can
+    /// still reach the target, the target has three-quarters cover.
+    pub fn cover(&self, origin: &Footprint, target: &Footprint) -> Cover {
+        let (x0, y0, x1, y1) = origin.bounds();
+        let corners = [(x0, y0), (x1, y0), (x0, y1), (x1, y1)];
+        // The attacker picks the corner and the target square that are the least covered.
+        corners
+            .iter()
+            .flat_map(|corner| target.squares().map(move |square| (*corner, square)))
+            .map(|(corner, square)| self.square_cover(corner, square, origin, target))
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// Returns true if nothing but walls completely conceals `target` from `origin`.
+    pub fn has_line_of_sight(&self, origin: &Footprint, target: &Footprint) -> bool {
+        self.cover(origin, target).allows_targeting()
+    }
+
+    /// Returns the cover of `square` given by the lines traced from `corner` to its corners.
+    fn square_cover(
+        &self,
+        corner: (u32, u32),
+        square: Square,
+        origin: &Footprint,
+        target: &Footprint,
+    ) -> Cover {
+        let (x, y) = (u32::from(square.x), u32::from(square.y));
+        let (mut walls, mut obstacles) = (0, 0);
+        for end in [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)] {
+            let (wall, obstacle) = self.blocked_line(corner, end, origin, target);
+            walls += u8::from(wall);
+            obstacles += u8::from(wall || obstacle);
+        }
+        match (walls, obstacles) {
+            (4, _) => Cover::Total,
+            (_, 3..=4) => Cover::ThreeQuarters,
+            (_, 1..=2) => Cover::Half,
+            _ => Cover::None,
+        }
+    }
+
+    /// Returns whether the line from `start` to `end` crosses the inside of a wall, and whether
+    /// it crosses the inside of a square occupied by a creature other than those occupying
+    /// `origin` and `target`. Lines running along the sides of a square are not blocked.
+    fn blocked_line(
+        &self,
+        start: (u32, u32),
+        end: (u32, u32),
+        origin: &Footprint,
+        target: &Footprint,
+    ) -> (bool, bool) {
+        let (mut wall, mut creature) = (false, false);
+        for x in start.0.min(end.0)..start.0.max(end.0) {
+            for y in start.1.min(end.1)..start.1.max(end.1) {
+                let square = match (u16::try_from(x), u16::try_from(y)) {
+                    (Ok(x), Ok(y)) => Square::new(x, y),
+                    _ => continue,
+                };
+                if !crosses(start, end, (x, y)) {
+                    continue;
+                }
+                if self.is_wall(square) {
+                    wall = true;
+                } else if self.occupant(square).is_some()
+                    && !origin.contains(square)
+                    && !target.contains(square)
+                {
+                    creature = true;
+                }
+            }
+        }
+        // A line running along the sides of walls is blocked as well, unless there is an open
+        // square on one side of it.
+        let solid = |x: Option<u32>, y: Option<u32>| match (x, y) {
+            (Some(x), Some(y)) => match (u16::try_from(x), u16::try_from(y)) {
+                (Ok(x), Ok(y)) => {
+                    let square = Square::new(x, y);
+                    !self.contains(square) || self.is_wall(square)
+                }
+                _ => true,
+            },
+            _ => true,
+        };
+        if start.1 == end.1 {
+            let y = start.1;
+            wall |= (start.0.min(end.0)..start.0.max(end.0))
+                .any(|x| solid(Some(x), y.checked_sub(1)) && solid(Some(x), Some(y)));
+        } else if start.0 == end.0 {
+            let x = start.0;
+            wall |= (start.1.min(end.1)..start.1.max(end.1))
+                .any(|y| solid(x.checked_sub(1), Some(y)) && solid(Some(x), Some(y)));
+        }
+        (wall, creature)
+    }
+
+    /// Moves `creature` to `footprint`, or removes it from the grid if `footprint` is `None`.
+    pub(crate) fn place(&mut self, creature: &CharacterId, footprint: Option<&Footprint>) {
+        if let Some(old) = self.footprints.remove(creature) {
+            for square in old.squares() {
+                self.occupants.remove(&square);
+            }
+        }
+        if let Some(footprint) = footprint {
+            for square in footprint.squares() {
+                self.occupants.insert(square, creature.clone());
+            }
+            self.footprints.insert(creature.clone(), *footprint);
+        }
+    }
+}
+
+/// Returns true if a creature of size `mover` can move through the space of a hostile creature
+/// of size `hostile`.
+///
+/// # Reference
+///
+/// You can move through a hostile creature's space only if the creature is at least two sizes
+/// larger or smaller than you.
+pub fn can_move_through_hostile(mover: CreatureSize, hostile: CreatureSize) -> bool {
+    (mover as u8).abs_diff(hostile as u8) >= 2
+}
+
+/// Returns true if the segment from `start` to `end` passes through the inside of the square
+/// whose top left corner is `square`.
+fn crosses(start: (u32, u32), end: (u32, u32), square: (u32, u32)) -> bool {
+    let point = |value: u32| f64::from(value);
+    let (dx, dy) = (point(end.0) - point(start.0), point(end.1) - point(start.1));
+    // Clip the segment against each side of the square (Liang-Barsky).
+    let sides = [
+        (-dx, point(start.0) - point(square.0)),
+        (dx, point(square.0) + 1.0 - point(start.0)),
+        (-dy, point(start.1) - point(square.1)),
+        (dy, point(square.1) + 1.0 - point(start.1)),
+    ];
+    let (mut enter, mut exit) = (0.0_f64, 1.0_f64);
+    for (p, q) in sides {
+        if p == 0.0 {
+            if q <= 0.0 {
+                return false;
+            }
+        } else if p < 0.0 {
+            enter = enter.max(q / p);
+        } else {
+            exit = exit.min(q / p);
+        }
+    }
+    enter < exit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn footprints() {
+        let medium = Footprint::new(Square::new(1, 1), CreatureSize::Medium);
+        assert_eq!(medium.squares().count(), 4);
+        assert!(medium.squares().any(|sq| sq == Square::new(2, 2)));
+        let tiny = Footprint::new(Square::new(2, 2), CreatureSize::Tiny);
+        assert!(medium.overlaps(&tiny));
+        assert!(tiny.overlaps(&medium));
+        let tiny = Footprint::new(Square::new(3, 1), CreatureSize::Tiny);
+        assert!(!medium.overlaps(&tiny));
+        assert_eq!(medium.distance_sq(&tiny), 1);
+        assert_eq!(tiny.distance_sq(&medium), 1);
+        assert_eq!(medium.distance_sq(&medium), 0);
+        let far = Footprint::new(Square::new(5, 6), CreatureSize::Medium);
+        assert_eq!(medium.distance_sq(&far), 4);
+    }
+
+    #[test]
+    fn occupy_grid() {
+        let mut grid = BattleGrid::new(&GridSeed {
+            width: 4,
+            height: 4,
+            walls: vec![Square::new(3, 3)],
+            ..GridSeed::default()
+        });
+        let one: CharacterId = "one".into();
+        let two: CharacterId = "two".into();
+        let footprint = Footprint::new(Square::new(0, 0), CreatureSize::Medium);
+        assert!(grid.check(&one, &footprint).is_ok());
+        grid.place(&one, Some(&footprint));
+        assert_eq!(grid.occupant(Square::new(1, 1)), Some(&one));
+        // A creature doesn't block itself.
+        let shifted = Footprint::new(Square::new(1, 0), CreatureSize::Medium);
+        assert!(grid.check(&one, &shifted).is_ok());
+        assert!(grid.check(&two, &shifted).is_err());
+        // Out of bounds and walls.
+        let outside = Footprint::new(Square::new(3, 0), CreatureSize::Medium);
+        assert!(grid.check(&two, &outside).is_err());
+        let wall = Footprint::new(Square::new(2, 2), CreatureSize::Medium);
+        assert!(grid.check(&two, &wall).is_err());
+        assert_eq!(
+            grid.free_footprint(CreatureSize::Medium),
+            Some(Footprint::new(Square::new(2, 0), CreatureSize::Medium))
+        );
+        assert_eq!(grid.free_footprint(CreatureSize::Large), None);
+        // Moving and removing update the occupancy.
+        grid.place(
+            &one,
+            Some(&Footprint::new(Square::new(0, 2), CreatureSize::Medium)),
+        );
+        assert_eq!(grid.occupant(Square::new(0, 0)), None);
+        assert_eq!(grid.occupant(Square::new(1, 3)), Some(&one));
+        grid.place(&one, None);
+        assert_eq!(grid.occupant(Square::new(1, 3)), None);
+        assert_eq!(grid.footprint(&one), None);
+    }
+
+    #[test]
+    fn path_costs() {
+        let mut grid = BattleGrid::new(&GridSeed {
+            width: 6,
+            height: 4,
+            walls: vec![Square::new(3, 0)],
+            difficult_terrain: vec![Square::new(5, 3)],
+        });
+        let one: CharacterId = "one".into();
+        let two: CharacterId = "two".into();
+        let medium = |x, y| Footprint::new(Square::new(x, y), CreatureSize::Medium);
+        grid.place(&one, Some(&medium(0, 0)));
+        grid.place(&two, Some(&medium(4, 0)));
+        let path = [Square::new(1, 1), Square::new(2, 2)];
+        assert_eq!(grid.path_cost(&one, &path, 0, &[]), Ok(2));
+        // Crawling.
+        assert_eq!(grid.path_cost(&one, &path, 1, &[]), Ok(4));
+        // Difficult terrain.
+        let path = [
+            Square::new(1, 1),
+            Square::new(2, 2),
+            Square::new(3, 2),
+            Square::new(4, 2),
+        ];
+        assert_eq!(grid.path_cost(&one, &path, 0, &[]), Ok(5));
+        // Moving through another creature.
+        let path = [
+            Square::new(1, 1),
+            Square::new(2, 1),
+            Square::new(3, 1),
+            Square::new(3, 2),
+        ];
+        assert_eq!(grid.path_cost(&one, &path, 0, &[]), Ok(5));
+        // Hostile creatures block the way.
+        assert!(grid.path_cost(&one, &path, 0, &[&two]).is_err());
+        // Unless they are at least two sizes larger or smaller.
+        assert!(can_move_through_hostile(
+            CreatureSize::Medium,
+            CreatureSize::Huge
+        ));
+        assert!(can_move_through_hostile(
+            CreatureSize::Medium,
+            CreatureSize::Tiny
+        ));
+        assert!(!can_move_through_hostile(
+            CreatureSize::Medium,
+            CreatureSize::Large
+        ));
+        // Invalid paths.
+        assert!(grid.path_cost(&one, &[], 0, &[]).is_err());
+        assert!(grid.path_cost(&one, &[Square::new(2, 0)], 0, &[]).is_err());
+        let path = [Square::new(1, 0), Square::new(2, 0)];
+        assert!(grid.path_cost(&one, &path, 0, &[]).is_err());
+        let path = [Square::new(1, 1), Square::new(2, 1), Square::new(3, 1)];
+        assert!(grid.path_cost(&one, &path, 0, &[]).is_err());
+        let path = [Square::new(0, 1), Square::new(0, 2), Square::new(0, 3)];
+        assert!(grid.path_cost(&one, &path, 0, &[]).is_err());
+        assert!(grid
+            .path_cost(&"nobody".into(), &[Square::new(1, 1)], 0, &[])
+            .is_err());
+    }
+
+    #[test]
+    fn cover_and_line_of_sight() {
+        let walls = (0..8).map(|y| Square::new(4, y)).collect();
+        let mut grid = BattleGrid::new(&GridSeed {
+            width: 12,
+            height: 8,
+            walls,
+            ..GridSeed::default()
+        });
+        let medium = |x, y| Footprint::new(Square::new(x, y), CreatureSize::Medium);
+        // A wall as wide as the grid blocks everything.
+        assert_eq!(grid.cover(&medium(0, 0), &medium(6, 0)), Cover::Total);
+        assert!(!grid.has_line_of_sight(&medium(0, 0), &medium(6, 0)));
+        // Nothing in between.
+        assert_eq!(grid.cover(&medium(6, 0), &medium(10, 0)), Cover::None);
+        assert!(grid.has_line_of_sight(&medium(6, 0), &medium(10, 0)));
+        // A creature in between gives some cover, but never total cover.
+        let blocker: CharacterId = "blocker".into();
+        grid.place(&blocker, Some(&medium(8, 0)));
+        let cover = grid.cover(&medium(6, 0), &medium(10, 0));
+        assert!(cover > Cover::None && cover < Cover::Total);
+        assert!(grid.has_line_of_sight(&medium(6, 0), &medium(10, 0)));
+        // Creatures don't cover themselves.
+        assert_eq!(grid.cover(&medium(8, 0), &medium(10, 0)), Cover::None);
+        // A low wall only covers part of the target.
+        let grid = BattleGrid::new(&GridSeed {
+            width: 12,
+            height: 8,
+            walls: vec![Square::new(4, 0), Square::new(4, 1)],
+            ..GridSeed::default()
+        });
+        assert_eq!(grid.cover(&medium(0, 0), &medium(6, 0)), Cover::Half);
+        assert_eq!(grid.cover(&medium(0, 4), &medium(6, 4)), Cover::None);
+    }
+}
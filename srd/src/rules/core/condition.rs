@@ -0,0 +1,422 @@
+//! Conditions altering the capabilities of creatures.
+
+use crate::ability::{DEXTERITY, STRENGTH};
+use crate::character::CharacterId;
+use crate::check::CheckKind;
+use crate::dice::D20Test;
+use crate::hit_points::HitPoints;
+use crate::rules::core::movement::Speeds;
+use crate::rules::SRDRules;
+use serde::{Deserialize, Serialize};
+use weasel::Character;
+
+/// Highest level of exhaustion. A creature reaching it dies.
+pub const MAX_EXHAUSTION_LEVEL: u8 = 6;
+
+/// Identifies a condition.
+///
+/// # Reference
+///
+/// Conditions alter a creature's capabilities in a variety of ways and can arise as a result of
+/// a spell, a class feature, a monster's attack, or other effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConditionId {
+    /// A blinded creature can't see. Attack rolls against the creature have advantage, and the
+    /// creature's attack rolls have disadvantage.
+    Blinded,
+    /// A charmed creature can't attack the charmer.
+    Charmed,
+    /// A deafened creature can't hear.
+    Deafened,
+    /// A frightened creature has disadvantage on ability checks and attack rolls and can't
+    /// willingly move closer to the source of its fear.
+    Frightened,
+    /// A grappled creature's speed becomes 0.
+    Grappled,
+    /// An incapacitated creature can't take actions or reactions.
+    Incapacitated,
+    /// Attack rolls against an invisible creature have disadvantage, and the creature's attack
+    /// rolls have advantage.
+    Invisible,
+    /// A paralyzed creature is incapacitated and can't move. It automatically fails Strength and
+    /// Dexterity saving throws. Attack rolls against the creature have advantage, and any attack
+    /// that hits it is a critical hit if the attacker is within 5 feet.
+    Paralyzed,
+    /// A petrified creature is incapacitated and can't move. It automatically fails Strength
+    /// and Dexterity saving throws and has resistance to all damage. Attack rolls against the
+    /// creature have advantage.
+    Petrified,
+    /// A poisoned creature has disadvantage on attack rolls and ability checks.
+    Poisoned,
+    /// A prone creature can only crawl, unless it stands up. It has disadvantage on attack rolls.
+    /// Attack rolls against it have advantage if the attacker is within 5 feet, otherwise they
+    /// have disadvantage.
+    Prone,
+    /// A restrained creature's speed becomes 0. Attack rolls against the creature have
+    /// advantage, and the creature's attack rolls and Dexterity saving throws have disadvantage.
+    Restrained,
+    /// A stunned creature is incapacitated and can't move. It automatically fails Strength and
+    /// Dexterity saving throws. Attack rolls against the creature have advantage.
+    Stunned,
+    /// An unconscious creature is incapacitated and can't move. It automatically fails Strength
+    /// and Dexterity saving throws. Attack rolls against the creature have advantage, and any
+    /// attack that hits it is a critical hit if the attacker is within 5 feet.
+    Unconscious,
+    /// Exhaustion is measured in six levels, whose effects are cumulative.
+    ///
+    /// # Reference
+    ///
+    /// 1. Disadvantage on ability checks
+    /// 2. Speed halved
+    /// 3. Disadvantage on attack rolls and saving throws
+    /// 4. Hit point maximum halved
+    /// 5. Speed reduced to 0
+    /// 6. Death
+    Exhaustion,
+}
+
+/// Parameters of a condition being inflicted on a creature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConditionPotency {
+    /// The creature causing the condition, if any.
+    pub source: Option<CharacterId>,
+    /// After how many starts of the afflicted creature's turn the condition ends.
+    /// `None` means that the condition lasts until removed.
+    pub duration: Option<u32>,
+    /// Levels of exhaustion to add. Ignored by other conditions.
+    pub levels: u8,
+}
+
+impl Default for ConditionPotency {
+    fn default() -> Self {
+        Self {
+            source: None,
+            duration: None,
+            levels: 1,
+        }
+    }
+}
+
+/// A condition affecting a creature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Condition {
+    id: ConditionId,
+    source: Option<CharacterId>,
+    duration: Option<u32>,
+    level: u8,
+}
+
+impl Condition {
+    /// Creates a new condition with the given potency.
+    /// The level of exhaustion is capped to `MAX_EXHAUSTION_LEVEL`.
+    pub fn new(id: ConditionId, potency: ConditionPotency) -> Self {
+        let level = match id {
+            ConditionId::Exhaustion => potency.levels.min(MAX_EXHAUSTION_LEVEL),
+            _ => 0,
+        };
+        Self {
+            id,
+            source: potency.source,
+            duration: potency.duration,
+            level,
+        }
+    }
+
+    /// Returns the creature that caused this condition, if any.
+    pub fn source(&self) -> Option<&CharacterId> {
+        self.source.as_ref()
+    }
+
+    /// Returns after how many starts of the afflicted creature's turn the condition ends.
+    pub const fn duration(&self) -> Option<u32> {
+        self.duration
+    }
+
+    /// Returns the level of exhaustion. It's always zero for other conditions.
+    pub const fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Changes the level of exhaustion by `change`, between 0 and `MAX_EXHAUSTION_LEVEL`.
+    pub(crate) fn change_level(&mut self, change: i8) {
+        if self.id == ConditionId::Exhaustion {
+            let level = i16::from(self.level) + i16::from(change);
+            self.level = level.clamp(0, i16::from(MAX_EXHAUSTION_LEVEL)) as u8;
+        }
+    }
+
+    /// Returns true if the condition ends after `turns` starts of the afflicted creature's turn.
+    pub fn expired(&self, turns: u32) -> bool {
+        self.duration.is_some_and(|duration| turns >= duration)
+    }
+}
+
+impl weasel::Id for Condition {
+    type Id = ConditionId;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+/// Changes to the conditions of a creature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConditionsAlteration {
+    /// Applies a condition, replacing any existing condition with the same id.
+    Apply(Condition),
+    /// Removes a condition.
+    Remove(ConditionId),
+    /// Removes all conditions caused by a creature.
+    RemoveFrom(CharacterId),
+    /// Changes the level of exhaustion. The condition is removed when the level drops to 0.
+    ///
+    /// # Reference
+    ///
+    /// Finishing a long rest reduces a creature's exhaustion level by 1, provided that the
+    /// creature has also ingested some food and drink.
+    Exhaustion(i8),
+}
+
+/// Advantage, disadvantage and automatic failure on a d20 roll.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RollModifiers {
+    /// The roll has advantage.
+    pub advantage: bool,
+    /// The roll has disadvantage.
+    pub disadvantage: bool,
+    /// The roll fails no matter the result.
+    pub auto_fail: bool,
+}
+
+impl RollModifiers {
+    /// Adds advantage and disadvantage to `test`.
+    pub fn apply(&self, test: &mut D20Test) {
+        if self.advantage {
+            test.add_advantage();
+        }
+        if self.disadvantage {
+            test.add_disadvantage();
+        }
+    }
+}
+
+/// All conditions affecting a creature, with their combined effects.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Conditions {
+    conditions: Vec<Condition>,
+}
+
+impl Conditions {
+    /// Collects the conditions of a character.
+    pub fn of(character: &dyn Character<SRDRules>) -> Self {
+        Self {
+            conditions: character
+                .statuses()
+                .map(|status| status.status().clone())
+                .collect(),
+        }
+    }
+
+    /// Returns an iterator over all conditions.
+    pub fn iter(&self) -> impl Iterator<Item = &Condition> {
+        self.conditions.iter()
+    }
+
+    /// Returns the condition with the given id.
+    pub fn get(&self, id: ConditionId) -> Option<&Condition> {
+        self.conditions.iter().find(|condition| condition.id == id)
+    }
+
+    /// Returns true if the creature has the condition.
+    pub fn has(&self, id: ConditionId) -> bool {
+        self.get(id).is_some()
+    }
+
+    fn has_any(&self, ids: &[ConditionId]) -> bool {
+        ids.iter().any(|id| self.has(*id))
+    }
+
+    /// Returns the level of exhaustion.
+    pub fn exhaustion(&self) -> u8 {
+        self.get(ConditionId::Exhaustion)
+            .map_or(0, |condition| condition.level)
+    }
+
+    /// Returns true if the creature can't take actions or reactions.
+    pub fn is_incapacitated(&self) -> bool {
+        use ConditionId::*;
+        self.has_any(&[Incapacitated, Paralyzed, Petrified, Stunned, Unconscious])
+    }
+
+    /// Returns the creature's speeds once its conditions are taken into account.
+    pub fn speeds(&self, speeds: &Speeds) -> Speeds {
+        use ConditionId::*;
+        let stopped = self.has_any(&[
+            Grappled,
+            Restrained,
+            Paralyzed,
+            Petrified,
+            Stunned,
+            Unconscious,
+        ]) || self.exhaustion() >= 5;
+        let adjust = |speed: u16| {
+            if stopped {
+                0
+            } else if self.exhaustion() >= 2 {
+                speed / 2
+            } else {
+                speed
+            }
+        };
+        Speeds {
+            walk: adjust(speeds.walk),
+            fly: adjust(speeds.fly),
+            swim: adjust(speeds.swim),
+            climb: adjust(speeds.climb),
+            burrow: adjust(speeds.burrow),
+        }
+    }
+
+    /// Returns the creature's hit points once its conditions are taken into account: the hit
+    /// point maximum is halved from the fourth level of exhaustion, and the current hit points
+    /// can't exceed it.
+    pub fn hit_points(&self, hit_points: &HitPoints) -> HitPoints {
+        let mut hit_points = *hit_points;
+        if self.exhaustion() >= 4 {
+            hit_points.set_max(hit_points.max() / 2);
+        }
+        hit_points
+    }
+
+    /// Returns true if the creature can't attack `target`.
+    pub fn forbids_attack(&self, target: &CharacterId) -> bool {
+        self.get(ConditionId::Charmed)
+            .is_some_and(|condition| condition.source() == Some(target))
+    }
+
+    /// Returns the modifiers to checks and saving throws of the creature.
+    pub fn check(&self, kind: CheckKind) -> RollModifiers {
+        use ConditionId::*;
+        match kind {
+            CheckKind::Ability(_) | CheckKind::Skill(_) => RollModifiers {
+                disadvantage: self.has_any(&[Frightened, Poisoned]) || self.exhaustion() >= 1,
+                ..RollModifiers::default()
+            },
+            CheckKind::SavingThrow(ability) => RollModifiers {
+                disadvantage: (ability == DEXTERITY && self.has(Restrained))
+                    || self.exhaustion() >= 3,
+                auto_fail: (ability == STRENGTH || ability == DEXTERITY)
+                    && self.has_any(&[Paralyzed, Petrified, Stunned, Unconscious]),
+                ..RollModifiers::default()
+            },
+        }
+    }
+
+    /// Returns the modifiers to the creature's attack rolls.
+    pub fn attacking(&self) -> RollModifiers {
+        use ConditionId::*;
+        RollModifiers {
+            advantage: self.has(Invisible),
+            disadvantage: self.has_any(&[Blinded, Frightened, Poisoned, Prone, Restrained])
+                || self.exhaustion() >= 3,
+            ..RollModifiers::default()
+        }
+    }
+
+    /// Returns the modifiers to attack rolls against the creature, made by an attacker within
+    /// 5 feet or farther away.
+    pub fn defending(&self, within_5_ft: bool) -> RollModifiers {
+        use ConditionId::*;
+        let prone = self.has(Prone);
+        RollModifiers {
+            advantage: self.has_any(&[
+                Blinded,
+                Paralyzed,
+                Petrified,
+                Restrained,
+                Stunned,
+                Unconscious,
+            ]) || (prone && within_5_ft),
+            disadvantage: self.has(Invisible) || (prone && !within_5_ft),
+            ..RollModifiers::default()
+        }
+    }
+
+    /// Returns true if any hit against the creature is a critical hit.
+    pub fn suffers_critical_hits(&self, within_5_ft: bool) -> bool {
+        within_5_ft && self.has_any(&[ConditionId::Paralyzed, ConditionId::Unconscious])
+    }
+
+    /// Returns true if the creature has resistance to all damage.
+    pub fn resists_all_damage(&self) -> bool {
+        self.has(ConditionId::Petrified)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conditions(ids: &[ConditionId]) -> Conditions {
+        Conditions {
+            conditions: ids
+                .iter()
+                .map(|id| Condition::new(*id, ConditionPotency::default()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn combined_effects() {
+        let none = conditions(&[]);
+        assert_eq!(none.attacking(), RollModifiers::default());
+        assert_eq!(none.speeds(&Speeds::walking(30)), Speeds::walking(30));
+        let stunned = conditions(&[ConditionId::Stunned]);
+        assert!(stunned.is_incapacitated());
+        assert_eq!(stunned.speeds(&Speeds::walking(30)).walk, 0);
+        assert!(stunned.check(CheckKind::SavingThrow(STRENGTH)).auto_fail);
+        assert!(stunned.defending(false).advantage);
+        assert!(!stunned.suffers_critical_hits(true));
+        let prone = conditions(&[ConditionId::Prone, ConditionId::Poisoned]);
+        assert!(prone.attacking().disadvantage);
+        assert!(prone.defending(true).advantage);
+        assert!(prone.defending(false).disadvantage);
+        assert!(prone.check(CheckKind::Ability(STRENGTH)).disadvantage);
+        assert!(!prone.check(CheckKind::SavingThrow(STRENGTH)).disadvantage);
+    }
+
+    #[test]
+    fn exhaustion_levels() {
+        let mut exhaustion = Condition::new(
+            ConditionId::Exhaustion,
+            ConditionPotency {
+                levels: 2,
+                ..ConditionPotency::default()
+            },
+        );
+        let exhausted = |condition: &Condition| Conditions {
+            conditions: vec![condition.clone()],
+        };
+        assert_eq!(exhausted(&exhaustion).speeds(&Speeds::walking(30)).walk, 15);
+        assert!(!exhausted(&exhaustion).attacking().disadvantage);
+        exhaustion.change_level(1);
+        assert!(exhausted(&exhaustion).attacking().disadvantage);
+        assert!(
+            exhausted(&exhaustion)
+                .check(CheckKind::SavingThrow(DEXTERITY))
+                .disadvantage
+        );
+        let hit_points = HitPoints::new(15, 21);
+        assert_eq!(exhausted(&exhaustion).hit_points(&hit_points), hit_points);
+        exhaustion.change_level(1);
+        assert_eq!(
+            exhausted(&exhaustion).hit_points(&hit_points),
+            HitPoints::new(10, 10)
+        );
+        exhaustion.change_level(10);
+        assert_eq!(exhaustion.level(), MAX_EXHAUSTION_LEVEL);
+        assert_eq!(exhausted(&exhaustion).speeds(&Speeds::walking(30)).walk, 0);
+        exhaustion.change_level(-10);
+        assert_eq!(exhaustion.level(), 0);
+    }
+}
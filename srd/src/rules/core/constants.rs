@@ -2,3 +2,9 @@
 
 /// Size of a square's side in feet.
 pub const SQUARE_FT: f32 = 2.5;
+
+/// Reach in feet of melee attacks without a more specific reach.
+pub const DEFAULT_REACH_FT: u16 = 5;
+
+/// Walking speed in feet of creatures without a more specific speed.
+pub const WALKING_SPEED_FT: u16 = 30;
@@ -0,0 +1,78 @@
+//! Cover granted by walls and creatures.
+
+use crate::ability::DEXTERITY;
+use crate::check::CheckKind;
+use serde::{Deserialize, Serialize};
+
+/// How much a target is protected by obstacles between it and an attack or effect.
+///
+/// # Reference
+///
+/// Walls, trees, creatures, and other obstacles can provide cover during combat, making a target
+/// more difficult to harm. A target can benefit from cover only when an attack or other effect
+/// originates on the opposite side of the cover.\
+/// There are three degrees of cover. If a target is behind multiple sources of cover, only the
+/// most protective degree of cover applies; the degrees aren't added together.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Cover {
+    /// Nothing stands in the way.
+    #[default]
+    None,
+    /// A target with half cover has a +2 bonus to AC and Dexterity saving throws. A target has
+    /// half cover if an obstacle blocks at least half of its body.
+    Half,
+    /// A target with three-quarters cover has a +5 bonus to AC and Dexterity saving throws. A
+    /// target has three-quarters cover if about three-quarters of it is covered by an obstacle.
+    ThreeQuarters,
+    /// A target with total cover can't be targeted directly by an attack or a spell, although
+    /// some spells can reach such a target by including it in an area of effect. A target has
+    /// total cover if it is completely concealed by an obstacle.
+    Total,
+}
+
+impl Cover {
+    /// Returns the bonus to armor class granted by this cover.
+    pub const fn armor_class_bonus(&self) -> u8 {
+        match self {
+            Self::Half => 2,
+            Self::ThreeQuarters => 5,
+            Self::None | Self::Total => 0,
+        }
+    }
+
+    /// Returns the bonus granted by this cover to a check of the given kind. Only Dexterity
+    /// saving throws benefit from cover.
+    pub fn check_bonus(&self, kind: CheckKind) -> i16 {
+        if kind == CheckKind::SavingThrow(DEXTERITY) {
+            i16::from(self.armor_class_bonus())
+        } else {
+            0
+        }
+    }
+
+    /// Returns true if the target can be targeted directly.
+    pub const fn allows_targeting(&self) -> bool {
+        !matches!(self, Self::Total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability::STRENGTH;
+
+    #[test]
+    fn cover_bonuses() {
+        assert_eq!(Cover::Half.armor_class_bonus(), 2);
+        assert_eq!(Cover::ThreeQuarters.armor_class_bonus(), 5);
+        assert_eq!(
+            Cover::ThreeQuarters.check_bonus(CheckKind::SavingThrow(DEXTERITY)),
+            5
+        );
+        assert_eq!(Cover::Half.check_bonus(CheckKind::SavingThrow(STRENGTH)), 0);
+        assert!(!Cover::Total.allows_targeting());
+        assert!(Cover::Half < Cover::Total);
+    }
+}
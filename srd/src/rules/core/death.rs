@@ -0,0 +1,199 @@
+//! Dropping to 0 hit points, death saving throws and death.
+
+use crate::character::CharacterId;
+use crate::check::DifficultyClass;
+use crate::dice::{D20Test, D20TestResult, Roller};
+use serde::{Deserialize, Serialize};
+
+/// Number of successes or failures needed to end a sequence of death saving throws.
+pub const DEATH_SAVING_THROWS_LIMIT: u8 = 3;
+
+/// Difficulty class of a death saving throw.
+pub const DEATH_SAVING_THROW_DC: DifficultyClass = DifficultyClass(10);
+
+/// Difficulty class of the Wisdom (Medicine) check to stabilize a dying creature.
+pub const STABILIZE_DC: DifficultyClass = DifficultyClass(10);
+
+/// Successes and failures of a dying creature's death saving throws.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeathSavingThrows {
+    /// Number of successful death saving throws.
+    pub successes: u8,
+    /// Number of failed death saving throws.
+    pub failures: u8,
+}
+
+/// Describes whether a creature is alive and conscious.
+///
+/// # Reference
+///
+/// When you drop to 0 hit points, you either die outright or fall unconscious.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LifeState {
+    /// The creature has at least 1 hit point.
+    #[default]
+    Conscious,
+    /// The creature is at 0 hit points, unconscious and making death saving throws.
+    Dying(DeathSavingThrows),
+    /// The creature is at 0 hit points and unconscious, but it no longer makes death saving throws.
+    Stable,
+    /// The creature is dead.
+    Dead,
+}
+
+impl LifeState {
+    /// Returns true if the creature is conscious.
+    pub const fn is_conscious(&self) -> bool {
+        matches!(self, Self::Conscious)
+    }
+
+    /// Returns true if the creature must make death saving throws.
+    pub const fn is_dying(&self) -> bool {
+        matches!(self, Self::Dying(_))
+    }
+
+    /// Returns true if the creature is dead.
+    pub const fn is_dead(&self) -> bool {
+        matches!(self, Self::Dead)
+    }
+
+    /// Adds the outcome of a death saving throw whose d20 roll is `natural`.
+    /// Nothing happens if the creature isn't dying.
+    ///
+    /// # Reference
+    ///
+    /// Roll a d20. If the roll is 10 or higher, you succeed. Otherwise, you fail.\
+    /// On your third success, you become stable. On your third failure, you die.\
+    /// When you make a death saving throw and roll a 1 on the d20, it counts as two failures.
+    /// If you roll a 20 on the d20, you regain 1 hit point.
+    pub fn death_saving_throw(&mut self, natural: u8) {
+        if let Self::Dying(throws) = self {
+            match natural {
+                20 => *self = Self::Conscious,
+                1 => self.add_failures(2),
+                n if n >= DEATH_SAVING_THROW_DC.0 => {
+                    throws.successes += 1;
+                    if throws.successes >= DEATH_SAVING_THROWS_LIMIT {
+                        *self = Self::Stable;
+                    }
+                }
+                _ => self.add_failures(1),
+            }
+        }
+    }
+
+    /// Takes damage while at 0 hit points.
+    ///
+    /// # Reference
+    ///
+    /// If you take any damage while you have 0 hit points, you suffer a death saving throw
+    /// failure. If the damage is from a critical hit, you suffer two failures instead.
+    pub fn damage_at_zero(&mut self, critical: bool) {
+        let failures = if critical { 2 } else { 1 };
+        if let Self::Stable = self {
+            *self = Self::Dying(DeathSavingThrows::default());
+        }
+        self.add_failures(failures);
+    }
+
+    /// Stabilizes a dying creature.
+    ///
+    /// # Reference
+    ///
+    /// A stable creature doesn't make death saving throws, even though it has 0 hit points,
+    /// but it does remain unconscious.
+    pub fn stabilize(&mut self) {
+        if let Self::Dying(_) = self {
+            *self = Self::Stable;
+        }
+    }
+
+    fn add_failures(&mut self, failures: u8) {
+        if let Self::Dying(throws) = self {
+            throws.failures += failures;
+            if throws.failures >= DEATH_SAVING_THROWS_LIMIT {
+                *self = Self::Dead;
+            }
+        }
+    }
+}
+
+/// The result of a death saving throw.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeathSavingThrowResult {
+    /// The dying creature.
+    pub creature: CharacterId,
+    /// The d20 roll.
+    pub roll: D20TestResult,
+}
+
+impl DeathSavingThrowResult {
+    /// Rolls a death saving throw for `creature`.
+    pub fn roll<R: Roller + ?Sized>(creature: CharacterId, roller: &mut R) -> Self {
+        Self {
+            creature,
+            roll: D20Test::new(0).roll(roller),
+        }
+    }
+
+    /// Returns true if the death saving throw is successful.
+    pub fn success(&self) -> bool {
+        self.roll.total() >= i32::from(DEATH_SAVING_THROW_DC.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dying() -> LifeState {
+        LifeState::Dying(DeathSavingThrows::default())
+    }
+
+    #[test]
+    fn death_saving_throws() {
+        let mut state = dying();
+        state.death_saving_throw(10);
+        state.death_saving_throw(9);
+        state.death_saving_throw(15);
+        assert_eq!(
+            state,
+            LifeState::Dying(DeathSavingThrows {
+                successes: 2,
+                failures: 1
+            })
+        );
+        state.death_saving_throw(12);
+        assert_eq!(state, LifeState::Stable);
+        let mut state = dying();
+        state.death_saving_throw(1);
+        assert!(state.is_dying());
+        state.death_saving_throw(2);
+        assert!(state.is_dead());
+        let mut state = dying();
+        state.death_saving_throw(20);
+        assert!(state.is_conscious());
+    }
+
+    #[test]
+    fn damage_at_zero_hit_points() {
+        let mut state = LifeState::Stable;
+        state.damage_at_zero(false);
+        assert_eq!(
+            state,
+            LifeState::Dying(DeathSavingThrows {
+                successes: 0,
+                failures: 1
+            })
+        );
+        state.damage_at_zero(true);
+        assert!(state.is_dead());
+        let mut state = dying();
+        state.stabilize();
+        assert_eq!(state, LifeState::Stable);
+        let mut state = LifeState::Conscious;
+        state.stabilize();
+        state.damage_at_zero(true);
+        assert!(state.is_conscious());
+    }
+}
@@ -0,0 +1,325 @@
+//! What a creature can do during its turn.
+
+use crate::error::{SRDError, SRDResult};
+use crate::rules::core::movement::{ft_to_squares, squares_to_ft, MovementMode, Speeds};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a creature spends to take an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ActionCost {
+    /// The creature's action.
+    Action,
+    /// The creature's bonus action.
+    BonusAction,
+    /// The creature's reaction.
+    Reaction,
+    /// Part of the creature's movement. Each foot moved is paid separately.
+    Movement,
+    /// The free interaction with an object.
+    ObjectInteraction,
+    /// Nothing at all.
+    Free,
+}
+
+impl fmt::Display for ActionCost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ActionCost::*;
+        match self {
+            Action => write!(f, "action"),
+            BonusAction => write!(f, "bonus action"),
+            Reaction => write!(f, "reaction"),
+            Movement => write!(f, "movement"),
+            ObjectInteraction => write!(f, "object interaction"),
+            Free => write!(f, "free action"),
+        }
+    }
+}
+
+/// Tracks the actions, bonus actions, reaction, movement and object interactions
+/// still available to a creature.
+///
+/// # Reference
+///
+/// On your turn, you can move a distance up to your speed and take one action.\
+/// You can take a bonus action only when a special ability, spell, or other feature of the game
+/// states that you can do something as a bonus action.\
+/// You can also interact with one object or feature of the environment for free, during either
+/// your move or your action.\
+/// When you take a reaction, you can't take another one until the start of your next turn.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TurnBudget {
+    speeds: Speeds,
+    actions: u8,
+    bonus_actions: u8,
+    reactions: u8,
+    moved: u16,
+    dashes: u8,
+    object_interactions: u8,
+    disengaged: bool,
+    attacks: u8,
+    attack_action: bool,
+}
+
+impl TurnBudget {
+    /// Creates a full budget for a creature with the given speeds.
+    pub const fn new(speeds: Speeds) -> Self {
+        Self {
+            speeds,
+            actions: 1,
+            bonus_actions: 1,
+            reactions: 1,
+            moved: 0,
+            dashes: 0,
+            object_interactions: 1,
+            disengaged: false,
+            attacks: 0,
+            attack_action: false,
+        }
+    }
+
+    /// Returns the creature's walking speed, in feet.
+    pub const fn speed(&self) -> u16 {
+        self.speeds.walk
+    }
+
+    /// Returns all the creature's speeds.
+    pub const fn speeds(&self) -> &Speeds {
+        &self.speeds
+    }
+
+    /// Returns this budget as if the creature had the given speeds, for example after its
+    /// conditions have reduced them.
+    pub const fn with_speeds(self, speeds: Speeds) -> Self {
+        Self { speeds, ..self }
+    }
+
+    /// Returns the number of actions left.
+    pub const fn actions(&self) -> u8 {
+        self.actions
+    }
+
+    /// Returns the number of bonus actions left.
+    pub const fn bonus_actions(&self) -> u8 {
+        self.bonus_actions
+    }
+
+    /// Returns the number of reactions left.
+    pub const fn reactions(&self) -> u8 {
+        self.reactions
+    }
+
+    /// Returns the walking movement left, in feet.
+    pub fn movement(&self) -> u16 {
+        squares_to_ft(self.movement_sq(MovementMode::Walk))
+    }
+
+    /// Returns the movement left when moving with `mode`, in squares.
+    ///
+    /// # Reference
+    ///
+    /// If you have more than one speed, you can switch back and forth between your speeds during
+    /// your move. Whenever you switch, subtract the distance you've already moved from the new
+    /// speed.
+    pub fn movement_sq(&self, mode: MovementMode) -> u16 {
+        self.total_movement_sq(self.speeds.speed(mode))
+            .saturating_sub(self.moved)
+    }
+
+    /// Returns the number of free object interactions left.
+    pub const fn object_interactions(&self) -> u8 {
+        self.object_interactions
+    }
+
+    /// Returns the number of attacks left from an Attack action already taken this turn.
+    pub const fn attacks(&self) -> u8 {
+        self.attacks
+    }
+
+    /// Returns true if the creature can make an attack, either with an attack left from a
+    /// previous Attack action or by taking a new one.
+    pub const fn can_attack(&self) -> bool {
+        self.attacks > 0 || self.actions > 0
+    }
+
+    /// Spends one attack. If no attacks are left, an action is taken and the creature gets
+    /// `attacks_per_action - 1` more attacks this turn.
+    ///
+    /// # Reference
+    ///
+    /// If an ability, such as Extra Attack, allows you to make more than one attack with the
+    /// Attack action, you can move between those attacks.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature has no attacks nor actions left.
+    pub fn spend_attack(&mut self, attacks_per_action: u8) -> SRDResult<()> {
+        if self.attacks > 0 {
+            self.attacks -= 1;
+        } else {
+            self.spend(ActionCost::Action)?;
+            self.attacks = attacks_per_action.saturating_sub(1);
+            self.attack_action = true;
+        }
+        Ok(())
+    }
+
+    /// Returns true if the creature took the Attack action this turn.
+    pub const fn took_attack_action(&self) -> bool {
+        self.attack_action
+    }
+
+    /// Returns true if the creature took the Disengage action this turn.
+    pub const fn disengaged(&self) -> bool {
+        self.disengaged
+    }
+
+    /// Marks the creature as disengaged until the end of the turn.
+    ///
+    /// # Reference
+    ///
+    /// If you take the Disengage action, your movement doesn't provoke opportunity attacks for
+    /// the rest of the turn.
+    pub fn disengage(&mut self) {
+        self.disengaged = true;
+    }
+
+    /// Returns the squares a creature with the given speed can move in this turn, dashes included.
+    fn total_movement_sq(&self, speed: u16) -> u16 {
+        ft_to_squares(speed).saturating_mul(u16::from(self.dashes) + 1)
+    }
+
+    /// Returns true if the creature can pay `cost`.
+    pub fn can_afford(&self, cost: ActionCost) -> bool {
+        use ActionCost::*;
+        match cost {
+            Action => self.actions > 0,
+            BonusAction => self.bonus_actions > 0,
+            Reaction => self.reactions > 0,
+            Movement => self.total_movement_sq(self.speeds.max()) > self.moved,
+            // Interacting with a second object requires an action.
+            ObjectInteraction => self.object_interactions > 0 || self.actions > 0,
+            Free => true,
+        }
+    }
+
+    /// Pays `cost`. Movement is paid with `spend_movement` instead.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature can't afford `cost`.
+    pub fn spend(&mut self, cost: ActionCost) -> SRDResult<()> {
+        if !self.can_afford(cost) {
+            return Err(SRDError::CostNotAffordable(cost));
+        }
+        use ActionCost::*;
+        match cost {
+            Action => self.actions -= 1,
+            BonusAction => self.bonus_actions -= 1,
+            Reaction => self.reactions -= 1,
+            ObjectInteraction if self.object_interactions > 0 => self.object_interactions -= 1,
+            ObjectInteraction => self.actions -= 1,
+            Movement | Free => {}
+        }
+        Ok(())
+    }
+
+    /// Spends `squares` of movement while moving with `mode`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the creature doesn't have enough movement left.
+    pub fn spend_movement(&mut self, mode: MovementMode, squares: u16) -> SRDResult<()> {
+        if squares > self.movement_sq(mode) {
+            return Err(SRDError::CostNotAffordable(ActionCost::Movement));
+        }
+        self.moved += squares;
+        Ok(())
+    }
+
+    /// Grants one more use of `cost` for this turn, as Action Surge does for actions.\
+    /// Movement is increased by the creature's speed, as when dashing.
+    ///
+    /// # Reference
+    ///
+    /// When you take the Dash action, you gain extra movement for the current turn. The increase
+    /// equals your speed, after applying any modifiers.
+    pub fn grant(&mut self, cost: ActionCost) {
+        use ActionCost::*;
+        match cost {
+            Action => self.actions = self.actions.saturating_add(1),
+            BonusAction => self.bonus_actions = self.bonus_actions.saturating_add(1),
+            Reaction => self.reactions = self.reactions.saturating_add(1),
+            Movement => self.dashes = self.dashes.saturating_add(1),
+            ObjectInteraction => {
+                self.object_interactions = self.object_interactions.saturating_add(1)
+            }
+            Free => {}
+        }
+    }
+
+    /// Restores the whole budget at the start of the creature's turn.
+    pub fn refresh(&mut self) {
+        *self = Self::new(self.speeds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spend_budget() {
+        let mut budget = TurnBudget::new(Speeds::walking(30));
+        assert!(budget.spend(ActionCost::Action).is_ok());
+        assert_eq!(
+            budget.spend(ActionCost::Action),
+            Err(SRDError::CostNotAffordable(ActionCost::Action))
+        );
+        assert!(budget.spend(ActionCost::BonusAction).is_ok());
+        assert!(!budget.can_afford(ActionCost::BonusAction));
+        assert!(budget.spend(ActionCost::Free).is_ok());
+        assert!(budget.spend_movement(MovementMode::Walk, 8).is_ok());
+        assert!(budget.spend_movement(MovementMode::Walk, 6).is_err());
+        assert_eq!(budget.movement(), 10);
+        // Dash and Action Surge.
+        budget.grant(ActionCost::Movement);
+        budget.grant(ActionCost::Action);
+        assert_eq!(budget.movement(), 40);
+        budget.disengage();
+        assert!(budget.disengaged());
+        assert!(budget.can_afford(ActionCost::Action));
+        // Switching to a slower speed.
+        assert!(budget.spend_movement(MovementMode::Climb, 16).is_ok());
+        assert_eq!(budget.movement_sq(MovementMode::Climb), 0);
+        assert!(!budget.can_afford(ActionCost::Movement));
+        budget.refresh();
+        assert_eq!(budget, TurnBudget::new(Speeds::walking(30)));
+    }
+
+    #[test]
+    fn extra_attacks() {
+        let mut budget = TurnBudget::new(Speeds::walking(30));
+        assert!(budget.can_attack());
+        assert!(budget.spend_attack(2).is_ok());
+        assert_eq!(budget.actions(), 0);
+        assert_eq!(budget.attacks(), 1);
+        assert!(budget.can_attack());
+        assert!(budget.spend_attack(2).is_ok());
+        assert!(!budget.can_attack());
+        assert_eq!(
+            budget.spend_attack(2),
+            Err(SRDError::CostNotAffordable(ActionCost::Action))
+        );
+    }
+
+    #[test]
+    fn second_object_interaction_costs_an_action() {
+        let mut budget = TurnBudget::new(Speeds::walking(30));
+        assert!(budget.spend(ActionCost::ObjectInteraction).is_ok());
+        assert_eq!(budget.actions(), 1);
+        assert!(budget.spend(ActionCost::ObjectInteraction).is_ok());
+        assert_eq!(budget.actions(), 0);
+        assert!(!budget.can_afford(ActionCost::ObjectInteraction));
+    }
+}
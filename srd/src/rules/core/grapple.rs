@@ -0,0 +1,107 @@
+//! Grappling and shoving creatures.
+
+use crate::check::{Check, CheckSubject};
+use crate::error::SRDResult;
+use crate::rules::core::size::CreatureSize;
+use crate::skill::{ACROBATICS, ATHLETICS};
+use serde::{Deserialize, Serialize};
+
+/// Distance, in feet, a creature is pushed away by a successful shove.
+pub const SHOVE_DISTANCE_FT: u16 = 5;
+
+/// What a creature attempts when it shoves another one.
+///
+/// # Reference
+///
+/// If you win the contest, you either knock the target prone or push it 5 feet away from you.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShoveEffect {
+    /// Knock the target prone.
+    KnockProne,
+    /// Push the target 5 feet away.
+    Push,
+}
+
+/// Returns true if a creature of size `attacker` can grapple or shove a creature of
+/// size `target`.
+///
+/// # Reference
+///
+/// The target of your grapple must be no more than one size larger than you.
+pub fn within_size_limit(attacker: CreatureSize, target: CreatureSize) -> bool {
+    target as u8 <= attacker as u8 + 1
+}
+
+/// Returns true if a creature of size `grappler` moves at half speed while dragging a grappled
+/// creature of size `grappled`.
+///
+/// # Reference
+///
+/// When you move, you can drag or carry the grappled creature with you, but your speed is
+/// halved, unless the creature is two or more sizes smaller than you.
+pub fn drags_at_half_speed(grappler: CreatureSize, grappled: CreatureSize) -> bool {
+    grappled as u8 + 2 > grappler as u8
+}
+
+/// Returns the check made by a creature attempting to grapple or shove: Strength (Athletics).
+pub fn grapple_check() -> Check {
+    Check::skill(ATHLETICS)
+}
+
+/// Returns the check `subject` makes to resist a grapple or a shove, or to escape a grapple:
+/// either Strength (Athletics) or Dexterity (Acrobatics), whichever is better.
+///
+/// # Reference
+///
+/// The target chooses the ability to use.
+///
+/// # Errors
+///
+/// An error is returned if `subject` lacks the statistics required by the checks.
+pub fn resisting_check<S: CheckSubject + ?Sized>(subject: &S) -> SRDResult<Check> {
+    let athletics = Check::skill(ATHLETICS);
+    let acrobatics = Check::skill(ACROBATICS);
+    if acrobatics.modifier(subject)? > athletics.modifier(subject)? {
+        Ok(acrobatics)
+    } else {
+        Ok(athletics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability::{AbilityScore, DEXTERITY};
+    use crate::character::class::fighter::FIGHTER;
+    use crate::character::race::hill_dwarf::HILL_DWARF;
+    use crate::character::Character;
+    use crate::check::CheckKind;
+
+    #[test]
+    fn size_limits() {
+        use CreatureSize::*;
+        assert!(within_size_limit(Medium, Large));
+        assert!(within_size_limit(Medium, Tiny));
+        assert!(!within_size_limit(Medium, Huge));
+        assert!(!within_size_limit(Tiny, Medium));
+        assert!(drags_at_half_speed(Medium, Medium));
+        assert!(drags_at_half_speed(Medium, Small));
+        assert!(!drags_at_half_speed(Medium, Tiny));
+        assert!(!drags_at_half_speed(Huge, Medium));
+    }
+
+    #[test]
+    fn best_resisting_check() {
+        let _ = crate::init_srd_compendium();
+        let mut character = Character::new("one", HILL_DWARF, FIGHTER).unwrap();
+        assert_eq!(
+            resisting_check(&character).unwrap().kind(),
+            CheckKind::Skill(ATHLETICS)
+        );
+        character.add_ability(DEXTERITY, AbilityScore::new(16).unwrap());
+        assert_eq!(
+            resisting_check(&character).unwrap().kind(),
+            CheckKind::Skill(ACROBATICS)
+        );
+    }
+}
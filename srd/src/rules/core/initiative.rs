@@ -0,0 +1,265 @@
+//! Initiative and order of turns.
+
+use crate::ability::AbilityScore;
+use crate::character::CharacterId;
+use crate::dice::{D20Test, D20TestResult, Die, Roller};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// The initiative of a creature.
+///
+/// # Reference
+///
+/// Initiative determines the order of turns during combat. When combat starts, every participant
+/// makes a Dexterity check to determine their place in the initiative order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Initiative {
+    /// The creature.
+    pub creature: CharacterId,
+    /// The Dexterity check.
+    pub roll: D20TestResult,
+    /// The creature's Dexterity score, used to break ties.
+    pub dexterity: AbilityScore,
+    /// A d20 roll, used to break ties between creatures with the same Dexterity score.
+    pub tiebreaker: u8,
+    /// Whether the creature is surprised.
+    pub surprised: bool,
+}
+
+impl Initiative {
+    /// Rolls the initiative for `creature`. `bonus` is added to the Dexterity modifier, for
+    /// example the proficiency bonus that applies to the creature's Dexterity checks.
+    pub fn roll<R: Roller + ?Sized>(
+        creature: CharacterId,
+        dexterity: AbilityScore,
+        bonus: i16,
+        roller: &mut R,
+    ) -> Self {
+        let roll = D20Test::new(i16::from(dexterity.modifier()) + bonus).roll(roller);
+        let tiebreaker = roller.roll_die(Die::D20);
+        Self {
+            creature,
+            roll,
+            dexterity,
+            tiebreaker,
+            surprised: false,
+        }
+    }
+
+    /// Returns the total of the initiative roll.
+    pub fn total(&self) -> i32 {
+        self.roll.total()
+    }
+
+    /// Orders initiatives from the highest to the lowest.
+    ///
+    /// # Reference
+    ///
+    /// The GM ranks the combatants in order from the one with the highest Dexterity check total
+    /// to the one with the lowest. This is the order (called the initiative order) in which they
+    /// act during each round.\
+    /// If a tie occurs, the GM decides the order among tied GM-controlled creatures, and the
+    /// players decide the order among their tied characters.
+    fn precedence(&self, other: &Self) -> Ordering {
+        other
+            .total()
+            .cmp(&self.total())
+            .then_with(|| other.dexterity.value().cmp(&self.dexterity.value()))
+            .then_with(|| other.tiebreaker.cmp(&self.tiebreaker))
+    }
+}
+
+/// The order in which creatures take their turns.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InitiativeOrder {
+    entries: Vec<Initiative>,
+    current: usize,
+    started: bool,
+}
+
+impl InitiativeOrder {
+    /// Returns an iterator over all initiatives, from the first to act to the last.
+    pub fn order(&self) -> impl Iterator<Item = &Initiative> {
+        self.entries.iter()
+    }
+
+    /// Returns the initiative of a creature.
+    pub fn initiative(&self, creature: &CharacterId) -> Option<&Initiative> {
+        self.entries.iter().find(|e| e.creature == *creature)
+    }
+
+    /// Returns the creature that acts now.
+    pub fn current(&self) -> Option<&CharacterId> {
+        self.entries.get(self.current).map(|e| &e.creature)
+    }
+
+    /// Returns the number of creatures in the initiative order.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the initiative order is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds a creature to the initiative order, replacing its previous initiative if any.\
+    /// Once the first turn has started, the creature acting now is not changed.
+    pub fn add(&mut self, initiative: Initiative) {
+        self.remove(&initiative.creature);
+        let index = self
+            .entries
+            .iter()
+            .position(|e| initiative.precedence(e) == Ordering::Less)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(index, initiative);
+        if self.started && index <= self.current && self.entries.len() > 1 {
+            self.current += 1;
+        }
+        self.skip_surprised();
+    }
+
+    /// Removes a creature from the initiative order and returns its initiative.
+    pub fn remove(&mut self, creature: &CharacterId) -> Option<Initiative> {
+        let index = self.entries.iter().position(|e| e.creature == *creature)?;
+        let initiative = self.entries.remove(index);
+        if index < self.current {
+            self.current -= 1;
+        }
+        if self.current >= self.entries.len() {
+            self.current = 0;
+        }
+        self.skip_surprised();
+        Some(initiative)
+    }
+
+    /// Marks a creature as surprised. Returns false if the creature is not in the initiative
+    /// order.
+    ///
+    /// # Reference
+    ///
+    /// If you're surprised, you can't move or take an action on your first turn of the combat,
+    /// and you can't take a reaction until that turn ends.
+    pub fn surprise(&mut self, creature: &CharacterId) -> bool {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.creature == *creature) {
+            entry.surprised = true;
+            self.skip_surprised();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Marks the beginning of a turn.
+    pub(crate) fn start(&mut self) {
+        self.started = true;
+    }
+
+    /// Passes the turn to the next creature.
+    pub(crate) fn advance(&mut self) {
+        if !self.entries.is_empty() {
+            self.current = (self.current + 1) % self.entries.len();
+            self.skip_surprised();
+        }
+    }
+
+    /// Surprised creatures lose their first turn.
+    fn skip_surprised(&mut self) {
+        for _ in 0..self.entries.len() {
+            match self.entries.get_mut(self.current) {
+                Some(entry) if entry.surprised => {
+                    log::debug!("{:?} is surprised and loses its turn", entry.creature);
+                    entry.surprised = false;
+                    self.current = (self.current + 1) % self.entries.len();
+                }
+                _ => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::roll::SequenceRoller;
+
+    fn initiative(creature: &str, dexterity: u8, d20: u8, tiebreaker: u8) -> Initiative {
+        Initiative::roll(
+            creature.into(),
+            AbilityScore::new(dexterity).unwrap(),
+            0,
+            &mut SequenceRoller::new(vec![d20, tiebreaker]),
+        )
+    }
+
+    fn names(order: &InitiativeOrder) -> Vec<&str> {
+        order.order().map(|e| e.creature.0.as_str()).collect()
+    }
+
+    #[test]
+    fn descending_order_with_ties() {
+        let mut order = InitiativeOrder::default();
+        order.add(initiative("slow", 10, 5, 20));
+        order.add(initiative("fast", 10, 15, 1));
+        // Same total as 'fast' but higher dexterity.
+        order.add(initiative("nimble", 14, 13, 1));
+        // Same total and dexterity as 'fast' but a higher tiebreaker.
+        order.add(initiative("lucky", 10, 15, 12));
+        assert_eq!(names(&order), vec!["nimble", "lucky", "fast", "slow"]);
+        assert_eq!(order.initiative(&"nimble".into()).unwrap().total(), 15);
+        // Bonuses to Dexterity checks apply to initiative.
+        let bard = Initiative::roll(
+            "bard".into(),
+            AbilityScore::new(10).unwrap(),
+            1,
+            &mut SequenceRoller::new(vec![10, 1]),
+        );
+        assert_eq!(bard.total(), 11);
+        assert_eq!(order.current(), Some(&"nimble".into()));
+    }
+
+    #[test]
+    fn turns_rotate() {
+        let mut order = InitiativeOrder::default();
+        order.add(initiative("a", 10, 20, 1));
+        order.add(initiative("b", 10, 10, 1));
+        order.add(initiative("c", 10, 1, 1));
+        order.start();
+        order.advance();
+        assert_eq!(order.current(), Some(&"b".into()));
+        // A newcomer doesn't steal the turn.
+        order.add(initiative("d", 10, 19, 1));
+        assert_eq!(order.current(), Some(&"b".into()));
+        // Removing the current creature passes the turn to the next one.
+        order.remove(&"b".into());
+        assert_eq!(order.current(), Some(&"c".into()));
+        order.advance();
+        assert_eq!(order.current(), Some(&"a".into()));
+        order.remove(&"d".into());
+        order.remove(&"c".into());
+        order.remove(&"a".into());
+        assert!(order.is_empty());
+        assert_eq!(order.current(), None);
+    }
+
+    #[test]
+    fn surprised_creatures_lose_first_turn() {
+        let mut order = InitiativeOrder::default();
+        order.add(initiative("a", 10, 20, 1));
+        order.add(initiative("b", 10, 10, 1));
+        assert!(order.surprise(&"a".into()));
+        assert!(!order.surprise(&"nobody".into()));
+        assert_eq!(order.current(), Some(&"b".into()));
+        order.start();
+        order.advance();
+        assert_eq!(order.current(), Some(&"a".into()));
+        // Everyone is surprised.
+        let mut order = InitiativeOrder::default();
+        order.add(initiative("a", 10, 20, 1));
+        order.add(initiative("b", 10, 10, 1));
+        order.surprise(&"b".into());
+        order.surprise(&"a".into());
+        assert_eq!(order.current(), Some(&"a".into()));
+        assert!(order.order().all(|e| !e.surprised));
+    }
+}
@@ -1,12 +1,44 @@
 //! Core components used by the rules.
 
 pub mod action;
-pub use self::action::{Action, ActionId, ActionInitializer, ActionsSeed};
+pub use self::action::{
+    Action, ActionId, ActionInitializer, ActionsAlteration, ActionsSeed, Activation,
+    ObjectInteraction,
+};
+
+pub mod attack;
+pub use self::attack::{
+    AttackCircumstances, AttackOutcome, AttackProfile, AttackReach, CriticalHit, Impact,
+};
 
 pub mod battlegrid;
+pub use self::battlegrid::{BattleGrid, Footprint, GridSeed, Square};
+
+pub mod condition;
+pub use self::condition::{
+    Condition, ConditionId, ConditionPotency, Conditions, ConditionsAlteration,
+};
+
+pub mod cover;
+pub use self::cover::Cover;
+
+pub mod death;
+pub use self::death::{DeathSavingThrowResult, DeathSavingThrows, LifeState};
 
 pub mod constants;
-pub use self::constants::SQUARE_FT;
+pub use self::constants::{DEFAULT_REACH_FT, SQUARE_FT, WALKING_SPEED_FT};
+
+pub mod economy;
+pub use self::economy::{ActionCost, TurnBudget};
+
+pub mod grapple;
+pub use self::grapple::ShoveEffect;
+
+pub mod initiative;
+pub use self::initiative::{Initiative, InitiativeOrder};
+
+pub mod movement;
+pub use self::movement::{MovementMode, Speeds};
 
 pub mod size;
 pub use self::size::CreatureSize;
@@ -15,3 +47,6 @@ pub mod statistic;
 pub use self::statistic::{
     Statistic, StatisticChange, StatisticId, StatisticInitializer, StatisticsSeed,
 };
+
+pub mod tactics;
+pub use self::tactics::{Help, ReadyTrigger, Tactics};
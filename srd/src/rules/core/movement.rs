@@ -0,0 +1,149 @@
+//! Speeds and ways of moving.
+
+use crate::rules::core::constants::{SQUARE_FT, WALKING_SPEED_FT};
+use serde::{Deserialize, Serialize};
+
+/// How a creature moves across the battle grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MovementMode {
+    /// Walking, using the walking speed.
+    Walk,
+    /// Crawling while prone, using the walking speed.
+    Crawl,
+    /// Flying, using the flying speed.
+    Fly,
+    /// Swimming, using the swimming speed or the walking speed.
+    Swim,
+    /// Climbing, using the climbing speed or the walking speed.
+    Climb,
+    /// Burrowing, using the burrowing speed.
+    Burrow,
+}
+
+/// The speeds of a creature, in feet. A speed of zero means that the creature can't move
+/// in that way.
+///
+/// # Reference
+///
+/// Every character and monster has a speed, which is the distance in feet that the character or
+/// monster can walk in 1 round.\
+/// Some creatures have special movement modes: burrowing, climbing, flying and swimming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Speeds {
+    /// Walking speed.
+    pub walk: u16,
+    /// Flying speed.
+    pub fly: u16,
+    /// Swimming speed.
+    pub swim: u16,
+    /// Climbing speed.
+    pub climb: u16,
+    /// Burrowing speed.
+    pub burrow: u16,
+}
+
+impl Speeds {
+    /// Creates speeds for a creature that can only walk.
+    pub const fn walking(walk: u16) -> Self {
+        Self {
+            walk,
+            fly: 0,
+            swim: 0,
+            climb: 0,
+            burrow: 0,
+        }
+    }
+
+    /// Sets the speed of a movement mode. Crawling always uses the walking speed.
+    pub fn set(&mut self, mode: MovementMode, speed: u16) {
+        use MovementMode::*;
+        match mode {
+            Walk | Crawl => self.walk = speed,
+            Fly => self.fly = speed,
+            Swim => self.swim = speed,
+            Climb => self.climb = speed,
+            Burrow => self.burrow = speed,
+        }
+    }
+
+    /// Returns the speed used when moving with `mode`.
+    ///
+    /// # Reference
+    ///
+    /// While climbing or swimming, each foot of movement costs 1 extra foot, unless a creature
+    /// has a climbing or swimming speed.
+    pub const fn speed(&self, mode: MovementMode) -> u16 {
+        use MovementMode::*;
+        match mode {
+            Walk | Crawl => self.walk,
+            Fly => self.fly,
+            Swim if self.swim > 0 => self.swim,
+            Climb if self.climb > 0 => self.climb,
+            Swim | Climb => self.walk,
+            Burrow => self.burrow,
+        }
+    }
+
+    /// Returns how many extra squares each square of movement costs when moving with `mode`.
+    ///
+    /// # Reference
+    ///
+    /// Crawling: every foot of movement costs 1 extra foot.
+    pub const fn extra_cost(&self, mode: MovementMode) -> u16 {
+        use MovementMode::*;
+        match mode {
+            Crawl => 1,
+            Swim if self.swim == 0 => 1,
+            Climb if self.climb == 0 => 1,
+            _ => 0,
+        }
+    }
+
+    /// Returns the highest of all speeds.
+    pub fn max(&self) -> u16 {
+        [self.walk, self.fly, self.swim, self.climb, self.burrow]
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Default for Speeds {
+    fn default() -> Self {
+        Self::walking(WALKING_SPEED_FT)
+    }
+}
+
+/// Converts a distance in feet to squares, rounding down.
+pub fn ft_to_squares(feet: u16) -> u16 {
+    (f32::from(feet) / SQUARE_FT) as u16
+}
+
+/// Converts a distance in squares to feet, rounding down.
+pub fn squares_to_ft(squares: u16) -> u16 {
+    (f32::from(squares) * SQUARE_FT) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speeds_of_movement_modes() {
+        let mut speeds = Speeds::walking(25);
+        assert_eq!(speeds.speed(MovementMode::Walk), 25);
+        assert_eq!(speeds.speed(MovementMode::Fly), 0);
+        assert_eq!(speeds.speed(MovementMode::Climb), 25);
+        assert_eq!(speeds.extra_cost(MovementMode::Climb), 1);
+        assert_eq!(speeds.extra_cost(MovementMode::Crawl), 1);
+        speeds.set(MovementMode::Climb, 20);
+        assert_eq!(speeds.speed(MovementMode::Climb), 20);
+        assert_eq!(speeds.extra_cost(MovementMode::Climb), 0);
+        speeds.set(MovementMode::Fly, 60);
+        assert_eq!(speeds.max(), 60);
+        assert_eq!(ft_to_squares(25), 10);
+        assert_eq!(squares_to_ft(3), 7);
+        assert_eq!(squares_to_ft(4), 10);
+    }
+}
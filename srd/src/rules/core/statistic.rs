@@ -1,11 +1,25 @@
 //! Statistics of creatures.
 
 use crate::ability::{AbilityId, AbilityScore};
-use crate::character::{class::ClassId, level::Level, race::RaceId};
+use crate::character::{
+    class::{ClassId, FightingStyle},
+    feat::Feat,
+    level::Level,
+    race::RaceId,
+};
+use crate::damage::{DamageDefense, DamageType};
+use crate::equipment::{
+    Ammunition, Armor, ArmorCategory, ArmorClass, ToolId, WeaponProficiency, WeaponSlots,
+};
 use crate::error::{SRDError, SRDResult};
-use crate::hit_points::HitPoints;
+use crate::hit_points::{HitPoints, TemporaryHitPoints};
 use crate::proficiency::{Proficiency, ProficiencyBonus};
+use crate::rules::core::attack::CriticalHit;
+use crate::rules::core::death::LifeState;
+use crate::rules::core::economy::{ActionCost, TurnBudget};
+use crate::rules::core::movement::MovementMode;
 use crate::rules::core::size::CreatureSize;
+use crate::rules::core::tactics::{Help, ReadyTrigger, Tactics};
 use crate::skill::SkillId;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -44,20 +58,75 @@ impl Statistic {
 
     accessor! {hit_points, HitPoints, HitPoints}
 
+    accessor! {temporary_hit_points, TemporaryHitPoints, TemporaryHitPoints}
+
+    accessor! {life_state, LifeState, LifeState}
+
     accessor! {size, Size, CreatureSize}
 
+    accessor! {armor_class, ArmorClass, ArmorClass}
+
+    accessor! {armor, Armor, Armor}
+
+    accessor! {ammunition, Ammunition, Ammunition}
+
+    accessor! {weapon_slots, WeaponSlots, WeaponSlots}
+
+    accessor! {critical_hit, CriticalHit, CriticalHit}
+
+    accessor! {turn_budget, TurnBudget, TurnBudget}
+
+    accessor! {tactics, Tactics, Tactics}
+
     accessor! {ability, Ability, AbilityScore}
 
     accessor! {skill, Skill, Proficiency}
 
     accessor! {proficiency_bonus, ProficiencyBonus, ProficiencyBonus}
 
+    accessor! {proficiency, Proficiency, Proficiency}
+
+    accessor! {damage_defense, DamageDefense, DamageDefense}
+
+    accessor! {feat, Feat, Feat}
+
+    accessor! {fighting_style, FightingStyle, FightingStyle}
+
     /// Applies a change on this statistic. The change will have an effect only if its
     /// type matches the statistic's one.
-    #[allow(dead_code)] // TODO remove when used
     pub(crate) fn apply_change(&mut self, change: &StatisticChange) {
         self.value.apply_change(change);
     }
+
+    /// Returns a mutable reference to `LifeState` if this statistic is of the correct type,
+    /// otherwise an error.
+    pub(crate) fn life_state_mut(&mut self) -> SRDResult<&mut LifeState> {
+        if let StatisticValue::LifeState(value) = &mut self.value {
+            Ok(value)
+        } else {
+            Err(SRDError::IncorrectVariant)
+        }
+    }
+
+    /// Returns a mutable reference to `HitPoints` if this statistic is of the correct type,
+    /// otherwise an error.
+    pub(crate) fn hit_points_mut(&mut self) -> SRDResult<&mut HitPoints> {
+        if let StatisticValue::HitPoints(value) = &mut self.value {
+            Ok(value)
+        } else {
+            Err(SRDError::IncorrectVariant)
+        }
+    }
+
+    /// Absorbs up to `damage` points of damage if this statistic holds temporary hit points.
+    /// Returns the leftover damage.
+    pub(crate) fn absorb_damage(&mut self, damage: u16) -> u16 {
+        if let StatisticValue::TemporaryHitPoints(value) = &mut self.value {
+            value.absorb(damage)
+        } else {
+            damage
+        }
+    }
 }
 
 impl From<StatisticInitializer> for Statistic {
@@ -68,7 +137,29 @@ impl From<StatisticInitializer> for Statistic {
             Class(value) => Self::new(StatisticId::Class, StatisticValue::Class(value)),
             Level(value) => Self::new(StatisticId::Level, StatisticValue::Level(value)),
             HitPoints(value) => Self::new(StatisticId::HitPoints, StatisticValue::HitPoints(value)),
+            TemporaryHitPoints(value) => Self::new(
+                StatisticId::TemporaryHitPoints,
+                StatisticValue::TemporaryHitPoints(value),
+            ),
+            LifeState(value) => Self::new(StatisticId::LifeState, StatisticValue::LifeState(value)),
             Size(value) => Self::new(StatisticId::Size, StatisticValue::Size(value)),
+            ArmorClass(value) => {
+                Self::new(StatisticId::ArmorClass, StatisticValue::ArmorClass(value))
+            }
+            Armor(value) => Self::new(StatisticId::Armor, StatisticValue::Armor(value)),
+            Ammunition(value) => {
+                Self::new(StatisticId::Ammunition, StatisticValue::Ammunition(value))
+            }
+            WeaponSlots(value) => {
+                Self::new(StatisticId::WeaponSlots, StatisticValue::WeaponSlots(value))
+            }
+            CriticalHit(value) => {
+                Self::new(StatisticId::CriticalHit, StatisticValue::CriticalHit(value))
+            }
+            TurnBudget(value) => {
+                Self::new(StatisticId::TurnBudget, StatisticValue::TurnBudget(value))
+            }
+            Tactics(value) => Self::new(StatisticId::Tactics, StatisticValue::Tactics(value)),
             Ability(id, value) => {
                 Self::new(StatisticId::Ability(id), StatisticValue::Ability(value))
             }
@@ -77,6 +168,35 @@ impl From<StatisticInitializer> for Statistic {
                 StatisticId::ProficiencyBonus,
                 StatisticValue::ProficiencyBonus(value),
             ),
+            AbilityCheck(id, value) => Self::new(
+                StatisticId::AbilityCheck(id),
+                StatisticValue::Proficiency(value),
+            ),
+            SavingThrow(id, value) => Self::new(
+                StatisticId::SavingThrow(id),
+                StatisticValue::Proficiency(value),
+            ),
+            ArmorProficiency(category) => Self::new(
+                StatisticId::ArmorProficiency(category),
+                StatisticValue::Proficiency(crate::proficiency::Proficiency::Proficient),
+            ),
+            WeaponProficiency(weapon) => Self::new(
+                StatisticId::WeaponProficiency(weapon),
+                StatisticValue::Proficiency(crate::proficiency::Proficiency::Proficient),
+            ),
+            ToolProficiency(id, value) => Self::new(
+                StatisticId::ToolProficiency(id),
+                StatisticValue::Proficiency(value),
+            ),
+            DamageDefense(damage_type, value) => Self::new(
+                StatisticId::DamageDefense(damage_type),
+                StatisticValue::DamageDefense(value),
+            ),
+            Feat(feat) => Self::new(StatisticId::Feat(feat), StatisticValue::Feat(feat)),
+            FightingStyle(style) => Self::new(
+                StatisticId::FightingStyle(style),
+                StatisticValue::FightingStyle(style),
+            ),
         }
     }
 }
@@ -97,10 +217,27 @@ pub enum StatisticId {
     Class,
     Level,
     HitPoints,
+    TemporaryHitPoints,
+    LifeState,
     Size,
+    ArmorClass,
+    Armor,
+    Ammunition,
+    WeaponSlots,
+    CriticalHit,
+    TurnBudget,
+    Tactics,
     Ability(AbilityId),
     Skill(SkillId),
     ProficiencyBonus,
+    AbilityCheck(AbilityId),
+    SavingThrow(AbilityId),
+    ArmorProficiency(ArmorCategory),
+    WeaponProficiency(WeaponProficiency),
+    ToolProficiency(ToolId),
+    DamageDefense(DamageType),
+    Feat(Feat),
+    FightingStyle(FightingStyle),
 }
 
 /// Encapsulates the actual value of a statistic.
@@ -110,10 +247,23 @@ enum StatisticValue {
     Class(ClassId),
     Level(Level),
     HitPoints(HitPoints),
+    TemporaryHitPoints(TemporaryHitPoints),
+    LifeState(LifeState),
     Size(CreatureSize),
+    ArmorClass(ArmorClass),
+    Armor(Armor),
+    Ammunition(Ammunition),
+    WeaponSlots(WeaponSlots),
+    CriticalHit(CriticalHit),
+    TurnBudget(TurnBudget),
+    Tactics(Tactics),
     Ability(AbilityScore),
     Skill(Proficiency),
     ProficiencyBonus(ProficiencyBonus),
+    Proficiency(Proficiency),
+    DamageDefense(DamageDefense),
+    Feat(Feat),
+    FightingStyle(FightingStyle),
 }
 
 impl StatisticValue {
@@ -123,7 +273,7 @@ impl StatisticValue {
         match change {
             StatisticChange::HitPoints(change) => {
                 if let HitPoints(value) = self {
-                    let change_abs = change.abs() as u16;
+                    let change_abs = change.unsigned_abs();
                     if *change > 0 {
                         value.add(change_abs);
                     } else {
@@ -132,9 +282,152 @@ impl StatisticValue {
                     successful = true;
                 }
             }
+            StatisticChange::Damage { amount, .. } => {
+                if let HitPoints(value) = self {
+                    value.subtract(*amount);
+                    successful = true;
+                } else if let TemporaryHitPoints(value) = self {
+                    value.absorb(*amount);
+                    successful = true;
+                }
+            }
+            StatisticChange::DeathSavingThrow(natural) => {
+                if let LifeState(value) = self {
+                    value.death_saving_throw(*natural);
+                    successful = true;
+                }
+            }
+            StatisticChange::Stabilize => {
+                if let LifeState(value) = self {
+                    value.stabilize();
+                    successful = true;
+                }
+            }
+            StatisticChange::TemporaryHitPoints(granted) => {
+                if let TemporaryHitPoints(value) = self {
+                    value.grant(*granted);
+                    successful = true;
+                }
+            }
+            StatisticChange::CountDownTemporaryHitPoints => {
+                if let TemporaryHitPoints(value) = self {
+                    value.count_down();
+                    successful = true;
+                }
+            }
+            StatisticChange::RemoveTemporaryHitPoints => {
+                if let TemporaryHitPoints(value) = self {
+                    value.clear();
+                    successful = true;
+                }
+            }
+            StatisticChange::SpendBudget(cost) => {
+                if let TurnBudget(value) = self {
+                    if let Err(err) = value.spend(*cost) {
+                        log::warn!("failed to spend {}: {}", cost, err);
+                    }
+                    successful = true;
+                }
+            }
+            StatisticChange::SpendMovement(mode, squares) => {
+                if let TurnBudget(value) = self {
+                    if let Err(err) = value.spend_movement(*mode, *squares) {
+                        log::warn!("failed to spend {} squares of movement: {}", squares, err);
+                    }
+                    successful = true;
+                }
+            }
+            StatisticChange::SpendAttack(attacks) => {
+                if let TurnBudget(value) = self {
+                    if let Err(err) = value.spend_attack(*attacks) {
+                        log::warn!("failed to attack: {}", err);
+                    }
+                    successful = true;
+                }
+            }
+            StatisticChange::SpendAmmunition => {
+                if let Ammunition(value) = self {
+                    if let Err(err) = value.spend() {
+                        log::warn!("failed to spend ammunition: {}", err);
+                    }
+                    successful = true;
+                }
+            }
+            StatisticChange::GrantBudget(cost) => {
+                if let TurnBudget(value) = self {
+                    value.grant(*cost);
+                    successful = true;
+                }
+            }
+            StatisticChange::Disengage => {
+                if let TurnBudget(value) = self {
+                    value.disengage();
+                    successful = true;
+                }
+            }
+            StatisticChange::RefreshBudget => {
+                if let TurnBudget(value) = self {
+                    value.refresh();
+                    successful = true;
+                }
+            }
+            StatisticChange::Dodge => {
+                if let Tactics(value) = self {
+                    value.dodge();
+                    successful = true;
+                }
+            }
+            StatisticChange::Help(help) => {
+                if let Tactics(value) = self {
+                    value.lend_help(help.clone());
+                    successful = true;
+                }
+            }
+            StatisticChange::EndHelp => {
+                if let Tactics(value) = self {
+                    value.end_help();
+                    successful = true;
+                }
+            }
+            StatisticChange::Ready(trigger) => {
+                if let Tactics(value) = self {
+                    value.ready(trigger.clone());
+                    successful = true;
+                }
+            }
+            StatisticChange::EndReady => {
+                if let Tactics(value) = self {
+                    value.end_ready();
+                    successful = true;
+                }
+            }
+            StatisticChange::Hide(stealth) => {
+                if let Tactics(value) = self {
+                    value.hide(*stealth);
+                    successful = true;
+                }
+            }
+            StatisticChange::Reveal => {
+                if let Tactics(value) = self {
+                    value.reveal();
+                    successful = true;
+                }
+            }
+            StatisticChange::RefreshTactics => {
+                if let Tactics(value) = self {
+                    value.refresh();
+                    successful = true;
+                }
+            }
+            StatisticChange::Wield(slots) => {
+                if let WeaponSlots(value) = self {
+                    *value = *slots;
+                    successful = true;
+                }
+            }
             StatisticChange::Ability(_, change) => {
                 if let Ability(value) = self {
-                    let change_abs = change.abs() as u8;
+                    let change_abs = change.unsigned_abs();
                     if *change > 0 {
                         value.add(change_abs);
                     } else {
@@ -162,10 +455,27 @@ pub enum StatisticInitializer {
     Class(ClassId),
     Level(Level),
     HitPoints(HitPoints),
+    TemporaryHitPoints(TemporaryHitPoints),
+    LifeState(LifeState),
     Size(CreatureSize),
+    ArmorClass(ArmorClass),
+    Armor(Armor),
+    Ammunition(Ammunition),
+    WeaponSlots(WeaponSlots),
+    CriticalHit(CriticalHit),
+    TurnBudget(TurnBudget),
+    Tactics(Tactics),
     Ability(AbilityId, AbilityScore),
     Skill(SkillId, Proficiency),
     ProficiencyBonus(ProficiencyBonus),
+    AbilityCheck(AbilityId, Proficiency),
+    SavingThrow(AbilityId, Proficiency),
+    ArmorProficiency(ArmorCategory),
+    WeaponProficiency(WeaponProficiency),
+    ToolProficiency(ToolId, Proficiency),
+    DamageDefense(DamageType, DamageDefense),
+    Feat(Feat),
+    FightingStyle(FightingStyle),
 }
 
 /// `StatisticsSeed` is used to generate all statistics of a creature.
@@ -176,12 +486,92 @@ pub struct StatisticsSeed {
 }
 
 /// Encapsulates a change to a statistic.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, DisplayVariant)]
+#[derive(Debug, Clone, Serialize, Deserialize, DisplayVariant)]
 pub enum StatisticChange {
-    /// A numeric change to hit points.
+    /// A numeric change to hit points, bypassing temporary hit points.
     HitPoints(i16),
+    /// Damage taken by a creature. Temporary hit points are drained first.
+    Damage {
+        /// The amount of damage.
+        amount: u16,
+        /// Whether or not the damage comes from a critical hit.
+        critical: bool,
+    },
+    /// Temporary hit points received by a creature. They replace the current ones only if higher.
+    TemporaryHitPoints(TemporaryHitPoints),
+    /// Counts down the duration of temporary hit points at the start of a creature's turn.
+    CountDownTemporaryHitPoints,
+    /// Removes all temporary hit points, for instance after a long rest.
+    RemoveTemporaryHitPoints,
+    /// A death saving throw with the given d20 roll.
+    DeathSavingThrow(u8),
+    /// Stabilizes a dying creature.
+    Stabilize,
+    /// Pays the cost of an action.
+    SpendBudget(ActionCost),
+    /// Spends one attack, taking the Attack action if needed. The value is the number of attacks
+    /// granted by the action.
+    SpendAttack(u8),
+    /// Spends the given squares of movement, moving in a certain way.
+    SpendMovement(MovementMode, u16),
+    /// Grants one more use of a kind of action for the current turn.
+    GrantBudget(ActionCost),
+    /// Prevents the creature's movement from provoking opportunity attacks this turn.
+    Disengage,
+    /// Restores the whole budget at the start of a creature's turn.
+    RefreshBudget,
+    /// Makes attacks against the creature have disadvantage until its next turn.
+    Dodge,
+    /// Lends help to an ally until the creature's next turn.
+    Help(Help),
+    /// Ends the help lent by the creature, once used.
+    EndHelp,
+    /// Readies an attack until the creature's next turn.
+    Ready(ReadyTrigger),
+    /// Ends the readied attack of the creature, once taken.
+    EndReady,
+    /// Hides the creature with the given Dexterity (Stealth) check total.
+    Hide(i32),
+    /// Reveals a hidden creature.
+    Reveal,
+    /// Ends dodging, help and readied attacks at the start of a creature's turn.
+    RefreshTactics,
+    /// Changes the weapons held by the creature.
+    Wield(WeaponSlots),
     /// A numeric change to an ability score.
     Ability(AbilityId, i8),
+    /// Expends one piece of ammunition.
+    SpendAmmunition,
+}
+
+impl StatisticChange {
+    /// Returns the id of the statistic targeted by this change.
+    pub const fn statistic_id(&self) -> StatisticId {
+        match self {
+            Self::HitPoints(_) | Self::Damage { .. } => StatisticId::HitPoints,
+            Self::DeathSavingThrow(_) | Self::Stabilize => StatisticId::LifeState,
+            Self::TemporaryHitPoints(_)
+            | Self::CountDownTemporaryHitPoints
+            | Self::RemoveTemporaryHitPoints => StatisticId::TemporaryHitPoints,
+            Self::SpendBudget(_)
+            | Self::SpendAttack(_)
+            | Self::SpendMovement(..)
+            | Self::GrantBudget(_)
+            | Self::Disengage
+            | Self::RefreshBudget => StatisticId::TurnBudget,
+            Self::Dodge
+            | Self::Help(_)
+            | Self::EndHelp
+            | Self::Ready(_)
+            | Self::EndReady
+            | Self::Hide(_)
+            | Self::Reveal
+            | Self::RefreshTactics => StatisticId::Tactics,
+            Self::Wield(_) => StatisticId::WeaponSlots,
+            Self::Ability(id, _) => StatisticId::Ability(*id),
+            Self::SpendAmmunition => StatisticId::Ammunition,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -201,4 +591,24 @@ mod tests {
         stat.apply_change(&StatisticChange::HitPoints(-4));
         assert_eq!(stat.hit_points().unwrap().value(), 6);
     }
+
+    #[test]
+    fn change_temporary_hit_points() {
+        let mut stat: Statistic =
+            StatisticInitializer::TemporaryHitPoints(TemporaryHitPoints::default()).into();
+        let grant = |value| StatisticChange::TemporaryHitPoints(TemporaryHitPoints::new(value));
+        stat.apply_change(&grant(5));
+        stat.apply_change(&grant(3));
+        assert_eq!(stat.temporary_hit_points().unwrap().value(), 5);
+        assert_eq!(stat.absorb_damage(7), 2);
+        assert_eq!(stat.temporary_hit_points().unwrap().value(), 0);
+        stat.apply_change(&grant(4));
+        stat.apply_change(&StatisticChange::RemoveTemporaryHitPoints);
+        assert_eq!(stat.temporary_hit_points().unwrap().value(), 0);
+        stat.apply_change(&StatisticChange::TemporaryHitPoints(
+            TemporaryHitPoints::with_duration(4, 1),
+        ));
+        stat.apply_change(&StatisticChange::CountDownTemporaryHitPoints);
+        assert_eq!(stat.temporary_hit_points().unwrap().value(), 0);
+    }
 }
@@ -0,0 +1,232 @@
+//! Lingering effects of the Dodge, Help, Hide and Ready actions.
+
+use crate::character::CharacterId;
+use crate::rules::core::condition::Conditions;
+use crate::rules::core::statistic::StatisticId;
+use crate::rules::SRDRules;
+use crate::skill::{SkillId, INVESTIGATION, PERCEPTION};
+use serde::{Deserialize, Serialize};
+use weasel::Character;
+
+/// Distance, in feet, within which the target of a helped attack must be from the helper.
+pub const HELP_DISTANCE_FT: u16 = 5;
+
+/// Returns true if `skill` can be used to search: Wisdom (Perception) or Intelligence
+/// (Investigation).
+///
+/// # Reference
+///
+/// When you take the Search action, you devote your attention to finding something. Depending on
+/// the nature of your search, the GM might have you make a Wisdom (Perception) check or an
+/// Intelligence (Investigation) check.
+pub fn is_search_skill(skill: SkillId) -> bool {
+    skill == PERCEPTION || skill == INVESTIGATION
+}
+
+/// The aid lent by a creature to an ally with the Help action.
+///
+/// # Reference
+///
+/// You can lend your aid to another creature in the completion of a task. When you take the Help
+/// action, the creature you aid gains advantage on the next ability check it makes to perform the
+/// task you are helping with, provided that it makes the check before the start of your next
+/// turn.\
+/// Alternatively, you can aid a friendly creature in attacking a creature within 5 feet of you.
+/// If your ally attacks the target before your next turn, the first attack roll is made with
+/// advantage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Help {
+    /// The creature receiving help.
+    pub ally: CharacterId,
+    /// The creature the ally is helped to attack, or `None` to help with its next ability check.
+    pub target: Option<CharacterId>,
+}
+
+/// The circumstance a creature waits for to use its readied action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReadyTrigger {
+    /// A creature moves within the reach of the readying creature.
+    EntersReach {
+        /// The only creature setting off the trigger, or `None` for any creature.
+        creature: Option<CharacterId>,
+    },
+}
+
+impl ReadyTrigger {
+    /// Returns true if `creature` moving within reach sets off this trigger.
+    pub fn matches(&self, creature: &CharacterId) -> bool {
+        match self {
+            Self::EntersReach { creature: expected } => expected
+                .as_ref()
+                .is_none_or(|expected| expected == creature),
+        }
+    }
+}
+
+/// Keeps track of what a creature does to gain an edge in combat: dodging, helping an ally,
+/// readying an attack or hiding.\
+/// All effects end at the start of the creature's next turn, except hiding.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Tactics {
+    dodging: bool,
+    help: Option<Help>,
+    readied: Option<ReadyTrigger>,
+    stealth: Option<i32>,
+}
+
+impl Tactics {
+    /// Returns true if the creature took the Dodge action.
+    pub const fn dodging(&self) -> bool {
+        self.dodging
+    }
+
+    /// Makes the creature dodge until the start of its next turn.
+    ///
+    /// # Reference
+    ///
+    /// When you take the Dodge action, you focus entirely on avoiding attacks. Until the start of
+    /// your next turn, any attack roll made against you has disadvantage if you can see the
+    /// attacker.
+    pub fn dodge(&mut self) {
+        self.dodging = true;
+    }
+
+    /// Returns the help lent by the creature, if it hasn't been used yet.
+    pub const fn help(&self) -> Option<&Help> {
+        self.help.as_ref()
+    }
+
+    /// Lends `help` until the start of the creature's next turn.
+    pub fn lend_help(&mut self, help: Help) {
+        self.help = Some(help);
+    }
+
+    /// Ends the help, after the ally benefited from it.
+    pub fn end_help(&mut self) {
+        self.help = None;
+    }
+
+    /// Returns the trigger of the creature's readied attack, if any.
+    pub const fn readied(&self) -> Option<&ReadyTrigger> {
+        self.readied.as_ref()
+    }
+
+    /// Readies an attack against the creature setting off `trigger`, until the start of the
+    /// creature's next turn.
+    ///
+    /// # Reference
+    ///
+    /// First, you decide what perceivable circumstance will trigger your reaction. Then, you
+    /// choose the action you will take in response to that trigger. When the trigger occurs, you
+    /// can either take your reaction right after the trigger finishes or ignore the trigger.
+    pub fn ready(&mut self, trigger: ReadyTrigger) {
+        self.readied = Some(trigger);
+    }
+
+    /// Ends the readied attack, after it has been taken.
+    pub fn end_ready(&mut self) {
+        self.readied = None;
+    }
+
+    /// Returns the total of the Dexterity (Stealth) check of the creature, if it's hidden.
+    pub const fn stealth(&self) -> Option<i32> {
+        self.stealth
+    }
+
+    /// Returns true if the creature is hidden.
+    pub const fn hidden(&self) -> bool {
+        self.stealth.is_some()
+    }
+
+    /// Hides the creature, which stays hidden until found or until it attacks.
+    ///
+    /// # Reference
+    ///
+    /// When you try to hide, make a Dexterity (Stealth) check. Until you are discovered or you
+    /// stop hiding, that check's total is contested by the Wisdom (Perception) check of any
+    /// creature that actively searches for signs of your presence.
+    pub fn hide(&mut self, stealth: i32) {
+        self.stealth = Some(stealth);
+    }
+
+    /// Reveals the location of a hidden creature.
+    pub fn reveal(&mut self) {
+        self.stealth = None;
+    }
+
+    /// Ends dodging, help and readied attacks at the start of the creature's turn.
+    pub fn refresh(&mut self) {
+        *self = Self {
+            stealth: self.stealth,
+            ..Self::default()
+        };
+    }
+}
+
+/// Returns the tactics of a creature, if it has them.
+pub(crate) fn tactics(creature: &dyn Character<SRDRules>) -> Option<&Tactics> {
+    creature
+        .statistic(&StatisticId::Tactics)
+        .and_then(|statistic| statistic.tactics().ok())
+}
+
+/// Returns true if attacks against `creature` have disadvantage because it's dodging.
+///
+/// # Reference
+///
+/// You lose this benefit if you are incapacitated or if your speed drops to 0.
+pub(crate) fn is_dodging(creature: &dyn Character<SRDRules>) -> bool {
+    let conditions = Conditions::of(creature);
+    let speed = creature
+        .statistic(&StatisticId::TurnBudget)
+        .and_then(|statistic| statistic.turn_budget().ok())
+        .map_or(0, |budget| conditions.speeds(budget.speeds()).max());
+    tactics(creature).is_some_and(Tactics::dodging) && !conditions.is_incapacitated() && speed > 0
+}
+
+/// Returns true if `creature` is hidden.
+pub(crate) fn is_hidden(creature: &dyn Character<SRDRules>) -> bool {
+    tactics(creature).is_some_and(Tactics::hidden)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::STEALTH;
+
+    #[test]
+    fn tactics_lifetime() {
+        let mut tactics = Tactics::default();
+        tactics.dodge();
+        tactics.lend_help(Help {
+            ally: "ally".into(),
+            target: None,
+        });
+        tactics.ready(ReadyTrigger::EntersReach { creature: None });
+        tactics.hide(15);
+        assert!(tactics.dodging());
+        assert!(tactics.readied().is_some());
+        tactics.end_help();
+        assert_eq!(tactics.help(), None);
+        // Only hiding lasts beyond the start of the next turn.
+        tactics.refresh();
+        assert!(!tactics.dodging());
+        assert_eq!(tactics.readied(), None);
+        assert_eq!(tactics.stealth(), Some(15));
+        tactics.reveal();
+        assert_eq!(tactics, Tactics::default());
+    }
+
+    #[test]
+    fn triggers_and_skills() {
+        let trigger = ReadyTrigger::EntersReach {
+            creature: Some("one".into()),
+        };
+        assert!(trigger.matches(&"one".into()));
+        assert!(!trigger.matches(&"two".into()));
+        assert!(ReadyTrigger::EntersReach { creature: None }.matches(&"two".into()));
+        assert!(is_search_skill(PERCEPTION));
+        assert!(is_search_skill(INVESTIGATION));
+        assert!(!is_search_skill(STEALTH));
+    }
+}
@@ -1,9 +1,19 @@
 //! Implementation of rules for combat.
 
+use crate::damage::DamageDefense;
+use crate::rules::core::attack::Impact;
+use crate::rules::core::condition::{
+    ConditionId, ConditionPotency, Conditions, MAX_EXHAUSTION_LEVEL,
+};
+use crate::rules::core::statistic::{StatisticChange, StatisticId};
 use crate::rules::narrator::Narrator;
 use crate::rules::SRDRules;
 use std::sync::Arc;
-use weasel::{BattleState, Entropy, EventQueue, FightRules, WriteMetrics};
+use weasel::{
+    status::{Application, AppliedStatus},
+    AlterStatistics, BattleState, Character, Creature, EntityId, Entropy, EventProcessor,
+    EventQueue, EventTrigger, FightRules, Id, LinkedQueue, RemoveEntity, WriteMetrics,
+};
 
 /// Rules to manage combat and damage.
 pub struct SRDFightRules {
@@ -19,17 +29,97 @@ impl SRDFightRules {
 }
 
 impl FightRules<SRDRules> for SRDFightRules {
-    type Impact = (); // TODO use a real type
-    type Potency = (); // TODO not sure if we need this
+    type Impact = Impact;
+    type Potency = ConditionPotency;
 
     fn apply_impact(
+        &self,
+        state: &BattleState<SRDRules>,
+        impact: &Self::Impact,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
+        _entropy: &mut Entropy<SRDRules>,
+        _metrics: &mut WriteMetrics<SRDRules>,
+    ) {
+        let target = if let Some(target) = state.entities().creature(&impact.target) {
+            target
+        } else {
+            log::warn!("ignoring impact on missing creature {:?}", impact.target);
+            return;
+        };
+        let damage = damage_against(target, impact);
+        log::trace!("{:?} takes {} damage", impact.target, damage);
+        if damage == 0 {
+            return;
+        }
+        AlterStatistics::trigger(
+            event_queue,
+            EntityId::Creature(impact.target.clone()),
+            StatisticChange::Damage {
+                amount: damage,
+                critical: impact.critical,
+            },
+        )
+        .fire();
+    }
+
+    fn apply_status(
         &self,
         _state: &BattleState<SRDRules>,
-        _impact: &Self::Impact,
-        _event_queue: &mut Option<EventQueue<SRDRules>>,
+        character: &dyn Character<SRDRules>,
+        application: Application<SRDRules>,
+        event_queue: &mut Option<EventQueue<SRDRules>>,
         _entropy: &mut Entropy<SRDRules>,
         _metrics: &mut WriteMetrics<SRDRules>,
     ) {
-        unimplemented!()
+        let status = match application {
+            Application::New(status) | Application::Replacement(_, status) => status,
+        };
+        log::debug!("{:?} is now {:?}", character.entity_id(), status.status());
+        die_of_exhaustion(character, event_queue);
     }
+
+    fn update_status(
+        &self,
+        _state: &BattleState<SRDRules>,
+        character: &dyn Character<SRDRules>,
+        status: &AppliedStatus<SRDRules>,
+        linked_queue: &mut Option<LinkedQueue<SRDRules>>,
+        _entropy: &mut Entropy<SRDRules>,
+        _metrics: &mut WriteMetrics<SRDRules>,
+    ) -> bool {
+        if *status.id() == ConditionId::Exhaustion {
+            die_of_exhaustion(character, linked_queue);
+        }
+        status.expired(status.duration())
+    }
+}
+
+/// Removes a character that reached the maximum level of exhaustion.
+fn die_of_exhaustion<P: EventProcessor<SRDRules>>(
+    character: &dyn Character<SRDRules>,
+    event_queue: &mut P,
+) {
+    let exhaustion = character
+        .status(&ConditionId::Exhaustion)
+        .map_or(0, |status| status.level());
+    if exhaustion >= MAX_EXHAUSTION_LEVEL {
+        log::debug!("{:?} died of exhaustion", character.entity_id());
+        RemoveEntity::trigger(event_queue, character.entity_id().clone()).fire();
+    }
+}
+
+/// Returns the damage dealt by `impact` to `target`, after applying the target's resistances,
+/// vulnerabilities and immunities to each damage type.\
+/// Petrified creatures resist all damage on top of their other defenses.
+pub(crate) fn damage_against(target: &Creature<SRDRules>, impact: &Impact) -> u16 {
+    let petrified = Conditions::of(target).resists_all_damage();
+    impact.damage.iter().fold(0u16, |total, damage| {
+        let defenses: Vec<_> = target
+            .statistic(&StatisticId::DamageDefense(damage.damage_type))
+            .and_then(|statistic| statistic.damage_defense().ok().copied())
+            .into_iter()
+            .chain(petrified.then_some(DamageDefense::Resistance))
+            .collect();
+        total.saturating_add(damage.amount_against(&defenses))
+    })
 }
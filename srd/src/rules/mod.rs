@@ -10,6 +10,8 @@ pub mod fight_rules;
 
 pub mod narrator;
 
+pub mod reaction;
+
 pub mod rounds_rules;
 
 pub mod space_rules;
@@ -20,6 +22,7 @@ use self::actor_rules::SRDActorRules;
 use self::character_rules::SRDCharacterRules;
 use self::fight_rules::SRDFightRules;
 use self::narrator::Narrator;
+use self::reaction::{EagerReactor, Reactor};
 use self::rounds_rules::SRDRoundsRules;
 use self::space_rules::SRDSpaceRules;
 use self::team_rules::SRDTeamRules;
@@ -51,7 +54,7 @@ impl SRDRules {
             narrator: narrator.clone(),
             team_rules: SRDTeamRules::new(narrator.clone()),
             character_rules: SRDCharacterRules::new(narrator.clone()),
-            actor_rules: SRDActorRules::new(narrator.clone()),
+            actor_rules: SRDActorRules::new(narrator.clone(), Arc::new(EagerReactor::default())),
             fight_rules: SRDFightRules::new(narrator.clone()),
             user_rules: EmptyUserRules::default(),
             space_rules: Some(SRDSpaceRules::new(narrator.clone())),
@@ -63,6 +66,13 @@ impl SRDRules {
         instance
     }
 
+    /// Replaces the reactor deciding whether creatures take their reactions.\
+    /// By default, creatures take all reactions.
+    pub fn with_reactor(mut self, reactor: Arc<dyn Reactor>) -> Self {
+        self.actor_rules = SRDActorRules::new(self.narrator.clone(), reactor);
+        self
+    }
+
     /// Returns the narrator of the battle.
     pub fn narrator(&self) -> &dyn Narrator {
         &*self.narrator
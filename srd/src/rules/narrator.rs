@@ -1,8 +1,31 @@
 //! Smart storytelling module.
 
+use crate::check::{CheckOutcome, CheckResult, ContestOutcome};
+use crate::rules::core::attack::AttackOutcome;
+use crate::rules::core::death::DeathSavingThrowResult;
+use crate::rules::core::initiative::Initiative;
+
+/// An episode is a noteworthy event happened during the game.
+#[derive(Debug, Clone, Copy)]
+pub enum Episode<'a> {
+    /// A check rolled without a difficulty class.
+    Check(&'a CheckResult),
+    /// A check rolled against a difficulty class.
+    CheckAgainstDC(&'a CheckOutcome),
+    /// A contest between two creatures.
+    Contest(&'a ContestOutcome),
+    /// An attack made by a creature against another.
+    Attack(&'a AttackOutcome),
+    /// A death saving throw of a dying creature.
+    DeathSavingThrow(&'a DeathSavingThrowResult),
+    /// A creature rolled initiative.
+    Initiative(&'a Initiative),
+}
+
 /// A narrator tells of all episodes happening during the battle.
 pub trait Narrator: Send + Sync {
-    // TODO fn episode(episode);
+    /// Tells of an episode. The default implementation does nothing.
+    fn episode(&self, _episode: &Episode) {}
 }
 
 /// A narrator that does nothing.
@@ -15,4 +38,8 @@ impl Narrator for NopNarrator {}
 #[derive(Default)]
 pub struct DebugNarrator {}
 
-impl Narrator for DebugNarrator {}
+impl Narrator for DebugNarrator {
+    fn episode(&self, episode: &Episode) {
+        log::debug!("{:?}", episode);
+    }
+}
@@ -0,0 +1,72 @@
+//! Decisions about reactions.
+
+use crate::character::CharacterId;
+use crate::rules::SRDRules;
+use weasel::BattleState;
+
+/// A chance for a creature to use its reaction, outside of its turn.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReactionOpportunity {
+    /// A creature is about to leave the reach of `reactor`, who can make an opportunity attack.
+    ///
+    /// # Reference
+    ///
+    /// You can make an opportunity attack when a hostile creature that you can see moves out of
+    /// your reach. To make the opportunity attack, you use your reaction to make one melee attack
+    /// against the provoking creature. The attack occurs right before the creature leaves your
+    /// reach.
+    OpportunityAttack {
+        /// The creature that can react.
+        reactor: CharacterId,
+        /// The creature leaving the reach.
+        target: CharacterId,
+    },
+    /// A creature has entered the reach of `reactor`, who readied an attack for this circumstance.
+    ///
+    /// # Reference
+    ///
+    /// When the trigger occurs, you can either take your reaction right after the trigger
+    /// finishes or ignore the trigger. Remember that you can take only one reaction per round.
+    ReadiedAttack {
+        /// The creature that can react.
+        reactor: CharacterId,
+        /// The creature entering the reach.
+        target: CharacterId,
+    },
+}
+
+impl ReactionOpportunity {
+    /// Returns the creature that can react.
+    pub fn reactor(&self) -> &CharacterId {
+        match self {
+            Self::OpportunityAttack { reactor, .. } | Self::ReadiedAttack { reactor, .. } => {
+                reactor
+            }
+        }
+    }
+}
+
+/// A reactor decides, on behalf of the players or of the AI, whether creatures take the
+/// reactions offered to them.
+pub trait Reactor: Send + Sync {
+    /// Returns true if the creature takes the reaction. The default implementation always does.
+    fn react(&self, _state: &BattleState<SRDRules>, _opportunity: &ReactionOpportunity) -> bool {
+        true
+    }
+}
+
+/// A reactor that takes every reaction.
+#[derive(Default)]
+pub struct EagerReactor {}
+
+impl Reactor for EagerReactor {}
+
+/// A reactor that declines every reaction.
+#[derive(Default)]
+pub struct PassiveReactor {}
+
+impl Reactor for PassiveReactor {
+    fn react(&self, _state: &BattleState<SRDRules>, _opportunity: &ReactionOpportunity) -> bool {
+        false
+    }
+}
@@ -1,13 +1,19 @@
 //! Implementation of rules for the order of initiative.
 
-use crate::rules::narrator::Narrator;
+use crate::ability::DEXTERITY;
+use crate::rules::core::initiative::{Initiative, InitiativeOrder};
+use crate::rules::core::statistic::StatisticId;
+use crate::rules::narrator::{Episode, Narrator};
 use crate::rules::SRDRules;
 use std::sync::Arc;
 use weasel::{Actor, Entities, Entropy, RoundsRules, Space, WriteMetrics};
 
 /// Rules to determine the order of initiative during a battle.
+///
+/// Creatures roll initiative as soon as they join the battle, even while a turn is in progress,
+/// and then take turns in initiative order. Only the creature returned by
+/// `InitiativeOrder::current` can start a turn.
 pub struct SRDRoundsRules {
-    #[allow(dead_code)] // TODO remove
     narrator: Arc<dyn Narrator>,
 }
 
@@ -19,44 +25,183 @@ impl SRDRoundsRules {
 }
 
 impl RoundsRules<SRDRules> for SRDRoundsRules {
-    type RoundsSeed = (); // TODO use a real type
-    type RoundsModel = (); // TODO use a real type
+    type RoundsSeed = InitiativeOrder;
+    type RoundsModel = InitiativeOrder;
 
-    fn generate_model(&self, _: &Option<Self::RoundsSeed>) -> Self::RoundsModel {}
+    fn generate_model(&self, seed: &Option<Self::RoundsSeed>) -> Self::RoundsModel {
+        seed.clone().unwrap_or_default()
+    }
 
-    fn eligible(&self, _model: &Self::RoundsModel, _actor: &dyn Actor<SRDRules>) -> bool {
-        unimplemented!()
+    fn eligible(&self, model: &Self::RoundsModel, actor: &dyn Actor<SRDRules>) -> bool {
+        actor.entity_id().creature().ok().as_ref() == model.current()
     }
 
     fn on_start(
         &self,
         _entities: &Entities<SRDRules>,
         _space: &Space<SRDRules>,
-        _model: &mut Self::RoundsModel,
+        model: &mut Self::RoundsModel,
         _actor: &dyn Actor<SRDRules>,
         _entropy: &mut Entropy<SRDRules>,
         _metrics: &mut WriteMetrics<SRDRules>,
     ) {
-        unimplemented!()
+        model.start();
     }
 
-    fn on_actor_added(
+    fn on_end(
         &self,
-        _model: &mut Self::RoundsModel,
-        _actor: &dyn Actor<SRDRules>,
+        _entities: &Entities<SRDRules>,
+        _space: &Space<SRDRules>,
+        model: &mut Self::RoundsModel,
+        actor: &dyn Actor<SRDRules>,
         _entropy: &mut Entropy<SRDRules>,
         _metrics: &mut WriteMetrics<SRDRules>,
     ) {
-        // TODO
+        // The actor might have already left the initiative order.
+        if actor.entity_id().creature().ok().as_ref() == model.current() {
+            model.advance();
+        }
+    }
+
+    fn on_actor_added(
+        &self,
+        model: &mut Self::RoundsModel,
+        actor: &dyn Actor<SRDRules>,
+        entropy: &mut Entropy<SRDRules>,
+        _metrics: &mut WriteMetrics<SRDRules>,
+    ) {
+        let id = match actor.entity_id().creature() {
+            Ok(id) => id,
+            Err(_) => return,
+        };
+        let dexterity = actor
+            .statistic(&StatisticId::Ability(DEXTERITY))
+            .and_then(|statistic| statistic.ability().ok().copied())
+            .unwrap_or_default();
+        // Bonuses to Dexterity checks, such as Jack of All Trades, apply to initiative.
+        let bonus = actor
+            .statistic(&StatisticId::AbilityCheck(DEXTERITY))
+            .and_then(|statistic| statistic.proficiency().ok())
+            .zip(
+                actor
+                    .statistic(&StatisticId::ProficiencyBonus)
+                    .and_then(|statistic| statistic.proficiency_bonus().ok()),
+            )
+            .map_or(0, |(proficiency, bonus)| {
+                i16::from(proficiency.bonus(*bonus).0)
+            });
+        let initiative = Initiative::roll(id, dexterity, bonus, entropy);
+        self.narrator.episode(&Episode::Initiative(&initiative));
+        // Creatures joining mid-combat don't steal the turn of the creature acting now.
+        model.add(initiative);
     }
 
     fn on_actor_removed(
         &self,
-        _model: &mut Self::RoundsModel,
-        _actor: &dyn Actor<SRDRules>,
+        model: &mut Self::RoundsModel,
+        actor: &dyn Actor<SRDRules>,
         _entropy: &mut Entropy<SRDRules>,
         _metrics: &mut WriteMetrics<SRDRules>,
     ) {
-        unimplemented!()
+        if let Ok(id) = actor.entity_id().creature() {
+            model.remove(&id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::character::class::FIGHTER;
+    use crate::character::race::HILL_DWARF;
+    use crate::character::CharacterId;
+    use crate::util::simple_server;
+    use weasel::{BattleController, EndTurn, EntityId, EventTrigger, ResetRounds, StartTurn};
+
+    #[test]
+    fn turns_follow_initiative() {
+        let mut server = simple_server();
+        for id in &["one", "two", "three"] {
+            crate::Character::new(*id, HILL_DWARF, FIGHTER)
+                .unwrap()
+                .spawn(&mut server)
+                .unwrap();
+        }
+        let order: Vec<CharacterId> = server
+            .battle()
+            .rounds()
+            .model()
+            .order()
+            .map(|e| e.creature.clone())
+            .collect();
+        assert_eq!(order.len(), 3);
+        // Only the current creature can act.
+        assert!(
+            StartTurn::trigger(&mut server, EntityId::Creature(order[1].clone()))
+                .fire()
+                .is_err()
+        );
+        // Surprise the second creature, then check that it loses its first turn.
+        let mut model = server.battle().rounds().model().clone();
+        assert!(model.surprise(&order[1]));
+        ResetRounds::trigger(&mut server)
+            .seed(model)
+            .fire()
+            .unwrap();
+        let mut acted = Vec::new();
+        for _ in 0..5 {
+            let current = server.battle().rounds().model().current().unwrap().clone();
+            StartTurn::trigger(&mut server, EntityId::Creature(current.clone()))
+                .fire()
+                .unwrap();
+            EndTurn::trigger(&mut server).fire().unwrap();
+            acted.push(current);
+        }
+        assert_eq!(
+            acted,
+            vec![
+                order[0].clone(),
+                order[2].clone(),
+                order[0].clone(),
+                order[1].clone(),
+                order[2].clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn creatures_join_mid_combat() {
+        let mut server = simple_server();
+        for id in &["one", "two"] {
+            crate::Character::new(*id, HILL_DWARF, FIGHTER)
+                .unwrap()
+                .spawn(&mut server)
+                .unwrap();
+        }
+        let current = server.battle().rounds().model().current().unwrap().clone();
+        StartTurn::trigger(&mut server, EntityId::Creature(current.clone()))
+            .fire()
+            .unwrap();
+        // A creature spawned during a turn rolls initiative without stealing the turn.
+        let late: CharacterId = "late".into();
+        crate::Character::new(late.clone(), HILL_DWARF, FIGHTER)
+            .unwrap()
+            .spawn(&mut server)
+            .unwrap();
+        let model = server.battle().rounds().model();
+        assert_eq!(model.len(), 3);
+        assert!(model.initiative(&late).is_some());
+        assert_eq!(model.current(), Some(&current));
+        EndTurn::trigger(&mut server).fire().unwrap();
+        // The newcomer acts within the next round.
+        let mut acted = false;
+        for _ in 0..3 {
+            let current = server.battle().rounds().model().current().unwrap().clone();
+            StartTurn::trigger(&mut server, EntityId::Creature(current.clone()))
+                .fire()
+                .unwrap();
+            EndTurn::trigger(&mut server).fire().unwrap();
+            acted |= current == late;
+        }
+        assert!(acted);
     }
 }
@@ -1,11 +1,17 @@
 //! Implementation of rules for movement and positions.
 
+use crate::rules::core::battlegrid::{BattleGrid, Footprint, GridSeed};
 use crate::rules::narrator::Narrator;
 use crate::rules::SRDRules;
 use std::sync::Arc;
-use weasel::{PositionClaim, SpaceRules, WeaselResult, WriteMetrics};
+use weasel::{
+    Entity, EventQueue, PositionClaim, SpaceRules, WeaselError, WeaselResult, WriteMetrics,
+};
 
 /// Rules for creatures' movement and spatial positions.
+///
+/// The battle takes place on a `BattleGrid`. Use a `ResetSpace` event with a `GridSeed` to
+/// describe the map.
 pub struct SRDSpaceRules {
     #[allow(dead_code)] // TODO remove
     narrator: Arc<dyn Narrator>,
@@ -19,29 +25,117 @@ impl SRDSpaceRules {
 }
 
 impl SpaceRules<SRDRules> for SRDSpaceRules {
-    type Position = (); // TODO use a real type
-    type SpaceSeed = (); // TODO use a real type
+    type Position = Footprint;
+    type SpaceSeed = GridSeed;
     type SpaceAlteration = (); // TODO use a real type
-    type SpaceModel = (); // TODO use a real type
+    type SpaceModel = BattleGrid;
 
-    fn generate_model(&self, _: &Option<Self::SpaceSeed>) -> Self::SpaceModel {}
+    fn generate_model(&self, seed: &Option<Self::SpaceSeed>) -> Self::SpaceModel {
+        match seed {
+            Some(seed) => BattleGrid::new(seed),
+            None => BattleGrid::new(&GridSeed::default()),
+        }
+    }
 
     fn check_move(
         &self,
-        _model: &Self::SpaceModel,
-        _claim: PositionClaim<SRDRules>,
-        _position: &Self::Position,
+        model: &Self::SpaceModel,
+        claim: PositionClaim<SRDRules>,
+        position: &Self::Position,
     ) -> WeaselResult<(), SRDRules> {
-        Ok(())
+        let id = claim.entity_id().creature()?;
+        model
+            .check(&id, position)
+            .map_err(|err| WeaselError::UserError(err.to_string()))
     }
 
     fn move_entity(
         &self,
-        _model: &mut Self::SpaceModel,
-        _claim: PositionClaim<SRDRules>,
-        _position: Option<&Self::Position>,
+        model: &mut Self::SpaceModel,
+        claim: PositionClaim<SRDRules>,
+        position: Option<&Self::Position>,
+        _metrics: &mut WriteMetrics<SRDRules>,
+    ) {
+        if let Ok(id) = claim.entity_id().creature() {
+            model.place(&id, position);
+        }
+    }
+
+    fn translate_entity(
+        &self,
+        _model: &Self::SpaceModel,
+        new_model: &mut Self::SpaceModel,
+        entity: &mut dyn Entity<SRDRules>,
+        _event_queue: &mut Option<EventQueue<SRDRules>>,
         _metrics: &mut WriteMetrics<SRDRules>,
     ) {
-        // TODO
+        // Entities keep their position in the new grid.
+        if let Ok(id) = entity.entity_id().creature() {
+            if let Err(err) = new_model.check(&id, entity.position()) {
+                log::warn!("{:?} is in an invalid position: {}", id, err);
+            }
+            new_model.place(&id, Some(entity.position()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::character::class::FIGHTER;
+    use crate::character::race::HILL_DWARF;
+    use crate::character::CharacterId;
+    use crate::rules::core::battlegrid::{Footprint, GridSeed, Square};
+    use crate::rules::core::CreatureSize;
+    use crate::util::simple_server;
+    use weasel::{BattleController, EntityId, EventTrigger, MoveEntity, RemoveEntity, ResetSpace};
+
+    #[test]
+    fn creatures_on_the_grid() {
+        let mut server = simple_server();
+        ResetSpace::trigger(&mut server)
+            .seed(GridSeed {
+                width: 6,
+                height: 4,
+                walls: vec![Square::new(5, 0)],
+                ..GridSeed::default()
+            })
+            .fire()
+            .unwrap();
+        let one: CharacterId = "one".into();
+        let two: CharacterId = "two".into();
+        let dwarf =
+            |id: &CharacterId| crate::Character::new(id.clone(), HILL_DWARF, FIGHTER).unwrap();
+        dwarf(&one)
+            .spawn_at(&mut server, Square::new(1, 1))
+            .unwrap();
+        // Overlapping spawn.
+        assert!(dwarf(&two)
+            .spawn_at(&mut server, Square::new(2, 2))
+            .is_err());
+        // Automatic placement.
+        dwarf(&two).spawn(&mut server).unwrap();
+        let footprint = |square| Footprint::new(square, CreatureSize::Medium);
+        let grid = server.battle().space().model();
+        assert_eq!(grid.footprint(&two), Some(&footprint(Square::new(3, 0))));
+        // Invalid moves.
+        let move_two = |server: &mut _, square| {
+            MoveEntity::trigger(server, EntityId::Creature(two.clone()), footprint(square)).fire()
+        };
+        assert!(move_two(&mut server, Square::new(2, 1)).is_err());
+        assert!(move_two(&mut server, Square::new(4, 0)).is_err());
+        assert!(move_two(&mut server, Square::new(5, 3)).is_err());
+        // Valid move.
+        assert!(move_two(&mut server, Square::new(3, 2)).is_ok());
+        let grid = server.battle().space().model();
+        assert_eq!(grid.occupant(Square::new(3, 0)), None);
+        assert_eq!(grid.occupant(Square::new(4, 3)), Some(&two));
+        // Removed creatures free their space.
+        RemoveEntity::trigger(&mut server, EntityId::Creature(one.clone()))
+            .fire()
+            .unwrap();
+        assert_eq!(
+            server.battle().space().model().occupant(Square::new(1, 1)),
+            None
+        );
     }
 }
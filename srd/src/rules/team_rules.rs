@@ -6,11 +6,14 @@ use crate::rules::SRDRules;
 use std::sync::Arc;
 use weasel::{BattleController, CreateTeam, EventTrigger, Server, TeamRules};
 
-pub(crate) const GLOBAL_TEAM_ID: u8 = 0;
+/// The team of creatures that didn't choose one.
+pub const GLOBAL_TEAM_ID: u8 = 0;
 
 /// Teams do not exists per se in the SRD, but weasel requires them.\
-/// The easy solution is have one team for all creatures.\
-/// There's no need to implement any specific behavior for teams.
+/// Creatures join the global team unless they choose another one. Creatures of the same team are
+/// kin, while different teams are enemies unless their relation is changed.\
+/// Being hostile matters, for instance, for opportunity attacks and for moving through
+/// another creature's space.
 pub struct SRDTeamRules {
     #[allow(dead_code)]
     narrator: Arc<dyn Narrator>,
@@ -29,11 +32,11 @@ impl TeamRules<SRDRules> for SRDTeamRules {
     type Objectives = ();
 }
 
-/// Creates the global team if it does not exist yet.
-pub(crate) fn create_global_team(server: &mut Server<SRDRules>) -> SRDResult<()> {
-    if server.battle().entities().teams().count() == 0 {
-        CreateTeam::trigger(server, GLOBAL_TEAM_ID).fire()?;
-        log::debug!("created global team");
+/// Creates the team with the given id if it does not exist yet.
+pub(crate) fn create_team(server: &mut Server<SRDRules>, id: u8) -> SRDResult<()> {
+    if server.battle().entities().team(&id).is_none() {
+        CreateTeam::trigger(server, id).fire()?;
+        log::debug!("created team {}", id);
     }
     Ok(())
 }
@@ -1,12 +1,16 @@
 //! Utility functions.
 
+use crate::character::CharacterId;
 use crate::compendium::init_srd_compendium;
 use crate::constants::{VERSION_MAJOR, VERSION_MINOR, VERSION_PATCH};
 use crate::error::SRDResult;
 use crate::rules::narrator::NopNarrator;
 use crate::rules::SRDRules;
 use serde::{Deserialize, Serialize};
-use weasel::{Battle, EventTrigger, ResetEntropy, Server};
+use weasel::round::TurnState;
+use weasel::{
+    Battle, BattleController, EndTurn, EntityId, EventTrigger, ResetEntropy, Server, StartTurn,
+};
 
 /// Reset the battle pseudo random number generator with a fairly good seed.
 /// The change will be propagated to all clients.\
@@ -65,6 +69,37 @@ pub(crate) fn simple_server() -> Server<SRDRules> {
     Server::builder(battle).build()
 }
 
+/// Ends the turns of other creatures until it's the turn of `id`, then starts it.
+#[allow(dead_code)]
+pub(crate) fn start_turn_of(server: &mut Server<SRDRules>, id: &CharacterId) {
+    let creatures = server.battle().rounds().model().len();
+    for _ in 0..=creatures {
+        let current = server
+            .battle()
+            .rounds()
+            .model()
+            .current()
+            .cloned()
+            .expect("no creature in the initiative order");
+        StartTurn::trigger(server, EntityId::Creature(current.clone()))
+            .fire()
+            .unwrap();
+        if current == *id {
+            return;
+        }
+        end_turn(server);
+    }
+    panic!("{:?} is not in the initiative order", id);
+}
+
+/// Ends the current turn, unless it already ended because the acting creature left the battle.
+#[allow(dead_code)]
+pub(crate) fn end_turn(server: &mut Server<SRDRules>) {
+    if let TurnState::Started(_) = server.battle().rounds().state() {
+        EndTurn::trigger(server).fire().unwrap();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;